@@ -3669,6 +3669,56 @@ template <typename Torus> struct int_comparison_buffer {
   }
 };
 
+template <typename Torus> struct int_scalar_select_if_ge_buffer {
+  int_radix_params params;
+  bool allocate_gpu_memory;
+
+  int_comparison_buffer<Torus> *comparison_buffer;
+  int_cmux_buffer<Torus> *cmux_buffer;
+
+  // Result of the comparison, only used as the condition of the cmux
+  CudaRadixCiphertextFFI *condition;
+
+  int_scalar_select_if_ge_buffer(cudaStream_t const *streams,
+                                 uint32_t const *gpu_indexes,
+                                 uint32_t gpu_count, int_radix_params params,
+                                 uint32_t num_radix_blocks,
+                                 uint32_t num_branch_blocks, bool is_signed,
+                                 bool allocate_gpu_memory) {
+    this->params = params;
+    this->allocate_gpu_memory = allocate_gpu_memory;
+
+    comparison_buffer = new int_comparison_buffer<Torus>(
+        streams, gpu_indexes, gpu_count, COMPARISON_TYPE::GE, params,
+        num_radix_blocks, is_signed, allocate_gpu_memory);
+    cmux_buffer = new int_cmux_buffer<Torus>(
+        streams, gpu_indexes, gpu_count,
+        [](Torus x) -> Torus { return x == 1; }, params, num_branch_blocks,
+        allocate_gpu_memory);
+
+    if (allocate_gpu_memory) {
+      condition = new CudaRadixCiphertextFFI;
+      create_zero_radix_ciphertext_async<Torus>(streams[0], gpu_indexes[0],
+                                                condition, 1,
+                                                params.big_lwe_dimension);
+    }
+  }
+
+  void release(cudaStream_t const *streams, uint32_t const *gpu_indexes,
+               uint32_t gpu_count) {
+    comparison_buffer->release(streams, gpu_indexes, gpu_count);
+    delete comparison_buffer;
+    cmux_buffer->release(streams, gpu_indexes, gpu_count);
+    delete cmux_buffer;
+
+    if (allocate_gpu_memory) {
+      release_radix_ciphertext_async(streams[0], gpu_indexes[0], condition);
+      cuda_synchronize_stream(streams[0], gpu_indexes[0]);
+      delete condition;
+    }
+  }
+};
+
 template <typename Torus> struct unsigned_int_div_rem_memory {
   int_radix_params params;
   uint32_t active_gpu_count;
@@ -85,3 +85,58 @@ void cuda_scalar_comparison_integer_radix_ciphertext_kb_64(
     PANIC("Cuda error: integer operation not supported")
   }
 }
+
+void scratch_cuda_integer_radix_scalar_select_if_ge_kb_64(
+    void *const *streams, uint32_t const *gpu_indexes, uint32_t gpu_count,
+    int8_t **mem_ptr, uint32_t glwe_dimension, uint32_t polynomial_size,
+    uint32_t big_lwe_dimension, uint32_t small_lwe_dimension, uint32_t ks_level,
+    uint32_t ks_base_log, uint32_t pbs_level, uint32_t pbs_base_log,
+    uint32_t grouping_factor, uint32_t num_radix_blocks,
+    uint32_t num_branch_blocks, uint32_t message_modulus,
+    uint32_t carry_modulus, PBS_TYPE pbs_type, bool is_signed,
+    bool allocate_gpu_memory, bool allocate_ms_array) {
+
+  int_radix_params params(pbs_type, glwe_dimension, polynomial_size,
+                          big_lwe_dimension, small_lwe_dimension, ks_level,
+                          ks_base_log, pbs_level, pbs_base_log, grouping_factor,
+                          message_modulus, carry_modulus, allocate_ms_array);
+
+  scratch_cuda_integer_radix_scalar_select_if_ge_kb<uint64_t>(
+      (cudaStream_t *)(streams), gpu_indexes, gpu_count,
+      (int_scalar_select_if_ge_buffer<uint64_t> **)mem_ptr, num_radix_blocks,
+      num_branch_blocks, params, is_signed, allocate_gpu_memory);
+}
+
+void cuda_scalar_select_if_ge_integer_radix_ciphertext_kb_64(
+    void *const *streams, uint32_t const *gpu_indexes, uint32_t gpu_count,
+    CudaRadixCiphertextFFI *lwe_array_out,
+    CudaRadixCiphertextFFI const *lwe_array_in, void const *scalar_blocks,
+    void const *h_scalar_blocks, CudaRadixCiphertextFFI const *lwe_array_true,
+    CudaRadixCiphertextFFI const *lwe_array_false, int8_t *mem_ptr,
+    void *const *bsks, void *const *ksks,
+    CudaModulusSwitchNoiseReductionKeyFFI const *ms_noise_reduction_key,
+    uint32_t num_scalar_blocks) {
+
+  auto num_radix_blocks = lwe_array_in->num_radix_blocks;
+  if (num_radix_blocks % 2 != 0 && num_radix_blocks != 1)
+    PANIC("Cuda error (scalar select if ge): the number of radix blocks has "
+          "to be even or equal to 1.")
+
+  host_integer_radix_scalar_select_if_ge_kb<uint64_t>(
+      (cudaStream_t *)(streams), gpu_indexes, gpu_count, lwe_array_out,
+      lwe_array_in, static_cast<const uint64_t *>(scalar_blocks),
+      static_cast<const uint64_t *>(h_scalar_blocks), lwe_array_true,
+      lwe_array_false, (int_scalar_select_if_ge_buffer<uint64_t> *)mem_ptr,
+      bsks, (uint64_t **)(ksks), ms_noise_reduction_key, num_radix_blocks,
+      num_scalar_blocks);
+}
+
+void cleanup_cuda_integer_radix_scalar_select_if_ge(void *const *streams,
+                                                    uint32_t const *gpu_indexes,
+                                                    uint32_t gpu_count,
+                                                    int8_t **mem_ptr_void) {
+
+  int_scalar_select_if_ge_buffer<uint64_t> *mem_ptr =
+      (int_scalar_select_if_ge_buffer<uint64_t> *)(*mem_ptr_void);
+  mem_ptr->release((cudaStream_t *)(streams), gpu_indexes, gpu_count);
+}
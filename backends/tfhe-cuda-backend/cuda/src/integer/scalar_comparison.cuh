@@ -745,6 +745,49 @@ __host__ void host_integer_radix_scalar_maxmin_kb(
       mem_ptr->cmux_buffer, bsks, ksks, ms_noise_reduction_key);
 }
 
+template <typename Torus>
+__host__ void scratch_cuda_integer_radix_scalar_select_if_ge_kb(
+    cudaStream_t const *streams, uint32_t const *gpu_indexes,
+    uint32_t gpu_count, int_scalar_select_if_ge_buffer<Torus> **mem_ptr,
+    uint32_t num_radix_blocks, uint32_t num_branch_blocks,
+    int_radix_params params, bool is_signed, bool allocate_gpu_memory) {
+
+  *mem_ptr = new int_scalar_select_if_ge_buffer<Torus>(
+      streams, gpu_indexes, gpu_count, params, num_radix_blocks,
+      num_branch_blocks, is_signed, allocate_gpu_memory);
+}
+
+// Selects lwe_array_true if lwe_array_in >= scalar, lwe_array_false otherwise.
+// The comparison result stays on the device and is directly used as the
+// condition of the cmux, so both steps are done within a single call.
+template <typename Torus>
+__host__ void host_integer_radix_scalar_select_if_ge_kb(
+    cudaStream_t const *streams, uint32_t const *gpu_indexes,
+    uint32_t gpu_count, CudaRadixCiphertextFFI *lwe_array_out,
+    CudaRadixCiphertextFFI const *lwe_array_in, Torus const *scalar_blocks,
+    Torus const *h_scalar_blocks, CudaRadixCiphertextFFI const *lwe_array_true,
+    CudaRadixCiphertextFFI const *lwe_array_false,
+    int_scalar_select_if_ge_buffer<Torus> *mem_ptr, void *const *bsks,
+    Torus *const *ksks,
+    CudaModulusSwitchNoiseReductionKeyFFI const *ms_noise_reduction_key,
+    uint32_t num_radix_blocks, uint32_t num_scalar_blocks) {
+
+  if (lwe_array_out->lwe_dimension != lwe_array_in->lwe_dimension)
+    PANIC("Cuda error: input and output lwe dimensions must be the same")
+
+  auto comparison_buffer = mem_ptr->comparison_buffer;
+  host_integer_radix_scalar_difference_check_kb<Torus>(
+      streams, gpu_indexes, gpu_count, mem_ptr->condition, lwe_array_in,
+      scalar_blocks, h_scalar_blocks, comparison_buffer,
+      comparison_buffer->diff_buffer->operator_f, bsks, ksks,
+      ms_noise_reduction_key, num_radix_blocks, num_scalar_blocks);
+
+  host_integer_radix_cmux_kb<Torus>(
+      streams, gpu_indexes, gpu_count, lwe_array_out, mem_ptr->condition,
+      lwe_array_true, lwe_array_false, mem_ptr->cmux_buffer, bsks, ksks,
+      ms_noise_reduction_key);
+}
+
 template <typename Torus>
 __host__ void host_integer_radix_scalar_equality_check_kb(
     cudaStream_t const *streams, uint32_t const *gpu_indexes,
@@ -673,6 +673,57 @@ unsafe extern "C" {
         mem_ptr_void: *mut *mut i8,
     );
 }
+unsafe extern "C" {
+    pub fn scratch_cuda_integer_radix_scalar_select_if_ge_kb_64(
+        streams: *const *mut ffi::c_void,
+        gpu_indexes: *const u32,
+        gpu_count: u32,
+        mem_ptr: *mut *mut i8,
+        glwe_dimension: u32,
+        polynomial_size: u32,
+        big_lwe_dimension: u32,
+        small_lwe_dimension: u32,
+        ks_level: u32,
+        ks_base_log: u32,
+        pbs_level: u32,
+        pbs_base_log: u32,
+        grouping_factor: u32,
+        num_radix_blocks: u32,
+        num_branch_blocks: u32,
+        message_modulus: u32,
+        carry_modulus: u32,
+        pbs_type: PBS_TYPE,
+        is_signed: bool,
+        allocate_gpu_memory: bool,
+        allocate_ms_array: bool,
+    );
+}
+unsafe extern "C" {
+    pub fn cuda_scalar_select_if_ge_integer_radix_ciphertext_kb_64(
+        streams: *const *mut ffi::c_void,
+        gpu_indexes: *const u32,
+        gpu_count: u32,
+        lwe_array_out: *mut CudaRadixCiphertextFFI,
+        lwe_array_in: *const CudaRadixCiphertextFFI,
+        scalar_blocks: *const ffi::c_void,
+        h_scalar_blocks: *const ffi::c_void,
+        lwe_array_true: *const CudaRadixCiphertextFFI,
+        lwe_array_false: *const CudaRadixCiphertextFFI,
+        mem_ptr: *mut i8,
+        bsks: *const *mut ffi::c_void,
+        ksks: *const *mut ffi::c_void,
+        ms_noise_reduction_key: *const CudaModulusSwitchNoiseReductionKeyFFI,
+        num_scalar_blocks: u32,
+    );
+}
+unsafe extern "C" {
+    pub fn cleanup_cuda_integer_radix_scalar_select_if_ge(
+        streams: *const *mut ffi::c_void,
+        gpu_indexes: *const u32,
+        gpu_count: u32,
+        mem_ptr_void: *mut *mut i8,
+    );
+}
 unsafe extern "C" {
     pub fn scratch_cuda_integer_radix_bitop_kb_64(
         streams: *const *mut ffi::c_void,
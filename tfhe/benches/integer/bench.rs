@@ -8,7 +8,7 @@ use crate::utilities::{
     get_bench_type, throughput_num_threads, write_to_json, BenchmarkType, EnvConfig, OperatorType,
     ParamsAndNumBlocksIter,
 };
-use criterion::{criterion_group, Criterion, Throughput};
+use criterion::{black_box, criterion_group, Criterion, Throughput};
 use rand::prelude::*;
 use rayon::prelude::*;
 use std::cmp::max;
@@ -682,6 +682,127 @@ fn if_then_else_parallelized(c: &mut Criterion) {
     bench_group.finish()
 }
 
+fn boolean_if_then_else_parallelized(c: &mut Criterion) {
+    let bench_name = "integer::boolean_if_then_else_parallelized";
+    let display_name = "boolean_if_then_else";
+    // Hot loop of selects, the lookup tables are generated on the first call with each key
+    const NUM_CALLS: usize = 100;
+
+    let mut bench_group = c.benchmark_group(bench_name);
+    bench_group
+        .sample_size(10)
+        .measurement_time(std::time::Duration::from_secs(60));
+    let mut rng = rand::thread_rng();
+
+    let mut seen_params = Vec::new();
+    for (param, _, _) in ParamsAndNumBlocksIter::default() {
+        let param_name = param.name();
+        // Selecting booleans does not depend on the number of blocks
+        if seen_params.contains(&param_name) {
+            continue;
+        }
+        seen_params.push(param_name.clone());
+
+        let bench_id = format!("{bench_name}::{NUM_CALLS}_calls::{param_name}");
+        bench_group.bench_function(&bench_id, |b| {
+            let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+            let encrypt_booleans = || {
+                let cond = cks.encrypt_bool(rng.gen_bool(0.5));
+                let true_ct = cks.encrypt_bool(rng.gen_bool(0.5));
+                let false_ct = cks.encrypt_bool(rng.gen_bool(0.5));
+
+                (cond, true_ct, false_ct)
+            };
+
+            b.iter_batched(
+                encrypt_booleans,
+                |(condition, true_ct, false_ct)| {
+                    for _ in 0..NUM_CALLS {
+                        black_box(sks.if_then_else_parallelized(&condition, &true_ct, &false_ct));
+                    }
+                },
+                criterion::BatchSize::SmallInput,
+            )
+        });
+
+        write_to_json::<u64, _>(
+            &bench_id,
+            param,
+            param.name(),
+            display_name,
+            &OperatorType::Atomic,
+            1,
+            vec![1],
+        );
+    }
+
+    bench_group.finish()
+}
+
+fn programmable_select_with_plan_parallelized(c: &mut Criterion) {
+    let bench_name = "integer::programmable_select_with_plan_parallelized";
+    let display_name = "programmable_select_with_plan";
+    // The plan is meant to amortize the generation of lookup tables over a few calls,
+    // each call already costs as much as an if_then_else
+    const NUM_CALLS: usize = 10;
+
+    let mut bench_group = c.benchmark_group(bench_name);
+    bench_group
+        .sample_size(10)
+        .measurement_time(std::time::Duration::from_secs(60));
+    let mut rng = rand::thread_rng();
+
+    for (param, num_block, bit_size) in ParamsAndNumBlocksIter::default() {
+        let param_name = param.name();
+
+        let bench_id = format!("{bench_name}::{NUM_CALLS}_calls::{param_name}::{bit_size}_bits");
+        bench_group.bench_function(&bench_id, |b| {
+            let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+            let message_modulus = param.message_modulus().0;
+
+            let encrypt_tree_values = || {
+                let clear_0 = gen_random_u256(&mut rng);
+                let ct_0 = cks.encrypt_radix(clear_0, num_block);
+
+                let clear_1 = gen_random_u256(&mut rng);
+                let ct_1 = cks.encrypt_radix(clear_1, num_block);
+
+                let cond = cks.encrypt_one_block(rng.gen::<u64>() % message_modulus);
+
+                (cond, ct_0, ct_1)
+            };
+
+            b.iter_batched(
+                encrypt_tree_values,
+                |(condition, true_ct, false_ct)| {
+                    let plan = sks.prepare_programmable_select(|x| x % 2 == 1);
+                    for _ in 0..NUM_CALLS {
+                        black_box(
+                            sks.programmable_select_with_plan(
+                                &condition, &true_ct, &false_ct, &plan,
+                            ),
+                        );
+                    }
+                },
+                criterion::BatchSize::SmallInput,
+            )
+        });
+
+        write_to_json::<u64, _>(
+            &bench_id,
+            param,
+            param.name(),
+            display_name,
+            &OperatorType::Atomic,
+            bit_size as u32,
+            vec![param.message_modulus().0.ilog2(); num_block],
+        );
+    }
+
+    bench_group.finish()
+}
+
 fn ciphertexts_sum_parallelized(c: &mut Criterion) {
     let bench_name = "integer::sum_ciphertexts_parallelized";
     let display_name = "sum_ctxts";
@@ -3029,6 +3150,8 @@ criterion_group!(
     gt_parallelized,
     ge_parallelized,
     if_then_else_parallelized,
+    boolean_if_then_else_parallelized,
+    programmable_select_with_plan_parallelized,
 );
 
 criterion_group!(
@@ -51,6 +51,46 @@ impl RadixCiphertext {
         self.blocks.iter().all(Ciphertext::carry_is_empty)
     }
 
+    /// Returns an estimate of the size in bytes of the ciphertext once serialized
+    ///
+    /// This is the sum of the [Ciphertext::serialized_size_hint] of the blocks,
+    /// plus the length prefix of the blocks.
+    pub fn serialized_size_hint(&self) -> usize {
+        size_of::<u64>()
+            + self
+                .blocks
+                .iter()
+                .map(Ciphertext::serialized_size_hint)
+                .sum::<usize>()
+    }
+
+    /// Returns whether all the blocks of the ciphertext are trivial encryptions
+    ///
+    /// This only reads the blocks metadata, no PBS is done.
+    /// When this returns `true`, the clear value can be obtained with [Self::trivial_decrypt].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // 8 bits
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, 4);
+    ///
+    /// let msg = 124u8;
+    ///
+    /// let trivial_ct: RadixCiphertext = sks.create_trivial_radix(msg, 4);
+    /// assert!(trivial_ct.is_trivial());
+    /// assert_eq!(trivial_ct.decrypt_trivial::<u8>(), Ok(msg));
+    ///
+    /// let non_trivial_ct = cks.encrypt(msg);
+    /// assert!(!non_trivial_ct.is_trivial());
+    ///
+    /// // Mixing trivial and non trivial ciphertexts gives a non trivial ciphertext
+    /// let ct_res = sks.add_parallelized(&trivial_ct, &non_trivial_ct);
+    /// assert!(!ct_res.is_trivial());
+    /// ```
     pub fn is_trivial(&self) -> bool {
         self.blocks.iter().all(Ciphertext::is_trivial)
     }
@@ -119,6 +159,33 @@ impl RadixCiphertext {
             bits_in_block,
         ))
     }
+
+    /// Returns the clear value of the ciphertext if it is trivial, `None` otherwise
+    ///
+    /// This is a shortcut for [Self::decrypt_trivial] with a `u64` clear type, it only reads
+    /// the blocks, no PBS is done. If the ciphertext holds more than 64 bits, only the 64 least
+    /// significant bits are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // 8 bits
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, 4);
+    ///
+    /// let msg = 124u64;
+    ///
+    /// let trivial_ct: RadixCiphertext = sks.create_trivial_radix(msg, 4);
+    /// assert_eq!(trivial_ct.trivial_decrypt(), Some(msg));
+    ///
+    /// let non_trivial_ct = cks.encrypt(msg);
+    /// assert_eq!(non_trivial_ct.trivial_decrypt(), None);
+    /// ```
+    pub fn trivial_decrypt(&self) -> Option<u64> {
+        self.decrypt_trivial().ok()
+    }
 }
 
 /// Structure containing a ciphertext in radix decomposition
@@ -159,6 +226,46 @@ impl SignedRadixCiphertext {
         self.blocks.iter().all(Ciphertext::carry_is_empty)
     }
 
+    /// Returns an estimate of the size in bytes of the ciphertext once serialized
+    ///
+    /// This is the sum of the [Ciphertext::serialized_size_hint] of the blocks,
+    /// plus the length prefix of the blocks.
+    pub fn serialized_size_hint(&self) -> usize {
+        size_of::<u64>()
+            + self
+                .blocks
+                .iter()
+                .map(Ciphertext::serialized_size_hint)
+                .sum::<usize>()
+    }
+
+    /// Returns whether all the blocks of the ciphertext are trivial encryptions
+    ///
+    /// This only reads the blocks metadata, no PBS is done.
+    /// When this returns `true`, the clear value can be obtained with [Self::trivial_decrypt].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, SignedRadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // 8 bits
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, 4);
+    ///
+    /// let msg = -35i8;
+    ///
+    /// let trivial_ct: SignedRadixCiphertext = sks.create_trivial_radix(msg, 4);
+    /// assert!(trivial_ct.is_trivial());
+    /// assert_eq!(trivial_ct.decrypt_trivial::<i8>(), Ok(msg));
+    ///
+    /// let non_trivial_ct = cks.encrypt_signed(msg);
+    /// assert!(!non_trivial_ct.is_trivial());
+    ///
+    /// // Mixing trivial and non trivial ciphertexts gives a non trivial ciphertext
+    /// let ct_res = sks.add_parallelized(&trivial_ct, &non_trivial_ct);
+    /// assert!(!ct_res.is_trivial());
+    /// ```
     pub fn is_trivial(&self) -> bool {
         self.blocks.iter().all(Ciphertext::is_trivial)
     }
@@ -227,6 +334,33 @@ impl SignedRadixCiphertext {
             bits_in_block,
         ))
     }
+
+    /// Returns the clear value of the ciphertext if it is trivial, `None` otherwise
+    ///
+    /// This is a shortcut for [Self::decrypt_trivial] with a `i64` clear type, it only reads
+    /// the blocks, no PBS is done. If the ciphertext holds more than 64 bits, only the 64 least
+    /// significant bits are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, SignedRadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // 8 bits
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, 4);
+    ///
+    /// let msg = -35i64;
+    ///
+    /// let trivial_ct: SignedRadixCiphertext = sks.create_trivial_radix(msg, 4);
+    /// assert_eq!(trivial_ct.trivial_decrypt(), Some(msg));
+    ///
+    /// let non_trivial_ct = cks.encrypt_signed(msg);
+    /// assert_eq!(non_trivial_ct.trivial_decrypt(), None);
+    /// ```
+    pub fn trivial_decrypt(&self) -> Option<i64> {
+        self.decrypt_trivial().ok()
+    }
 }
 
 /// Structure containing a ciphertext in CRT decomposition.
@@ -248,3 +382,93 @@ impl<Block> From<(Vec<Block>, Vec<u64>)> for BaseCrtCiphertext<Block> {
         Self { blocks, moduli }
     }
 }
+
+impl CrtCiphertext {
+    /// Returns an estimate of the size in bytes of the ciphertext once serialized
+    ///
+    /// This is the sum of the [Ciphertext::serialized_size_hint] of the blocks,
+    /// plus the moduli and the length prefixes of the blocks and moduli.
+    pub fn serialized_size_hint(&self) -> usize {
+        size_of::<u64>()
+            + self
+                .blocks
+                .iter()
+                .map(Ciphertext::serialized_size_hint)
+                .sum::<usize>()
+            + size_of::<u64>()
+            + std::mem::size_of_val(self.moduli.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::integer::{
+        gen_keys, BooleanBlock, IntegerKeyKind, RadixCiphertext, SignedRadixCiphertext,
+    };
+    use crate::shortint::parameters::test_params::TEST_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    use crate::shortint::ShortintParameterSet;
+
+    const NUM_BLOCKS: usize = 8;
+
+    // The hint is allowed to be off by 1%
+    fn assert_hint_is_close(hint: usize, serialized: &[u8]) {
+        let actual = serialized.len();
+        assert!(
+            hint.abs_diff(actual) * 100 <= actual,
+            "Serialized size hint {hint} is too far from the actual size {actual}"
+        );
+    }
+
+    #[test]
+    fn test_serialized_size_hint() {
+        let (cks, sks) = gen_keys::<ShortintParameterSet>(
+            TEST_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128.into(),
+            IntegerKeyKind::Radix,
+        );
+
+        let ct = cks.encrypt_radix(42u64, NUM_BLOCKS);
+        assert_hint_is_close(ct.serialized_size_hint(), &bincode::serialize(&ct).unwrap());
+
+        let ct: RadixCiphertext = sks.create_trivial_radix(42u64, NUM_BLOCKS);
+        assert_hint_is_close(ct.serialized_size_hint(), &bincode::serialize(&ct).unwrap());
+
+        let ct = cks.encrypt_signed_radix(-42i64, NUM_BLOCKS);
+        assert_hint_is_close(ct.serialized_size_hint(), &bincode::serialize(&ct).unwrap());
+
+        let ct: BooleanBlock = cks.encrypt_bool(true);
+        assert_hint_is_close(ct.serialized_size_hint(), &bincode::serialize(&ct).unwrap());
+
+        let ct = cks.encrypt_crt(4, vec![2, 3]);
+        assert_hint_is_close(ct.serialized_size_hint(), &bincode::serialize(&ct).unwrap());
+    }
+
+    #[test]
+    fn test_trivial_predicates() {
+        let (cks, sks) = gen_keys::<ShortintParameterSet>(
+            TEST_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128.into(),
+            IntegerKeyKind::Radix,
+        );
+
+        let ct: RadixCiphertext = sks.create_trivial_radix(42u64, NUM_BLOCKS);
+        assert!(ct.is_trivial());
+        assert_eq!(ct.trivial_decrypt(), Some(42));
+
+        let ct = cks.encrypt_radix(42u64, NUM_BLOCKS);
+        assert!(!ct.is_trivial());
+        assert_eq!(ct.trivial_decrypt(), None);
+
+        let ct: SignedRadixCiphertext = sks.create_trivial_radix(-42i64, NUM_BLOCKS);
+        assert!(ct.is_trivial());
+        assert_eq!(ct.trivial_decrypt(), Some(-42));
+
+        let ct = cks.encrypt_signed_radix(-42i64, NUM_BLOCKS);
+        assert!(!ct.is_trivial());
+        assert_eq!(ct.trivial_decrypt(), None);
+
+        // A single non trivial block makes the whole ciphertext non trivial
+        let mut ct: RadixCiphertext = sks.create_trivial_radix(42u64, NUM_BLOCKS);
+        ct.blocks[NUM_BLOCKS - 1] = cks.encrypt_one_block(0);
+        assert!(!ct.is_trivial());
+        assert_eq!(ct.trivial_decrypt(), None);
+    }
+}
@@ -57,6 +57,13 @@ impl BooleanBlock {
         Self(block)
     }
 
+    /// Returns an estimate of the size in bytes of the ciphertext once serialized
+    ///
+    /// See [Ciphertext::serialized_size_hint]
+    pub fn serialized_size_hint(&self) -> usize {
+        self.0.serialized_size_hint()
+    }
+
     /// Creates a new BooleanBlock, but does some checks to see
     /// if it seems plausible that it encrypts a boolean.
     ///
@@ -189,6 +189,37 @@ impl CudaBooleanBlock {
     }
 }
 
+/// A batch of encrypted booleans, one per condition
+///
+/// The conditions are stored packed in a single radix ciphertext (one block per condition),
+/// so that they can all be used at once by a batched operation.
+///
+/// See [crate::integer::gpu::CudaServerKey::batch_prepare_conditions] and
+/// [crate::integer::gpu::CudaServerKey::batch_select].
+pub struct CudaBatchConditions(pub(crate) CudaUnsignedRadixCiphertext);
+
+impl CudaBatchConditions {
+    /// Returns the number of conditions in the batch
+    pub fn len(&self) -> usize {
+        self.0.ciphertext.d_blocks.lwe_ciphertext_count().0
+    }
+
+    /// Returns whether the batch has no conditions
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Copies the conditions back to the CPU, in the order they were prepared
+    pub fn to_boolean_blocks(&self, streams: &CudaStreams) -> Vec<BooleanBlock> {
+        self.0
+            .to_radix_ciphertext(streams)
+            .blocks
+            .into_iter()
+            .map(BooleanBlock::new_unchecked)
+            .collect()
+    }
+}
+
 impl AsRef<CudaUnsignedRadixCiphertext> for CudaBooleanBlock {
     fn as_ref(&self) -> &CudaUnsignedRadixCiphertext {
         &self.0
@@ -54,6 +54,7 @@ pub enum ShiftRotateType {
 }
 
 #[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum ComparisonType {
     EQ = 0,
     NE = 1,
@@ -1381,6 +1382,215 @@ pub unsafe fn unchecked_scalar_comparison_integer_radix_kb_async<T: UnsignedInte
     update_noise_degree(radix_lwe_out, &cuda_ffi_radix_lwe_out);
 }
 
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_scalar_select_if_ge_integer_radix_kb_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_out: &mut CudaRadixCiphertext,
+    radix_lwe_in: &CudaRadixCiphertext,
+    scalar_blocks: &CudaVec<T>,
+    h_scalar_blocks: &[T],
+    radix_lwe_true: &CudaRadixCiphertext,
+    radix_lwe_false: &CudaRadixCiphertext,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    big_lwe_dimension: LweDimension,
+    small_lwe_dimension: LweDimension,
+    ks_level: DecompositionLevelCount,
+    ks_base_log: DecompositionBaseLog,
+    pbs_level: DecompositionLevelCount,
+    pbs_base_log: DecompositionBaseLog,
+    num_scalar_blocks: u32,
+    is_signed: bool,
+    pbs_type: PBSType,
+    grouping_factor: LweBskGroupingFactor,
+    noise_reduction_key: Option<&CudaModulusSwitchNoiseReductionKey>,
+) {
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_out.d_blocks.0.d_vec.gpu_index(0),
+        "GPU error: first stream is on GPU {}, first output pointer is on GPU {}",
+        streams.gpu_indexes[0].get(),
+        radix_lwe_out.d_blocks.0.d_vec.gpu_index(0).get(),
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_in.d_blocks.0.d_vec.gpu_index(0),
+        "GPU error: first stream is on GPU {}, first input pointer is on GPU {}",
+        streams.gpu_indexes[0].get(),
+        radix_lwe_in.d_blocks.0.d_vec.gpu_index(0).get(),
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        scalar_blocks.gpu_index(0),
+        "GPU error: first stream is on GPU {}, first scalar input pointer is on GPU {}",
+        streams.gpu_indexes[0].get(),
+        scalar_blocks.gpu_index(0).get(),
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_true.d_blocks.0.d_vec.gpu_index(0),
+        "GPU error: first stream is on GPU {}, first true pointer is on GPU {}",
+        streams.gpu_indexes[0].get(),
+        radix_lwe_true.d_blocks.0.d_vec.gpu_index(0).get(),
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_false.d_blocks.0.d_vec.gpu_index(0),
+        "GPU error: first stream is on GPU {}, first false pointer is on GPU {}",
+        streams.gpu_indexes[0].get(),
+        radix_lwe_false.d_blocks.0.d_vec.gpu_index(0).get(),
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        bootstrapping_key.gpu_index(0),
+        "GPU error: first stream is on GPU {}, first bsk pointer is on GPU {}",
+        streams.gpu_indexes[0].get(),
+        bootstrapping_key.gpu_index(0).get(),
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        keyswitch_key.gpu_index(0),
+        "GPU error: first stream is on GPU {}, first ksk pointer is on GPU {}",
+        streams.gpu_indexes[0].get(),
+        keyswitch_key.gpu_index(0).get(),
+    );
+    let ct_modulus = radix_lwe_in
+        .d_blocks
+        .ciphertext_modulus()
+        .raw_modulus_float();
+    let ms_noise_reduction_key_ffi =
+        prepare_cuda_ms_noise_reduction_key_ffi(noise_reduction_key, ct_modulus);
+    let allocate_ms_noise_array = noise_reduction_key.is_some();
+    let mut mem_ptr: *mut i8 = std::ptr::null_mut();
+    let mut radix_lwe_out_degrees = radix_lwe_out
+        .info
+        .blocks
+        .iter()
+        .map(|b| b.degree.0)
+        .collect();
+    let mut radix_lwe_out_noise_levels = radix_lwe_out
+        .info
+        .blocks
+        .iter()
+        .map(|b| b.noise_level.0)
+        .collect();
+    let mut cuda_ffi_radix_lwe_out = prepare_cuda_radix_ffi(
+        radix_lwe_out,
+        &mut radix_lwe_out_degrees,
+        &mut radix_lwe_out_noise_levels,
+    );
+    let mut radix_lwe_in_degrees = radix_lwe_in
+        .info
+        .blocks
+        .iter()
+        .map(|b| b.degree.0)
+        .collect();
+    let mut radix_lwe_in_noise_levels = radix_lwe_in
+        .info
+        .blocks
+        .iter()
+        .map(|b| b.noise_level.0)
+        .collect();
+    let cuda_ffi_radix_lwe_in = prepare_cuda_radix_ffi(
+        radix_lwe_in,
+        &mut radix_lwe_in_degrees,
+        &mut radix_lwe_in_noise_levels,
+    );
+    let mut radix_lwe_true_degrees = radix_lwe_true
+        .info
+        .blocks
+        .iter()
+        .map(|b| b.degree.0)
+        .collect();
+    let mut radix_lwe_true_noise_levels = radix_lwe_true
+        .info
+        .blocks
+        .iter()
+        .map(|b| b.noise_level.0)
+        .collect();
+    let cuda_ffi_radix_lwe_true = prepare_cuda_radix_ffi(
+        radix_lwe_true,
+        &mut radix_lwe_true_degrees,
+        &mut radix_lwe_true_noise_levels,
+    );
+    let mut radix_lwe_false_degrees = radix_lwe_false
+        .info
+        .blocks
+        .iter()
+        .map(|b| b.degree.0)
+        .collect();
+    let mut radix_lwe_false_noise_levels = radix_lwe_false
+        .info
+        .blocks
+        .iter()
+        .map(|b| b.noise_level.0)
+        .collect();
+    let cuda_ffi_radix_lwe_false = prepare_cuda_radix_ffi(
+        radix_lwe_false,
+        &mut radix_lwe_false_degrees,
+        &mut radix_lwe_false_noise_levels,
+    );
+    scratch_cuda_integer_radix_scalar_select_if_ge_kb_64(
+        streams.ptr.as_ptr(),
+        streams.gpu_indexes_ptr(),
+        streams.len() as u32,
+        std::ptr::addr_of_mut!(mem_ptr),
+        glwe_dimension.0 as u32,
+        polynomial_size.0 as u32,
+        big_lwe_dimension.0 as u32,
+        small_lwe_dimension.0 as u32,
+        ks_level.0 as u32,
+        ks_base_log.0 as u32,
+        pbs_level.0 as u32,
+        pbs_base_log.0 as u32,
+        grouping_factor.0 as u32,
+        radix_lwe_in.d_blocks.lwe_ciphertext_count().0 as u32,
+        radix_lwe_true.d_blocks.lwe_ciphertext_count().0 as u32,
+        message_modulus.0 as u32,
+        carry_modulus.0 as u32,
+        pbs_type as u32,
+        is_signed,
+        true,
+        allocate_ms_noise_array,
+    );
+
+    cuda_scalar_select_if_ge_integer_radix_ciphertext_kb_64(
+        streams.ptr.as_ptr(),
+        streams.gpu_indexes_ptr(),
+        streams.len() as u32,
+        &mut cuda_ffi_radix_lwe_out,
+        &cuda_ffi_radix_lwe_in,
+        scalar_blocks.as_c_ptr(0),
+        h_scalar_blocks.as_ptr().cast::<std::ffi::c_void>(),
+        &cuda_ffi_radix_lwe_true,
+        &cuda_ffi_radix_lwe_false,
+        mem_ptr,
+        bootstrapping_key.ptr.as_ptr(),
+        keyswitch_key.ptr.as_ptr(),
+        &ms_noise_reduction_key_ffi,
+        num_scalar_blocks,
+    );
+
+    cleanup_cuda_integer_radix_scalar_select_if_ge(
+        streams.ptr.as_ptr(),
+        streams.gpu_indexes_ptr(),
+        streams.len() as u32,
+        std::ptr::addr_of_mut!(mem_ptr),
+    );
+    update_noise_degree(radix_lwe_out, &cuda_ffi_radix_lwe_out);
+}
+
 #[allow(clippy::too_many_arguments)]
 /// # Safety
 ///
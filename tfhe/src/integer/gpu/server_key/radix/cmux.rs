@@ -1,7 +1,7 @@
 use crate::core_crypto::gpu::CudaStreams;
 use crate::core_crypto::prelude::LweBskGroupingFactor;
-use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
-use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
+use crate::integer::gpu::ciphertext::boolean_value::{CudaBatchConditions, CudaBooleanBlock};
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
 use crate::integer::gpu::server_key::CudaBootstrappingKey;
 use crate::integer::gpu::{unchecked_cmux_integer_radix_kb_async, CudaServerKey, PBSType};
 
@@ -131,4 +131,347 @@ impl CudaServerKey {
 
         self.unchecked_if_then_else(condition, true_ct, false_ct, stream)
     }
+
+    /// Computes the two masked branches of an "if then else" selection
+    ///
+    /// Returns `(true_ct_masked, false_ct_masked)`:
+    ///
+    /// - If condition == 1, `true_ct_masked` encrypts the value of true_ct and `false_ct_masked`
+    ///   encrypts 0.
+    /// - If condition == 0, `true_ct_masked` encrypts 0 and `false_ct_masked` encrypts the value of
+    ///   false_ct.
+    ///
+    /// The branches must have empty carries. Combining the two returned ciphertexts with
+    /// [Self::select_combine_async] gives the same result as [Self::unchecked_if_then_else],
+    /// splitting the selection lets the caller interleave other GPU work between the two steps.
+    ///
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn select_zero_pair_async<T: CudaIntegerRadixCiphertext>(
+        &self,
+        condition: &CudaBooleanBlock,
+        true_ct: &T,
+        false_ct: &T,
+        streams: &CudaStreams,
+    ) -> (T, T) {
+        let num_blocks = true_ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        assert_eq!(
+            num_blocks,
+            false_ct.as_ref().d_blocks.lwe_ciphertext_count().0,
+            "true_ct and false_ct must have the same number of blocks"
+        );
+
+        // One copy of the condition per block, so that each block is paired with the condition
+        // in the bivariate PBS
+        let conditions: CudaUnsignedRadixCiphertext = self
+            .convert_integer_radixes_vec_to_single_integer_radix_ciphertext(
+                &(0..num_blocks)
+                    .map(|_| condition.0.duplicate_async(streams))
+                    .collect::<Vec<_>>(),
+                streams,
+            );
+
+        self.select_zero_pair_with_block_conditions_async(&conditions, true_ct, false_ct, streams)
+    }
+
+    /// Same as [Self::select_zero_pair_async], but each block of the branches is paired with the
+    /// block of `conditions` at the same index
+    ///
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    unsafe fn select_zero_pair_with_block_conditions_async<T: CudaIntegerRadixCiphertext>(
+        &self,
+        conditions: &CudaUnsignedRadixCiphertext,
+        true_ct: &T,
+        false_ct: &T,
+        streams: &CudaStreams,
+    ) -> (T, T) {
+        let num_blocks = true_ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+
+        let keep_if_true =
+            self.generate_lookup_table_bivariate(|block, condition| block * (condition & 1));
+        let keep_if_false =
+            self.generate_lookup_table_bivariate(|block, condition| block * (1 - (condition & 1)));
+
+        let mut true_ct_masked: T = self.create_trivial_zero_radix_async(num_blocks, streams);
+        let mut false_ct_masked: T = self.create_trivial_zero_radix_async(num_blocks, streams);
+        self.apply_bivariate_lookup_table_async(
+            true_ct_masked.as_mut(),
+            true_ct.as_ref(),
+            conditions.as_ref(),
+            &keep_if_true,
+            0..num_blocks,
+            streams,
+        );
+        self.apply_bivariate_lookup_table_async(
+            false_ct_masked.as_mut(),
+            false_ct.as_ref(),
+            conditions.as_ref(),
+            &keep_if_false,
+            0..num_blocks,
+            streams,
+        );
+
+        (true_ct_masked, false_ct_masked)
+    }
+
+    /// Computes the two masked branches of an "if then else" selection
+    ///
+    /// Same as [Self::select_zero_pair_async], but the carries of the branches are propagated
+    /// first if needed, and the streams are synchronized before returning.
+    ///
+    /// Returns `(true_ct_masked, false_ct_masked)`, where the branch that is not selected by
+    /// `condition` is replaced by an encryption of 0.
+    pub fn select_zero_pair<T: CudaIntegerRadixCiphertext>(
+        &self,
+        condition: &CudaBooleanBlock,
+        true_ct: &T,
+        false_ct: &T,
+        streams: &CudaStreams,
+    ) -> (T, T) {
+        let mut tmp_true_ct;
+        let mut tmp_false_ct;
+
+        let result = unsafe {
+            let true_ct = if true_ct.block_carries_are_empty() {
+                true_ct
+            } else {
+                tmp_true_ct = true_ct.duplicate_async(streams);
+                self.full_propagate_assign_async(&mut tmp_true_ct, streams);
+                &tmp_true_ct
+            };
+
+            let false_ct = if false_ct.block_carries_are_empty() {
+                false_ct
+            } else {
+                tmp_false_ct = false_ct.duplicate_async(streams);
+                self.full_propagate_assign_async(&mut tmp_false_ct, streams);
+                &tmp_false_ct
+            };
+
+            self.select_zero_pair_async(condition, true_ct, false_ct, streams)
+        };
+        streams.synchronize();
+        result
+    }
+
+    /// Combines the two masked branches returned by [Self::select_zero_pair_async]
+    ///
+    /// The masked branches are added block-wise, and the message of each block is extracted,
+    /// so the returned ciphertext has clean blocks.
+    ///
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn select_combine_async<T: CudaIntegerRadixCiphertext>(
+        &self,
+        true_ct_masked: &T,
+        false_ct_masked: &T,
+        streams: &CudaStreams,
+    ) -> T {
+        let num_blocks = true_ct_masked.as_ref().d_blocks.lwe_ciphertext_count().0;
+
+        // One of the two blocks is 0, so the sum has no carry
+        let mut sum = true_ct_masked.duplicate_async(streams);
+        self.unchecked_add_assign_async(&mut sum, false_ct_masked, streams);
+
+        let message_extract_lut = self.generate_lookup_table(|x| x % self.message_modulus.0);
+        let mut result: T = self.create_trivial_zero_radix_async(num_blocks, streams);
+        self.apply_lookup_table_async(
+            result.as_mut(),
+            sum.as_ref(),
+            &message_extract_lut,
+            0..num_blocks,
+            streams,
+        );
+        result
+    }
+
+    /// Combines the two masked branches returned by [Self::select_zero_pair]
+    ///
+    /// Same as [Self::select_combine_async], but the streams are synchronized before returning.
+    /// The returned ciphertext has clean blocks.
+    pub fn select_combine<T: CudaIntegerRadixCiphertext>(
+        &self,
+        true_ct_masked: &T,
+        false_ct_masked: &T,
+        streams: &CudaStreams,
+    ) -> T {
+        let result = unsafe { self.select_combine_async(true_ct_masked, false_ct_masked, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// Selects between many pairs of branches at once
+    ///
+    /// The i-th returned ciphertext is `true_cts[i]` if the i-th condition of the batch is true,
+    /// otherwise it is `false_cts[i]`.
+    ///
+    /// All the selections are done together, with the same number of PBS launches as a single
+    /// [Self::select_zero_pair_async] followed by [Self::select_combine_async]. The conditions
+    /// are repeated for each block and the results are split with device to device copies,
+    /// whose number grows with the number of selections.
+    ///
+    /// - All branches must have the same number of blocks
+    /// - Requires carry bits to be empty
+    ///
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn unchecked_batch_select_async<T: CudaIntegerRadixCiphertext>(
+        &self,
+        conditions: &CudaBatchConditions,
+        true_cts: &[T],
+        false_cts: &[T],
+        streams: &CudaStreams,
+    ) -> Vec<T> {
+        let num_selects = conditions.len();
+        assert_eq!(
+            true_cts.len(),
+            num_selects,
+            "There must be one true branch per condition"
+        );
+        assert_eq!(
+            false_cts.len(),
+            num_selects,
+            "There must be one false branch per condition"
+        );
+        if num_selects == 0 {
+            return Vec::new();
+        }
+
+        let num_blocks = true_cts[0].as_ref().d_blocks.lwe_ciphertext_count().0;
+        for ct in true_cts.iter().chain(false_cts.iter()) {
+            assert_eq!(
+                ct.as_ref().d_blocks.lwe_ciphertext_count().0,
+                num_blocks,
+                "All branches must have the same number of blocks"
+            );
+        }
+
+        // Each condition is repeated for all the blocks of its branches
+        let block_conditions: CudaUnsignedRadixCiphertext = self.gather_blocks_async(
+            &conditions.0,
+            &(0..num_selects * num_blocks)
+                .map(|i| i / num_blocks)
+                .collect::<Vec<_>>(),
+            streams,
+        );
+
+        let packed_true_cts: T =
+            self.convert_integer_radixes_vec_to_single_integer_radix_ciphertext(true_cts, streams);
+        let packed_false_cts: T =
+            self.convert_integer_radixes_vec_to_single_integer_radix_ciphertext(false_cts, streams);
+
+        let (true_cts_masked, false_cts_masked) = self
+            .select_zero_pair_with_block_conditions_async(
+                &block_conditions,
+                &packed_true_cts,
+                &packed_false_cts,
+                streams,
+            );
+        let packed_result = self.select_combine_async(&true_cts_masked, &false_cts_masked, streams);
+
+        (0..num_selects)
+            .map(|i| {
+                self.gather_blocks_async(
+                    &packed_result,
+                    &((i * num_blocks)..((i + 1) * num_blocks)).collect::<Vec<_>>(),
+                    streams,
+                )
+            })
+            .collect()
+    }
+
+    /// Selects between many pairs of branches at once
+    ///
+    /// See [Self::unchecked_batch_select_async], the branches do not need to have empty carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
+    /// use tfhe::integer::gpu::{gen_keys_radix_gpu, ComparisonType};
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_4_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex::new(gpu_index));
+    ///
+    /// let size = 4;
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_4_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128, size, &streams);
+    ///
+    /// let clears = [(14u64, 97u64), (97, 14), (42, 42)];
+    /// let cts = clears
+    ///     .iter()
+    ///     .map(|&(a, b)| {
+    ///         (
+    ///             CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(a), &streams),
+    ///             CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(b), &streams),
+    ///         )
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// // Compute max(a, b) for all the pairs
+    /// let comparisons = cts
+    ///     .iter()
+    ///     .map(|(a, b)| (ComparisonType::GT, a, b))
+    ///     .collect::<Vec<_>>();
+    /// let conditions = sks.batch_prepare_conditions(&comparisons, &streams);
+    ///
+    /// let (true_cts, false_cts): (Vec<_>, Vec<_>) = cts
+    ///     .iter()
+    ///     .map(|(a, b)| (a.duplicate(&streams), b.duplicate(&streams)))
+    ///     .unzip();
+    /// let d_results = sks.batch_select(&conditions, &true_cts, &false_cts, &streams);
+    ///
+    /// // Copy back to CPU and decrypt
+    /// for (d_result, (a, b)) in d_results.iter().zip(clears) {
+    ///     let dec_result: u64 = cks.decrypt(&d_result.to_radix_ciphertext(&streams));
+    ///     assert_eq!(dec_result, a.max(b));
+    /// }
+    /// ```
+    pub fn batch_select<T: CudaIntegerRadixCiphertext>(
+        &self,
+        conditions: &CudaBatchConditions,
+        true_cts: &[T],
+        false_cts: &[T],
+        streams: &CudaStreams,
+    ) -> Vec<T> {
+        let propagate = |cts: &[T]| unsafe {
+            cts.iter()
+                .map(|ct| {
+                    let mut tmp = ct.duplicate_async(streams);
+                    if !tmp.block_carries_are_empty() {
+                        self.full_propagate_assign_async(&mut tmp, streams);
+                    }
+                    tmp
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let result = unsafe {
+            if true_cts
+                .iter()
+                .chain(false_cts.iter())
+                .all(|ct| ct.block_carries_are_empty())
+            {
+                self.unchecked_batch_select_async(conditions, true_cts, false_cts, streams)
+            } else {
+                let true_cts = propagate(true_cts);
+                let false_cts = propagate(false_cts);
+                self.unchecked_batch_select_async(conditions, &true_cts, &false_cts, streams)
+            }
+        };
+        streams.synchronize();
+        result
+    }
 }
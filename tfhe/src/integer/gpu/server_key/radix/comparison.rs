@@ -1,9 +1,11 @@
 use crate::core_crypto::gpu::lwe_ciphertext_list::CudaLweCiphertextList;
 use crate::core_crypto::gpu::CudaStreams;
 use crate::core_crypto::prelude::{CiphertextModulus, LweBskGroupingFactor, LweCiphertextCount};
-use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+use crate::integer::gpu::ciphertext::boolean_value::{CudaBatchConditions, CudaBooleanBlock};
 use crate::integer::gpu::ciphertext::info::CudaRadixCiphertextInfo;
-use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaRadixCiphertext};
+use crate::integer::gpu::ciphertext::{
+    CudaIntegerRadixCiphertext, CudaRadixCiphertext, CudaUnsignedRadixCiphertext,
+};
 use crate::integer::gpu::server_key::CudaBootstrappingKey;
 use crate::integer::gpu::{
     unchecked_comparison_integer_radix_kb_async, ComparisonType, CudaServerKey, PBSType,
@@ -1221,4 +1223,252 @@ impl CudaServerKey {
         streams.synchronize();
         result
     }
+
+    /// Computes many comparisons at once, returning one condition per comparison
+    ///
+    /// Each element of `comparisons` is `(op, lhs, rhs)`, the i-th condition of the returned
+    /// batch encrypts the result of `lhs op rhs` for the i-th element.
+    ///
+    /// Instead of launching the comparisons one after the other, the blocks of all the pairs are
+    /// compared in a single launch, then the per-block results of all the pairs are reduced
+    /// together. The number of PBS launches depends on the number of blocks of the ciphertexts
+    /// and on the number of distinct comparison kinds, not on the number of comparisons.
+    ///
+    /// Between the PBS launches, the per-block results are rearranged with device to device
+    /// copies, one per run of consecutive blocks. Their number does grow with the number of
+    /// comparisons, but they are much cheaper than the PBS.
+    ///
+    /// - All ciphertexts must have the same number of blocks
+    /// - Only `EQ`, `NE`, `GT`, `GE`, `LT` and `LE` are supported
+    /// - Requires carry bits to be empty
+    /// - Requires a message modulus of at least 3
+    ///
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn unchecked_batch_prepare_conditions_async(
+        &self,
+        comparisons: &[(
+            ComparisonType,
+            &CudaUnsignedRadixCiphertext,
+            &CudaUnsignedRadixCiphertext,
+        )],
+        streams: &CudaStreams,
+    ) -> CudaBatchConditions {
+        assert!(
+            !comparisons.is_empty(),
+            "At least one comparison is required"
+        );
+        assert!(
+            self.message_modulus.0 >= 3,
+            "Batched comparisons require a message modulus of at least 3"
+        );
+
+        let num_comparisons = comparisons.len();
+        let num_blocks = comparisons[0].1.as_ref().d_blocks.lwe_ciphertext_count().0;
+        for (op, lhs, rhs) in comparisons {
+            assert!(
+                !matches!(op, ComparisonType::MAX | ComparisonType::MIN),
+                "MAX and MIN do not return a condition"
+            );
+            assert_eq!(
+                lhs.as_ref().d_blocks.lwe_ciphertext_count().0,
+                num_blocks,
+                "All ciphertexts must have the same number of blocks"
+            );
+            assert_eq!(
+                rhs.as_ref().d_blocks.lwe_ciphertext_count().0,
+                num_blocks,
+                "All ciphertexts must have the same number of blocks"
+            );
+        }
+
+        let pack = |cts: Vec<&CudaUnsignedRadixCiphertext>| {
+            let d_blocks = CudaLweCiphertextList::from_vec_cuda_lwe_ciphertexts_list(
+                cts.iter().map(|&ct| &ct.as_ref().d_blocks),
+                streams,
+            );
+            let info = CudaRadixCiphertextInfo {
+                blocks: cts
+                    .iter()
+                    .flat_map(|&ct| ct.as_ref().info.blocks.clone())
+                    .collect(),
+            };
+            CudaUnsignedRadixCiphertext {
+                ciphertext: CudaRadixCiphertext { d_blocks, info },
+            }
+        };
+        let lhs = pack(comparisons.iter().map(|&(_, lhs, _)| lhs).collect());
+        let rhs = pack(comparisons.iter().map(|&(_, _, rhs)| rhs).collect());
+
+        // Compare all the blocks at once, each block of signs encrypts
+        // 0 if lhs < rhs, 1 if lhs == rhs, 2 if lhs > rhs
+        let sign_lut = self.generate_lookup_table_bivariate(|x, y| match x.cmp(&y) {
+            std::cmp::Ordering::Less => 0,
+            std::cmp::Ordering::Equal => 1,
+            std::cmp::Ordering::Greater => 2,
+        });
+        let mut signs: CudaUnsignedRadixCiphertext =
+            self.create_trivial_zero_radix_async(num_comparisons * num_blocks, streams);
+        self.apply_bivariate_lookup_table_async(
+            signs.as_mut(),
+            lhs.as_ref(),
+            rhs.as_ref(),
+            &sign_lut,
+            0..num_comparisons * num_blocks,
+            streams,
+        );
+
+        // Reduce the signs of all the comparisons together, two by two,
+        // the sign of the more significant block wins unless it is equal
+        let reduce_lut =
+            self.generate_lookup_table_bivariate(|msb, lsb| if msb == 1 { lsb } else { msb });
+        let mut num_signs = num_blocks;
+        while num_signs > 1 {
+            let num_pairs = num_signs / 2;
+            let pair_indices = |offset: usize| {
+                (0..num_comparisons)
+                    .flat_map(|c| (0..num_pairs).map(move |i| c * num_signs + 2 * i + offset))
+                    .collect::<Vec<_>>()
+            };
+            let lsb_signs = self.gather_blocks_async(&signs, &pair_indices(0), streams);
+            let msb_signs = self.gather_blocks_async(&signs, &pair_indices(1), streams);
+
+            let mut reduced: CudaUnsignedRadixCiphertext =
+                self.create_trivial_zero_radix_async(num_comparisons * num_pairs, streams);
+            self.apply_bivariate_lookup_table_async(
+                reduced.as_mut(),
+                msb_signs.as_ref(),
+                lsb_signs.as_ref(),
+                &reduce_lut,
+                0..num_comparisons * num_pairs,
+                streams,
+            );
+
+            signs = if num_signs % 2 == 0 {
+                reduced
+            } else {
+                // The most significant sign of each comparison has no pair,
+                // it is kept as is, after the reduced ones
+                let all_signs = self
+                    .convert_integer_radixes_vec_to_single_integer_radix_ciphertext(
+                        &[reduced, signs],
+                        streams,
+                    );
+                let indices = (0..num_comparisons)
+                    .flat_map(|c| {
+                        (0..num_pairs)
+                            .map(move |i| c * num_pairs + i)
+                            .chain(std::iter::once(
+                                num_comparisons * num_pairs + c * num_signs + num_signs - 1,
+                            ))
+                    })
+                    .collect::<Vec<_>>();
+                self.gather_blocks_async(&all_signs, &indices, streams)
+            };
+            num_signs = num_pairs + num_signs % 2;
+        }
+
+        // Turn the signs into conditions, with one launch per kind of comparison
+        let mut groups: Vec<(ComparisonType, Vec<usize>)> = Vec::new();
+        for (i, (op, _, _)) in comparisons.iter().enumerate() {
+            match groups.iter_mut().find(|(group_op, _)| group_op == op) {
+                Some((_, indices)) => indices.push(i),
+                None => groups.push((*op, vec![i])),
+            }
+        }
+
+        let mut outputs = Vec::with_capacity(groups.len());
+        for (op, indices) in &groups {
+            let sign_to_condition = self.generate_lookup_table(|sign| {
+                let result = match op {
+                    ComparisonType::EQ => sign == 1,
+                    ComparisonType::NE => sign != 1,
+                    ComparisonType::GT => sign == 2,
+                    ComparisonType::GE => sign >= 1,
+                    ComparisonType::LT => sign == 0,
+                    ComparisonType::LE => sign <= 1,
+                    ComparisonType::MAX | ComparisonType::MIN => unreachable!(),
+                };
+                u64::from(result)
+            });
+            let gathered_signs;
+            let group_signs = if groups.len() == 1 {
+                &signs
+            } else {
+                gathered_signs = self.gather_blocks_async(&signs, indices, streams);
+                &gathered_signs
+            };
+            let mut output: CudaUnsignedRadixCiphertext =
+                self.create_trivial_zero_radix_async(indices.len(), streams);
+            self.apply_lookup_table_async(
+                output.as_mut(),
+                group_signs.as_ref(),
+                &sign_to_condition,
+                0..indices.len(),
+                streams,
+            );
+            outputs.push(output);
+        }
+
+        if groups.len() == 1 {
+            return CudaBatchConditions(outputs.pop().unwrap());
+        }
+
+        // Put the conditions back in the order of the comparisons
+        let mut positions = vec![0; num_comparisons];
+        let mut offset = 0;
+        for (_, indices) in &groups {
+            for (j, &i) in indices.iter().enumerate() {
+                positions[i] = offset + j;
+            }
+            offset += indices.len();
+        }
+        let all_conditions =
+            self.convert_integer_radixes_vec_to_single_integer_radix_ciphertext(&outputs, streams);
+        CudaBatchConditions(self.gather_blocks_async(&all_conditions, &positions, streams))
+    }
+
+    /// Computes many comparisons at once, returning one condition per comparison
+    ///
+    /// See [Self::unchecked_batch_prepare_conditions_async], the ciphertexts do not need to have
+    /// empty carries.
+    pub fn batch_prepare_conditions(
+        &self,
+        comparisons: &[(
+            ComparisonType,
+            &CudaUnsignedRadixCiphertext,
+            &CudaUnsignedRadixCiphertext,
+        )],
+        streams: &CudaStreams,
+    ) -> CudaBatchConditions {
+        let propagate = |ct: &CudaUnsignedRadixCiphertext| {
+            (!ct.block_carries_are_empty()).then(|| unsafe {
+                let mut tmp = ct.duplicate_async(streams);
+                self.full_propagate_assign_async(&mut tmp, streams);
+                tmp
+            })
+        };
+        let propagated = comparisons
+            .iter()
+            .map(|&(_, lhs, rhs)| (propagate(lhs), propagate(rhs)))
+            .collect::<Vec<_>>();
+        let comparisons = comparisons
+            .iter()
+            .zip(propagated.iter())
+            .map(|(&(op, lhs, rhs), (tmp_lhs, tmp_rhs))| {
+                (
+                    op,
+                    tmp_lhs.as_ref().unwrap_or(lhs),
+                    tmp_rhs.as_ref().unwrap_or(rhs),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let result =
+            unsafe { self.unchecked_batch_prepare_conditions_async(&comparisons, streams) };
+        streams.synchronize();
+        result
+    }
 }
@@ -28,6 +28,7 @@ use crate::shortint::server_key::{
     BivariateLookupTableOwned, LookupTableOwned, ManyLookupTableOwned,
 };
 use crate::shortint::{PBSOrder, PaddingBit, ShortintEncoding};
+use tfhe_cuda_backend::cuda_bind::cuda_memcpy_async_gpu_to_gpu;
 
 mod abs;
 mod add;
@@ -763,6 +764,70 @@ impl CudaServerKey {
         T::from(CudaRadixCiphertext::new(trimmed_ct_list, trimmed_ct_info))
     }
 
+    /// Returns a new radix ciphertext where the i-th block is a copy of the block
+    /// `indices[i]` of `ct`
+    ///
+    /// Runs of consecutive indices are copied together, so the number of copies is the number
+    /// of such runs (at most `indices.len()`).
+    ///
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronized
+    pub(crate) unsafe fn gather_blocks_async<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct: &T,
+        indices: &[usize],
+        streams: &CudaStreams,
+    ) -> T {
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let lwe_size = ct.as_ref().d_blocks.lwe_dimension().to_lwe_size().0;
+        let block_size_in_bytes = lwe_size * std::mem::size_of::<u64>();
+
+        let mut result: T = self.create_trivial_zero_radix_async(indices.len(), streams);
+        let mut run_start = 0;
+        while run_start < indices.len() {
+            let first_index = indices[run_start];
+            let mut run_end = run_start + 1;
+            while run_end < indices.len() && indices[run_end] == first_index + run_end - run_start {
+                run_end += 1;
+            }
+            let run_len = run_end - run_start;
+            assert!(
+                first_index + run_len <= num_blocks,
+                "Block index {} is out of range for a ciphertext with {num_blocks} blocks",
+                first_index + run_len - 1
+            );
+
+            let dest_ptr = result
+                .as_mut()
+                .d_blocks
+                .0
+                .d_vec
+                .as_mut_c_ptr(0)
+                .add(run_start * block_size_in_bytes);
+            let src_ptr = ct
+                .as_ref()
+                .d_blocks
+                .0
+                .d_vec
+                .as_c_ptr(0)
+                .add(first_index * block_size_in_bytes);
+            cuda_memcpy_async_gpu_to_gpu(
+                dest_ptr,
+                src_ptr,
+                (run_len * block_size_in_bytes) as u64,
+                streams.ptr[0],
+                streams.gpu_indexes[0].get(),
+            );
+            result.as_mut().info.blocks[run_start..run_end]
+                .copy_from_slice(&ct.as_ref().info.blocks[first_index..first_index + run_len]);
+
+            run_start = run_end;
+        }
+        result
+    }
+
     pub(crate) fn generate_lookup_table<F>(&self, f: F) -> LookupTableOwned
     where
         F: Fn(u64) -> u64,
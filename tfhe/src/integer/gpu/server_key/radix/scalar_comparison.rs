@@ -10,7 +10,8 @@ use crate::integer::gpu::server_key::{CudaBootstrappingKey, CudaServerKey};
 use crate::integer::gpu::{
     unchecked_are_all_comparisons_block_true_integer_radix_kb_async,
     unchecked_is_at_least_one_comparisons_block_true_integer_radix_kb_async,
-    unchecked_scalar_comparison_integer_radix_kb_async, ComparisonType, PBSType,
+    unchecked_scalar_comparison_integer_radix_kb_async,
+    unchecked_scalar_select_if_ge_integer_radix_kb_async, ComparisonType, PBSType,
 };
 use crate::shortint::ciphertext::Degree;
 
@@ -1192,4 +1193,254 @@ impl CudaServerKey {
         streams.synchronize();
         result
     }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn unchecked_select_if_scalar_ge_async<Scalar, T>(
+        &self,
+        ct: &T,
+        scalar: Scalar,
+        true_ct: &T,
+        false_ct: &T,
+        streams: &CudaStreams,
+    ) -> T
+    where
+        Scalar: DecomposableInto<u64>,
+        T: CudaIntegerRadixCiphertext,
+    {
+        assert_eq!(
+            true_ct.as_ref().d_blocks.lwe_ciphertext_count(),
+            false_ct.as_ref().d_blocks.lwe_ciphertext_count(),
+            "Both branches must have the same number of blocks"
+        );
+
+        match self.is_scalar_out_of_bounds(ct, scalar) {
+            // The scalar is bigger than any value of ct, so ct >= scalar is false
+            Some(std::cmp::Ordering::Greater) => return false_ct.duplicate_async(streams),
+            // The scalar is smaller than any value of ct, so ct >= scalar is true
+            Some(std::cmp::Ordering::Less) => return true_ct.duplicate_async(streams),
+            Some(std::cmp::Ordering::Equal) => unreachable!("Internal error: invalid value"),
+            None => {
+                // scalar is in range, fallthrough
+            }
+        }
+
+        if scalar < Scalar::ZERO {
+            // The backend only handles non-negative scalars,
+            // a negative scalar in range is compared as a trivial ciphertext
+            let condition = self.unchecked_scalar_ge_async(ct, scalar, streams);
+            return self.unchecked_if_then_else_async(&condition, true_ct, false_ct, streams);
+        }
+
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let mut scalar_blocks =
+            BlockDecomposer::with_early_stop_at_zero(scalar, self.message_modulus.0.ilog2())
+                .iter_as::<u64>()
+                .collect::<Vec<_>>();
+        // The scalar is in range, so the blocks above num_blocks are all 0s
+        scalar_blocks.truncate(num_blocks);
+
+        let d_scalar_blocks: CudaVec<u64> = CudaVec::from_cpu_async(&scalar_blocks, streams, 0);
+
+        let mut result: T = self.create_trivial_zero_radix_async(
+            true_ct.as_ref().d_blocks.lwe_ciphertext_count().0,
+            streams,
+        );
+
+        match &self.bootstrapping_key {
+            CudaBootstrappingKey::Classic(d_bsk) => {
+                unchecked_scalar_select_if_ge_integer_radix_kb_async(
+                    streams,
+                    result.as_mut(),
+                    ct.as_ref(),
+                    &d_scalar_blocks,
+                    &scalar_blocks,
+                    true_ct.as_ref(),
+                    false_ct.as_ref(),
+                    &d_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    self.message_modulus,
+                    self.carry_modulus,
+                    d_bsk.glwe_dimension,
+                    d_bsk.polynomial_size,
+                    self.key_switching_key
+                        .input_key_lwe_size()
+                        .to_lwe_dimension(),
+                    self.key_switching_key
+                        .output_key_lwe_size()
+                        .to_lwe_dimension(),
+                    self.key_switching_key.decomposition_level_count(),
+                    self.key_switching_key.decomposition_base_log(),
+                    d_bsk.decomp_level_count,
+                    d_bsk.decomp_base_log,
+                    scalar_blocks.len() as u32,
+                    T::IS_SIGNED,
+                    PBSType::Classical,
+                    LweBskGroupingFactor(0),
+                    d_bsk.d_ms_noise_reduction_key.as_ref(),
+                );
+            }
+            CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
+                unchecked_scalar_select_if_ge_integer_radix_kb_async(
+                    streams,
+                    result.as_mut(),
+                    ct.as_ref(),
+                    &d_scalar_blocks,
+                    &scalar_blocks,
+                    true_ct.as_ref(),
+                    false_ct.as_ref(),
+                    &d_multibit_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    self.message_modulus,
+                    self.carry_modulus,
+                    d_multibit_bsk.glwe_dimension,
+                    d_multibit_bsk.polynomial_size,
+                    self.key_switching_key
+                        .input_key_lwe_size()
+                        .to_lwe_dimension(),
+                    self.key_switching_key
+                        .output_key_lwe_size()
+                        .to_lwe_dimension(),
+                    self.key_switching_key.decomposition_level_count(),
+                    self.key_switching_key.decomposition_base_log(),
+                    d_multibit_bsk.decomp_level_count,
+                    d_multibit_bsk.decomp_base_log,
+                    scalar_blocks.len() as u32,
+                    T::IS_SIGNED,
+                    PBSType::MultiBit,
+                    d_multibit_bsk.grouping_factor,
+                    None,
+                );
+            }
+        }
+        result
+    }
+
+    pub fn unchecked_select_if_scalar_ge<Scalar, T>(
+        &self,
+        ct: &T,
+        scalar: Scalar,
+        true_ct: &T,
+        false_ct: &T,
+        streams: &CudaStreams,
+    ) -> T
+    where
+        Scalar: DecomposableInto<u64>,
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe {
+            self.unchecked_select_if_scalar_ge_async(ct, scalar, true_ct, false_ct, streams)
+        };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn select_if_scalar_ge_async<Scalar, T>(
+        &self,
+        ct: &T,
+        scalar: Scalar,
+        true_ct: &T,
+        false_ct: &T,
+        streams: &CudaStreams,
+    ) -> T
+    where
+        Scalar: DecomposableInto<u64>,
+        T: CudaIntegerRadixCiphertext,
+    {
+        let mut tmp_lhs;
+        let lhs = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_lhs = ct.duplicate_async(streams);
+            self.full_propagate_assign_async(&mut tmp_lhs, streams);
+            &tmp_lhs
+        };
+
+        let mut tmp_true_ct;
+        let true_ct = if true_ct.block_carries_are_empty() {
+            true_ct
+        } else {
+            tmp_true_ct = true_ct.duplicate_async(streams);
+            self.full_propagate_assign_async(&mut tmp_true_ct, streams);
+            &tmp_true_ct
+        };
+
+        let mut tmp_false_ct;
+        let false_ct = if false_ct.block_carries_are_empty() {
+            false_ct
+        } else {
+            tmp_false_ct = false_ct.duplicate_async(streams);
+            self.full_propagate_assign_async(&mut tmp_false_ct, streams);
+            &tmp_false_ct
+        };
+
+        self.unchecked_select_if_scalar_ge_async(lhs, scalar, true_ct, false_ct, streams)
+    }
+
+    /// Returns `true_ct` if `ct >= scalar`, otherwise returns `false_ct`
+    ///
+    /// This is the same as a [Self::scalar_ge] followed by an [Self::if_then_else], except that
+    /// the comparison and the selection are done in a single call to the backend: the encrypted
+    /// result of the comparison stays on the device and is directly used as the condition of the
+    /// selection, which saves a round of host synchronization.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_4_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex::new(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_4_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128, size, &streams);
+    ///
+    /// let msg = 97u64;
+    /// let scalar = 14u64;
+    /// let msg_true = 1u64;
+    /// let msg_false = 2u64;
+    ///
+    /// // Copy to GPU
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams);
+    /// let d_true = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg_true), &streams);
+    /// let d_false = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg_false), &streams);
+    ///
+    /// let d_ct_res = sks.select_if_scalar_ge(&d_ct, scalar, &d_true, &d_false, &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, if msg >= scalar { msg_true } else { msg_false });
+    /// ```
+    pub fn select_if_scalar_ge<Scalar, T>(
+        &self,
+        ct: &T,
+        scalar: Scalar,
+        true_ct: &T,
+        false_ct: &T,
+        streams: &CudaStreams,
+    ) -> T
+    where
+        Scalar: DecomposableInto<u64>,
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result =
+            unsafe { self.select_if_scalar_ge_async(ct, scalar, true_ct, false_ct, streams) };
+        streams.synchronize();
+        result
+    }
 }
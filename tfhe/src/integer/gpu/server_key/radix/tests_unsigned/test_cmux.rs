@@ -1,15 +1,23 @@
 use crate::core_crypto::gpu::get_number_of_gpus;
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
 use crate::integer::gpu::server_key::radix::tests_long_run::GpuMultiDeviceFunctionExecutor;
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
-use crate::integer::gpu::CudaServerKey;
+use crate::integer::gpu::{ComparisonType, CudaServerKey};
+use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_unsigned::test_cmux::default_if_then_else_test;
+use crate::integer::IntegerKeyKind;
 use crate::shortint::parameters::test_params::*;
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_if_then_else);
 create_gpu_parameterized_test!(multi_device_integer_if_then_else);
+create_gpu_parameterized_test!(integer_select_zero_pair_then_combine);
+create_gpu_parameterized_test!(integer_batch_prepare_conditions_then_select);
 
 fn integer_if_then_else<P>(param: P)
 where
@@ -28,3 +36,115 @@ where
         default_if_then_else_test(param, executor);
     }
 }
+
+fn integer_select_zero_pair_then_combine<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    // The two steps composed must give the same results as the fused if_then_else
+    let func = |sks: &CudaServerKey,
+                condition: &CudaBooleanBlock,
+                true_ct: &CudaUnsignedRadixCiphertext,
+                false_ct: &CudaUnsignedRadixCiphertext,
+                streams: &CudaStreams| {
+        let (true_ct_masked, false_ct_masked) =
+            sks.select_zero_pair(condition, true_ct, false_ct, streams);
+        sks.select_combine(&true_ct_masked, &false_ct_masked, streams)
+    };
+    let executor = GpuFunctionExecutor::new(&func);
+    default_if_then_else_test(param, executor);
+}
+
+fn integer_batch_prepare_conditions_then_select<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const BATCH_SIZE: usize = 16;
+
+    let p = param.into();
+    let (cks, _) = KEY_CACHE.get_from_params(p, IntegerKeyKind::Radix);
+
+    let streams = CudaStreams::new_multi_gpu();
+    let sks = CudaServerKey::new(&cks, &streams);
+
+    let mut rng = rand::thread_rng();
+    let ops = [
+        ComparisonType::EQ,
+        ComparisonType::NE,
+        ComparisonType::GT,
+        ComparisonType::GE,
+        ComparisonType::LT,
+        ComparisonType::LE,
+    ];
+
+    // With 3 blocks, the reduction of the comparisons has a block without pair
+    for num_blocks in [3, 4] {
+        let modulus = p.message_modulus().0.pow(num_blocks as u32);
+
+        let clears = (0..BATCH_SIZE)
+            .map(|_| {
+                let lhs = rng.gen::<u64>() % modulus;
+                // Make sure equal values are compared too
+                let rhs = if rng.gen_bool(0.25) {
+                    lhs
+                } else {
+                    rng.gen::<u64>() % modulus
+                };
+                let op = ops[rng.gen_range(0..ops.len())];
+                let clear_true = rng.gen::<u64>() % modulus;
+                let clear_false = rng.gen::<u64>() % modulus;
+                (op, lhs, rhs, clear_true, clear_false)
+            })
+            .collect::<Vec<_>>();
+
+        let encrypt = |clear: u64| {
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+                &cks.encrypt_radix(clear, num_blocks),
+                &streams,
+            )
+        };
+        let d_lhs = clears.iter().map(|c| encrypt(c.1)).collect::<Vec<_>>();
+        let d_rhs = clears.iter().map(|c| encrypt(c.2)).collect::<Vec<_>>();
+        let d_true_cts = clears.iter().map(|c| encrypt(c.3)).collect::<Vec<_>>();
+        let d_false_cts = clears.iter().map(|c| encrypt(c.4)).collect::<Vec<_>>();
+
+        let comparisons = clears
+            .iter()
+            .zip(d_lhs.iter().zip(d_rhs.iter()))
+            .map(|(c, (lhs, rhs))| (c.0, lhs, rhs))
+            .collect::<Vec<_>>();
+        let conditions = sks.batch_prepare_conditions(&comparisons, &streams);
+        assert_eq!(conditions.len(), BATCH_SIZE);
+
+        let d_results = sks.batch_select(&conditions, &d_true_cts, &d_false_cts, &streams);
+        assert_eq!(d_results.len(), BATCH_SIZE);
+
+        let conditions = conditions.to_boolean_blocks(&streams);
+        for (i, &(op, lhs, rhs, clear_true, clear_false)) in clears.iter().enumerate() {
+            let expected_condition = match op {
+                ComparisonType::EQ => lhs == rhs,
+                ComparisonType::NE => lhs != rhs,
+                ComparisonType::GT => lhs > rhs,
+                ComparisonType::GE => lhs >= rhs,
+                ComparisonType::LT => lhs < rhs,
+                ComparisonType::LE => lhs <= rhs,
+                ComparisonType::MAX | ComparisonType::MIN => unreachable!(),
+            };
+            let condition = cks.decrypt_bool(&conditions[i]);
+            assert_eq!(
+                condition, expected_condition,
+                "Invalid condition for the comparison {i} of {lhs} and {rhs}"
+            );
+
+            let result = d_results[i].to_radix_ciphertext(&streams);
+            assert!(result.block_carries_are_empty());
+            let decrypted: u64 = cks.decrypt_radix(&result);
+            let expected = if expected_condition {
+                clear_true
+            } else {
+                clear_false
+            };
+            assert_eq!(decrypted, expected, "Invalid select {i}");
+        }
+    }
+}
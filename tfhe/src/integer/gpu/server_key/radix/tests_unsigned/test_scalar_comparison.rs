@@ -4,11 +4,13 @@ use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
 use crate::integer::gpu::{gen_keys_gpu, CudaServerKey};
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::prelude::ServerKeyDefaultCMux;
 use crate::integer::server_key::radix_parallel::tests_unsigned::test_scalar_comparison::{
     test_default_scalar_function, test_default_scalar_minmax, test_unchecked_scalar_function,
     test_unchecked_scalar_minmax,
 };
-use crate::integer::U256;
+use crate::integer::{IntegerKeyKind, U256};
 use crate::shortint::parameters::test_params::*;
 use crate::shortint::parameters::*;
 use rand::Rng;
@@ -324,6 +326,68 @@ where
     }
 }
 
+// Checks the fused select_if_scalar_ge against a CPU scalar_ge followed by an if_then_else
+fn integer_select_if_scalar_ge_matches_cpu<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let p = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(p, IntegerKeyKind::Radix);
+
+    let streams = CudaStreams::new_multi_gpu();
+    let d_sks = CudaServerKey::new(&cks, &streams);
+
+    let mut rng = rand::thread_rng();
+    let bits_per_block = p.message_modulus().0.ilog2();
+
+    for num_bits in [u32::BITS, u64::BITS] {
+        let num_blocks = num_bits.div_ceil(bits_per_block) as usize;
+        let modulus = u64::MAX >> (u64::BITS - num_bits);
+
+        for _ in 0..4 {
+            let clear = rng.gen::<u64>() & modulus;
+            let clear_true = rng.gen::<u64>() & modulus;
+            let clear_false = rng.gen::<u64>() & modulus;
+            // Make sure both outcomes of the comparison are covered
+            let scalar = match rng.gen_range(0..3) {
+                0 => clear,
+                1 => clear.saturating_add(1) & modulus,
+                _ => rng.gen::<u64>() & modulus,
+            };
+
+            let ct = cks.encrypt_radix(clear, num_blocks);
+            let ct_true = cks.encrypt_radix(clear_true, num_blocks);
+            let ct_false = cks.encrypt_radix(clear_false, num_blocks);
+
+            let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+            let d_true = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct_true, &streams);
+            let d_false = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct_false, &streams);
+
+            let d_result = d_sks.select_if_scalar_ge(&d_ct, scalar, &d_true, &d_false, &streams);
+            let gpu_result = d_result.to_radix_ciphertext(&streams);
+            let gpu_decrypted: u64 = cks.decrypt_radix(&gpu_result);
+
+            let condition = sks.scalar_ge_parallelized(&ct, scalar);
+            let cpu_result = sks.if_then_else_parallelized(&condition, &ct_true, &ct_false);
+            let cpu_decrypted: u64 = cks.decrypt_radix(&cpu_result);
+
+            let expected = if clear >= scalar {
+                clear_true
+            } else {
+                clear_false
+            };
+            assert_eq!(
+                cpu_decrypted, expected,
+                "Invalid CPU result for {clear} >= {scalar} on {num_bits} bits"
+            );
+            assert_eq!(
+                gpu_decrypted, cpu_decrypted,
+                "GPU and CPU results differ for {clear} >= {scalar} on {num_bits} bits"
+            );
+        }
+    }
+}
+
 create_gpu_parameterized_test!(integer_unchecked_scalar_min_u256 {
     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
     PARAM_GPU_MULTI_BIT_GROUP_4_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
@@ -352,6 +416,11 @@ define_gpu_scalar_comparison_test_functions!(le, U256);
 define_gpu_scalar_comparison_test_functions!(gt, U256);
 define_gpu_scalar_comparison_test_functions!(ge, U256);
 
+create_gpu_parameterized_test!(integer_select_if_scalar_ge_matches_cpu {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    PARAM_GPU_MULTI_BIT_GROUP_4_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+    TEST_PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+});
 create_gpu_parameterized_test!(integer_unchecked_scalar_comparisons_edge {
     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
     PARAM_GPU_MULTI_BIT_GROUP_4_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
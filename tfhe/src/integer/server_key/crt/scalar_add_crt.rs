@@ -44,9 +44,64 @@ impl ServerKey {
     ///
     /// The result is assigned to the `ct_left` ciphertext.
     pub fn unchecked_crt_scalar_add_assign(&self, ct: &mut CrtCiphertext, scalar: u64) {
-        //Add the crt representation of the scalar to the ciphertext
-        for (ct_i, mod_i) in ct.blocks.iter_mut().zip(ct.moduli.iter()) {
+        self.crt_scalar_add_blocks_assign(&mut ct.blocks, &ct.moduli, scalar);
+    }
+
+    /// Computes homomorphically an addition between a scalar and the blocks of a ciphertext in
+    /// CRT decomposition.
+    ///
+    /// This is the same as [Self::unchecked_crt_scalar_add_assign], but it works on the blocks
+    /// and moduli directly, so they can be stored in any container.
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `blocks` and `moduli` do not have the same length
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::{gen_keys_crt, CrtCiphertext, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let basis = vec![2, 3, 5];
+    /// let modulus: u64 = basis.iter().product();
+    /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128, basis.clone());
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 14;
+    /// // Encrypt a message, and keep only its blocks
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let mut blocks = ctxt_1.blocks().to_vec();
+    ///
+    /// sks.crt_scalar_add_blocks_assign(&mut blocks, &basis, clear_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&CrtCiphertext::from((blocks, basis)));
+    /// assert_eq!((clear_1 + clear_2) % modulus, res);
+    /// ```
+    pub fn crt_scalar_add_blocks_assign(
+        &self,
+        blocks: &mut [crate::shortint::Ciphertext],
+        moduli: &[u64],
+        scalar: u64,
+    ) {
+        assert_eq!(
+            blocks.len(),
+            moduli.len(),
+            "blocks and moduli must have the same length"
+        );
+
+        //Add the crt representation of the scalar to the blocks
+        for (ct_i, mod_i) in blocks.iter_mut().zip(moduli.iter()) {
             let scalar_i = scalar % mod_i;
+            // Adding 0 to this residue is a no-op
+            if scalar_i == 0 {
+                continue;
+            }
 
             self.key.unchecked_scalar_add_assign(ct_i, scalar_i as u8);
         }
@@ -38,6 +38,12 @@ impl ServerKey {
         ct_result
     }
 
+    /// Computes homomorphically a multiplication between a scalar and a ciphertext.
+    ///
+    /// Each block is multiplied by the residue of the scalar for its modulus. When the product
+    /// does not fit in a block, the block is reduced with a PBS instead of overflowing.
+    ///
+    /// The result is assigned to the input ciphertext.
     pub fn unchecked_crt_scalar_mul_assign(&self, ctxt: &mut CrtCiphertext, scalar: u64) {
         for (ct_i, mod_i) in ctxt.blocks.iter_mut().zip(ctxt.moduli.iter()) {
             let scalar_i = (scalar % mod_i) as u8;
@@ -49,7 +55,7 @@ impl ServerKey {
                 && self
                     .key
                     .max_noise_level
-                    .validate(ct_i.noise_level() * scalar)
+                    .validate(ct_i.noise_level() * u64::from(scalar_i))
                     .is_ok()
             {
                 self.key.unchecked_scalar_mul_assign(ct_i, scalar_i);
@@ -62,6 +68,10 @@ impl ServerKey {
 
     ///Verifies if ct1 can be multiplied by scalar.
     ///
+    /// Each block `i` is multiplied by `scalar % mod_i`, so both its degree and its noise level
+    /// grow multiplicatively with that residue. Scalars for which a block would exceed its
+    /// maximum degree or noise level before a PBS are rejected.
+    ///
     /// # Example
     ///
     ///```rust
@@ -124,13 +134,10 @@ impl ServerKey {
         ct: &CrtCiphertext,
         scalar: u64,
     ) -> Result<CrtCiphertext, CheckError> {
-        let mut ct_result = ct.clone();
-
         // If the ciphertext cannot be multiplied without exceeding the capacity of a ciphertext
         self.is_crt_scalar_mul_possible(ct, scalar)?;
-        ct_result = self.unchecked_crt_scalar_mul(&ct_result, scalar);
 
-        Ok(ct_result)
+        Ok(self.unchecked_crt_scalar_mul(ct, scalar))
     }
 
     /// Computes homomorphically a multiplication between a scalar and a ciphertext.
@@ -151,9 +158,8 @@ impl ServerKey {
 
     /// Computes homomorphically a multiplication between a scalar and a ciphertext.
     ///
-    /// `small` means the scalar value shall fit in a __shortint block__.
-    /// For example, if the parameters are PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
-    /// the scalar should fit in 2 bits.
+    /// If the multiplication is not possible, the carries of the ciphertext are
+    /// cleaned first.
     ///
     /// The result is returned as a new ciphertext.
     ///
@@ -191,9 +197,8 @@ impl ServerKey {
 
     /// Computes homomorphically a multiplication between a scalar and a ciphertext.
     ///
-    /// `small` means the scalar shall value fit in a __shortint block__.
-    /// For example, if the parameters are PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
-    /// the scalar should fit in 2 bits.
+    /// If the multiplication is not possible, the carries of the ciphertext are
+    /// cleaned first.
     ///
     /// The result is assigned to the input ciphertext
     ///
@@ -220,7 +225,7 @@ impl ServerKey {
     /// assert_eq!((clear_1 * clear_2) % modulus, res);
     /// ```
     pub fn smart_crt_scalar_mul_assign(&self, ctxt: &mut CrtCiphertext, scalar: u64) {
-        if self.is_crt_small_scalar_mul_possible(ctxt, scalar).is_err() {
+        if self.is_crt_scalar_mul_possible(ctxt, scalar).is_err() {
             self.full_extract_message_assign(ctxt);
         }
         self.is_crt_scalar_mul_possible(ctxt, scalar).unwrap();
@@ -20,16 +20,16 @@ impl ServerKey {
     /// let modulus: u64 = basis.iter().product();
     /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128, basis);
     ///
-    /// let clear_1 = 14;
-    /// let clear_2 = 7;
+    /// let clear_1: i64 = 7;
+    /// let clear_2: i64 = 14;
     /// // Encrypt two messages
-    /// let mut ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_1 = cks.encrypt(clear_1 as u64);
     ///
-    /// sks.unchecked_crt_scalar_sub_assign(&mut ctxt_1, clear_2);
+    /// let ct_res = sks.unchecked_crt_scalar_sub(&ctxt_1, clear_2 as u64);
     ///
     /// // Decrypt
-    /// let res = cks.decrypt(&ctxt_1);
-    /// assert_eq!((clear_1 - clear_2) % modulus, res);
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!((clear_1 - clear_2).rem_euclid(modulus as i64) as u64, res);
     /// ```
     pub fn unchecked_crt_scalar_sub(&self, ct: &CrtCiphertext, scalar: u64) -> CrtCiphertext {
         let mut result = ct.clone();
@@ -37,24 +37,63 @@ impl ServerKey {
         result
     }
 
+    /// Computes homomorphically a subtraction between a ciphertext and a scalar.
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext.
+    ///
+    /// The result is assigned to the `ct` ciphertext.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_crt;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let basis = vec![2, 3, 5];
+    /// let modulus: u64 = basis.iter().product();
+    /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128, basis);
+    ///
+    /// let clear_1: i64 = 7;
+    /// let clear_2: i64 = 14;
+    /// // Encrypt two messages
+    /// let mut ctxt_1 = cks.encrypt(clear_1 as u64);
+    ///
+    /// sks.unchecked_crt_scalar_sub_assign(&mut ctxt_1, clear_2 as u64);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ctxt_1);
+    /// assert_eq!((clear_1 - clear_2).rem_euclid(modulus as i64) as u64, res);
+    /// ```
     pub fn unchecked_crt_scalar_sub_assign(&self, ct: &mut CrtCiphertext, scalar: u64) {
-        //Put each decomposition into a new ciphertext
+        // Subtracting is adding the opposite of the scalar in each residue ring
         for (ct_i, mod_i) in ct.blocks.iter_mut().zip(ct.moduli.iter()) {
             let neg_scalar = (mod_i - scalar % mod_i) % mod_i;
+            // Subtracting 0 from this residue is a no-op
+            if neg_scalar == 0 {
+                continue;
+            }
+
             self.key.unchecked_scalar_add_assign(ct_i, neg_scalar as u8);
         }
     }
 
     /// Verifies if the subtraction of a ciphertext by scalar can be computed.
     ///
+    /// Each block `i` receives `(mod_i - scalar % mod_i) % mod_i`, so the degree increase
+    /// of a block depends on the opposite of the scalar residue, not on the residue itself:
+    /// subtracting a small scalar can make the degree grow by up to `mod_i - 1`, while
+    /// residues where the scalar is a multiple of `mod_i` are left untouched.
+    ///
     /// # Example
     ///
     ///```rust
     /// use tfhe::integer::gen_keys_crt;
-    /// let basis = vec![2, 3, 5];
     /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128;
     ///
     /// // Generate the client key and the server key:
+    /// let basis = vec![2, 3, 5];
     /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128, basis);
     ///
     /// let clear_1 = 14;
@@ -63,9 +102,6 @@ impl ServerKey {
     /// let ctxt_1 = cks.encrypt(clear_1);
     ///
     /// sks.is_crt_scalar_sub_possible(&ctxt_1, clear_2).unwrap();
-    ///
-    /// // Decrypt
-    /// let res = cks.decrypt(&ctxt_1);
     /// ```
     pub fn is_crt_scalar_sub_possible(
         &self,
@@ -97,16 +133,16 @@ impl ServerKey {
     /// let modulus: u64 = basis.iter().product();
     /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128, basis);
     ///
-    /// let clear_1 = 14;
-    /// let clear_2 = 8;
+    /// let clear_1: i64 = 8;
+    /// let clear_2: i64 = 14;
     ///
-    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_1 = cks.encrypt(clear_1 as u64);
     ///
-    /// let ct_res = sks.checked_crt_scalar_sub(&ctxt_1, clear_2).unwrap();
+    /// let ct_res = sks.checked_crt_scalar_sub(&ctxt_1, clear_2 as u64).unwrap();
     ///
     /// // Decrypt:
     /// let dec = cks.decrypt(&ct_res);
-    /// assert_eq!((clear_1 - clear_2) % modulus, dec);
+    /// assert_eq!((clear_1 - clear_2).rem_euclid(modulus as i64) as u64, dec);
     /// ```
     pub fn checked_crt_scalar_sub(
         &self,
@@ -133,17 +169,17 @@ impl ServerKey {
     /// let modulus: u64 = basis.iter().product();
     /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128, basis);
     ///
-    /// let clear_1 = 14;
-    /// let clear_2 = 7;
+    /// let clear_1: i64 = 7;
+    /// let clear_2: i64 = 14;
     ///
-    /// let mut ctxt_1 = cks.encrypt(clear_1);
+    /// let mut ctxt_1 = cks.encrypt(clear_1 as u64);
     ///
-    /// sks.checked_crt_scalar_sub_assign(&mut ctxt_1, clear_2)
+    /// sks.checked_crt_scalar_sub_assign(&mut ctxt_1, clear_2 as u64)
     ///     .unwrap();
     ///
     /// // Decrypt:
     /// let dec = cks.decrypt(&ctxt_1);
-    /// assert_eq!((clear_1 - clear_2) % modulus, dec);
+    /// assert_eq!((clear_1 - clear_2).rem_euclid(modulus as i64) as u64, dec);
     /// ```
     pub fn checked_crt_scalar_sub_assign(
         &self,
@@ -168,16 +204,16 @@ impl ServerKey {
     /// let modulus: u64 = basis.iter().product();
     /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128, basis);
     ///
-    /// let clear_1 = 14;
-    /// let clear_2 = 7;
+    /// let clear_1: i64 = 7;
+    /// let clear_2: i64 = 14;
     /// // Encrypt two messages
-    /// let mut ctxt_1 = cks.encrypt(clear_1);
+    /// let mut ctxt_1 = cks.encrypt(clear_1 as u64);
     ///
-    /// sks.smart_crt_scalar_sub_assign(&mut ctxt_1, clear_2);
+    /// let ct_res = sks.smart_crt_scalar_sub(&mut ctxt_1, clear_2 as u64);
     ///
     /// // Decrypt
-    /// let res = cks.decrypt(&ctxt_1);
-    /// assert_eq!((clear_1 - clear_2) % modulus, res);
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!((clear_1 - clear_2).rem_euclid(modulus as i64) as u64, res);
     /// ```
     pub fn smart_crt_scalar_sub(&self, ct: &mut CrtCiphertext, scalar: u64) -> CrtCiphertext {
         if self.is_crt_scalar_sub_possible(ct, scalar).is_err() {
@@ -188,6 +224,32 @@ impl ServerKey {
         self.unchecked_crt_scalar_sub(ct, scalar)
     }
 
+    /// Computes homomorphically a subtraction of a ciphertext by a scalar.
+    ///
+    /// The result is assigned to the `ct` ciphertext.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_crt;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let basis = vec![2, 3, 5];
+    /// let modulus: u64 = basis.iter().product();
+    /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128, basis);
+    ///
+    /// let clear_1: i64 = 7;
+    /// let clear_2: i64 = 14;
+    /// // Encrypt two messages
+    /// let mut ctxt_1 = cks.encrypt(clear_1 as u64);
+    ///
+    /// sks.smart_crt_scalar_sub_assign(&mut ctxt_1, clear_2 as u64);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ctxt_1);
+    /// assert_eq!((clear_1 - clear_2).rem_euclid(modulus as i64) as u64, res);
+    /// ```
     pub fn smart_crt_scalar_sub_assign(&self, ct: &mut CrtCiphertext, scalar: u64) {
         if self.is_crt_scalar_sub_possible(ct, scalar).is_err() {
             self.full_extract_message_assign(ct);
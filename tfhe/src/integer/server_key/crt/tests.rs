@@ -173,6 +173,40 @@ fn integer_unchecked_crt_scalar_add_32_bits() {
     }
 }
 
+#[test]
+fn integer_unchecked_crt_scalar_add_zero_residues() {
+    let basis = [3u64, 11, 13, 19, 23, 29, 31, 32];
+
+    let modulus = basis.iter().product::<u64>();
+    let (cks, sks) = KEY_CACHE.get_from_params(PARAM, IntegerKeyKind::CRT);
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let ct = cks.encrypt_crt(clear_0, basis.to_vec());
+
+        // 0 is a multiple of all the moduli,
+        // the other scalar is only a multiple of some of them
+        let partial_multiple = basis.iter().filter(|_| rng.gen_bool(0.5)).product::<u64>();
+        for scalar in [0, modulus, partial_multiple] {
+            let ct_res = sks.unchecked_crt_scalar_add(&ct, scalar);
+
+            for ((block, block_res), mod_i) in
+                ct.blocks.iter().zip(ct_res.blocks.iter()).zip(basis.iter())
+            {
+                if scalar % mod_i == 0 {
+                    // No spurious degree growth on residues where nothing is added
+                    assert_eq!(block_res.degree, block.degree);
+                    assert_eq!(block_res.noise_level(), block.noise_level());
+                }
+            }
+
+            let dec_res = cks.decrypt_crt(&ct_res);
+            assert_eq!((clear_0 + scalar) % modulus, dec_res % modulus);
+        }
+    }
+}
+
 #[test]
 fn integer_unchecked_crt_scalar_mul_32_bits() {
     // Define CRT basis, and global modulus
@@ -227,6 +261,44 @@ fn integer_unchecked_crt_scalar_sub_32_bits() {
     }
 }
 
+#[test]
+fn integer_unchecked_crt_scalar_sub_zero_residues() {
+    let basis = [3u64, 11, 13, 19, 23, 29, 31, 32];
+
+    let modulus = basis.iter().product::<u64>();
+    let (cks, sks) = KEY_CACHE.get_from_params(PARAM, IntegerKeyKind::CRT);
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let ct = cks.encrypt_crt(clear_0, basis.to_vec());
+
+        // 0 is a multiple of all the moduli,
+        // the other scalar is only a multiple of some of them
+        let partial_multiple = basis.iter().filter(|_| rng.gen_bool(0.5)).product::<u64>();
+        for scalar in [0, modulus, partial_multiple] {
+            sks.is_crt_scalar_sub_possible(&ct, scalar).unwrap();
+            let ct_res = sks.unchecked_crt_scalar_sub(&ct, scalar);
+
+            for ((block, block_res), mod_i) in
+                ct.blocks.iter().zip(ct_res.blocks.iter()).zip(basis.iter())
+            {
+                if scalar % mod_i == 0 {
+                    // No spurious degree growth on residues where nothing is subtracted
+                    assert_eq!(block_res.degree, block.degree);
+                    assert_eq!(block_res.noise_level(), block.noise_level());
+                }
+            }
+
+            let dec_res = cks.decrypt_crt(&ct_res);
+            assert_eq!(
+                (clear_0 + modulus - scalar % modulus) % modulus,
+                dec_res % modulus
+            );
+        }
+    }
+}
+
 fn integer_unchecked_crt_mul(param: ClassicPBSParameters) {
     // generate the server-client key set
     let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::CRT);
@@ -380,6 +452,21 @@ fn integer_smart_crt_scalar_mul(param: ClassicPBSParameters) {
 
         assert_eq!((clear_0 * clear_1) % modulus, dec_res % modulus);
     }
+
+    // Chained multiplications, the degrees grow until the carries have to be cleaned
+    let mut clear_0 = rng.gen::<u64>() % modulus;
+    let mut ct_zero = cks.encrypt_crt(clear_0, basis);
+
+    for _ in 0..NB_TESTS_SMALLER {
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        ct_zero = sks.smart_crt_scalar_mul(&mut ct_zero, clear_1);
+
+        let dec_res = cks.decrypt_crt(&ct_zero);
+
+        clear_0 = (clear_0 * clear_1) % modulus;
+        assert_eq!(clear_0, dec_res % modulus);
+    }
 }
 
 fn integer_smart_crt_scalar_sub(param: ClassicPBSParameters) {
@@ -0,0 +1,416 @@
+use crate::integer::prelude::ServerKeyDefaultCMux;
+use crate::integer::{BooleanBlock, CrtCiphertext, RadixCiphertext, ServerKey};
+use crate::shortint::server_key::BivariateLookupTableOwned;
+use crate::shortint::MessageModulus;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// FHE "if then else" selection between two ciphertexts in CRT decomposition.
+    ///
+    /// Returns a new ciphertext that encrypts the same value
+    /// as either true_ct or false_ct depending on the value of condition:
+    ///
+    /// - If condition == 1, the returned ciphertext will encrypt the same value as true_ct.
+    /// - If condition == 0, the returned ciphertext will encrypt the same value as false_ct.
+    ///
+    /// The branches do not need to have the same degrees (e.g. one of them may be the result of a
+    /// multiplication), as each residue of both branches is first refreshed so that its degree is
+    /// below its modulus.
+    ///
+    /// The selection costs 5 PBS per residue: one refresh per branch, one to shift the condition
+    /// above the residue, and one selection per branch. The refreshes and the condition shift are
+    /// done in parallel, followed by the two selections in parallel.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// - if true_ct and false_ct do not have the same basis
+    /// - if the parameters do not have room for twice the biggest modulus of the basis
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_crt;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let basis = vec![2, 3, 5];
+    /// let modulus: u64 = basis.iter().product();
+    /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128, basis);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 11;
+    ///
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// let product = sks.unchecked_crt_mul_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// for condition in [false, true] {
+    ///     let ct_condition = cks.as_ref().encrypt_bool(condition);
+    ///     let ct_res = sks.crt_if_then_else_parallelized(&ct_condition, &product, &ctxt_1);
+    ///
+    ///     // Decrypt
+    ///     let res = cks.decrypt(&ct_res);
+    ///     let expected = if condition {
+    ///         (clear_1 * clear_2) % modulus
+    ///     } else {
+    ///         clear_1
+    ///     };
+    ///     assert_eq!(res, expected);
+    /// }
+    /// ```
+    pub fn crt_if_then_else_parallelized(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: &CrtCiphertext,
+        false_ct: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        assert_eq!(
+            true_ct.moduli, false_ct.moduli,
+            "true_ct and false_ct must have the same basis"
+        );
+        assert!(condition.0.degree.get() <= 1);
+
+        let total_modulus = self.key.message_modulus.0 * self.key.carry_modulus.0;
+        assert!(
+            true_ct
+                .moduli
+                .iter()
+                .all(|modulus| 2 * modulus <= total_modulus),
+            "The parameters must have room for twice the biggest modulus of the basis"
+        );
+
+        let blocks = true_ct
+            .blocks
+            .par_iter()
+            .zip(false_ct.blocks.par_iter())
+            .zip(true_ct.moduli.par_iter())
+            .map(|((true_block, false_block), &modulus)| {
+                // Refresh the residues of both branches, and move the condition just above
+                // the residues, so that a residue and the condition can be added together
+                // and then be extracted by a PBS
+                let refresh_lut = self.key.generate_lookup_table(|x| x % modulus);
+                let condition_lut =
+                    self.key
+                        .generate_lookup_table(|x| if x == 1 { modulus } else { 0 });
+                let ((mut true_block, mut false_block), shifted_condition) = rayon::join(
+                    || {
+                        rayon::join(
+                            || self.key.apply_lookup_table(true_block, &refresh_lut),
+                            || self.key.apply_lookup_table(false_block, &refresh_lut),
+                        )
+                    },
+                    || self.key.apply_lookup_table(&condition.0, &condition_lut),
+                );
+
+                let true_lut = self.key.generate_lookup_table(|x| {
+                    if x >= modulus {
+                        (x - modulus) % modulus
+                    } else {
+                        0
+                    }
+                });
+                let false_lut = self
+                    .key
+                    .generate_lookup_table(|x| if x < modulus { x } else { 0 });
+                rayon::join(
+                    || {
+                        self.key
+                            .unchecked_add_assign(&mut true_block, &shifted_condition);
+                        self.key
+                            .apply_lookup_table_assign(&mut true_block, &true_lut);
+                    },
+                    || {
+                        self.key
+                            .unchecked_add_assign(&mut false_block, &shifted_condition);
+                        self.key
+                            .apply_lookup_table_assign(&mut false_block, &false_lut);
+                    },
+                );
+
+                // One of the two blocks encrypts 0
+                self.key.unchecked_add_assign(&mut true_block, &false_block);
+                true_block
+            })
+            .collect::<Vec<_>>();
+
+        CrtCiphertext::from((blocks, true_ct.moduli.clone()))
+    }
+
+    /// Masks all the residues of a ciphertext in CRT decomposition with a single condition.
+    ///
+    /// - If condition == 1, the returned ciphertext encrypts the same value as ct.
+    /// - If condition == 0, the returned ciphertext encrypts 0.
+    ///
+    /// Each residue is packed with the condition and goes through one bivariate PBS, all the
+    /// residues are processed in parallel. The PBS also reduces the residue modulo its modulus,
+    /// so the returned residues have a degree below their modulus. Residues that cannot be
+    /// packed with the condition (because of their degree or noise level) have their message
+    /// extracted first.
+    ///
+    /// The returned ciphertext has the same basis as ct.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// - if the parameters do not have room for twice the biggest modulus of the basis
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_crt;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let basis = vec![2, 3, 5];
+    /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128, basis);
+    ///
+    /// let clear = 23;
+    /// let ctxt = cks.encrypt(clear);
+    ///
+    /// for condition in [false, true] {
+    ///     let ct_condition = cks.as_ref().encrypt_bool(condition);
+    ///     let ct_res = sks.crt_mask_if_parallelized(&ctxt, &ct_condition);
+    ///
+    ///     // Decrypt
+    ///     let res = cks.decrypt(&ct_res);
+    ///     assert_eq!(res, if condition { clear } else { 0 });
+    /// }
+    /// ```
+    pub fn crt_mask_if_parallelized(
+        &self,
+        ct: &CrtCiphertext,
+        condition: &BooleanBlock,
+    ) -> CrtCiphertext {
+        assert!(condition.0.degree.get() <= 1);
+
+        let total_modulus = self.key.message_modulus.0 * self.key.carry_modulus.0;
+        assert!(
+            ct.moduli.iter().all(|modulus| 2 * modulus <= total_modulus),
+            "The parameters must have room for twice the biggest modulus of the basis"
+        );
+
+        let blocks = ct
+            .blocks
+            .par_iter()
+            .zip(ct.moduli.par_iter())
+            .map(|(block, &modulus)| {
+                let mut block = block.clone();
+                // The residue is multiplied by 2 to make room for the condition
+                let can_be_packed = block.degree.get() * 2 + 1 < total_modulus
+                    && self
+                        .key
+                        .max_noise_level
+                        .validate(block.noise_level() * 2 + condition.0.noise_level())
+                        .is_ok();
+                if !can_be_packed {
+                    self.key.message_extract_assign(&mut block);
+                }
+
+                // The residue may be greater than the message modulus of the parameters,
+                // so the lookup table is built on the packed value directly
+                let lut = BivariateLookupTableOwned {
+                    acc: self.key.generate_lookup_table(|x| {
+                        let (residue, condition) = (x / 2, x % 2);
+                        if condition == 1 {
+                            residue % modulus
+                        } else {
+                            0
+                        }
+                    }),
+                    ct_right_modulus: MessageModulus(2),
+                };
+                self.key.unchecked_apply_lookup_table_bivariate_assign(
+                    &mut block,
+                    &condition.0,
+                    &lut,
+                );
+                block
+            })
+            .collect::<Vec<_>>();
+
+        CrtCiphertext::from((blocks, ct.moduli.clone()))
+    }
+
+    /// Adds `x` to `acc` if the radix `condition` is true, both being ciphertexts in CRT
+    /// decomposition
+    ///
+    /// `condition` is a [BooleanBlock] coming from the radix representation (e.g. the result of
+    /// a radix comparison), it is used to mask each residue of `x` before the residue-wise
+    /// addition, so no conversion between the representations is needed.
+    ///
+    /// The masking is done by [Self::crt_mask_if_parallelized], with one bivariate PBS per
+    /// residue. If the residues of `acc` cannot receive the masked residues, their messages are
+    /// extracted first.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1.
+    ///
+    /// # Panics
+    ///
+    /// - if acc and x do not have the same basis
+    /// - if the parameters do not have room for twice the biggest modulus of the basis
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_crt;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let basis = vec![2, 3, 5];
+    /// let modulus: u64 = basis.iter().product();
+    /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128, basis);
+    ///
+    /// let clear_acc = 14;
+    /// let clear_x = 23;
+    ///
+    /// let mut ctxt_acc = cks.encrypt(clear_acc);
+    /// let ctxt_x = cks.encrypt(clear_x);
+    ///
+    /// // The condition comes from a comparison of radix ciphertexts
+    /// let num_blocks = 2;
+    /// let ctxt_a = cks.as_ref().encrypt_radix(7u64, num_blocks);
+    /// let ctxt_b = cks.as_ref().encrypt_radix(42u64, num_blocks);
+    /// let condition = sks.lt_parallelized(&ctxt_a, &ctxt_b);
+    ///
+    /// sks.crt_conditional_add_from_radix_bool_parallelized(&mut ctxt_acc, &ctxt_x, &condition);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt(&ctxt_acc);
+    /// assert_eq!(res, (clear_acc + clear_x) % modulus);
+    /// ```
+    pub fn crt_conditional_add_from_radix_bool_parallelized(
+        &self,
+        acc: &mut CrtCiphertext,
+        x: &CrtCiphertext,
+        condition: &BooleanBlock,
+    ) {
+        assert_eq!(acc.moduli, x.moduli, "acc and x must have the same basis");
+
+        let masked_x = self.crt_mask_if_parallelized(x, condition);
+
+        if self.is_crt_add_possible(acc, &masked_x).is_err() {
+            self.full_extract_message_assign_parallelized(acc);
+        }
+        self.is_crt_add_possible(acc, &masked_x).unwrap();
+
+        self.unchecked_crt_add_assign_parallelized(acc, &masked_x);
+    }
+
+    /// Conditionally synchronizes a value kept both in radix and in CRT decomposition.
+    ///
+    /// - If use_radix == 1, the radix value is authoritative: it is converted to the basis of
+    ///   `crt`, and `crt` is overwritten with the result.
+    /// - If use_radix == 0, the CRT value is authoritative: it is converted to a radix ciphertext
+    ///   with the same number of blocks as `radix`, and `radix` is overwritten with the result.
+    ///
+    /// # Cost
+    ///
+    /// The conversions are expensive: the CRT to radix conversion costs as much as the
+    /// reconstruction done by [Self::crt_lt_parallelized], and the radix to CRT conversion costs
+    /// at least one PBS per radix block per modulus of the basis.
+    ///
+    /// Exactly one conversion runs per call only when `use_radix` is a trivial ciphertext and
+    /// `constant_time` is false. Otherwise, as the direction of the conversion is not known (or
+    /// must not leak through the execution time), both conversions run in parallel, followed by
+    /// a selection on each representation, so the cost is the same whatever the condition.
+    ///
+    /// If the product of the basis does not fit in the number of blocks of `radix`, the value
+    /// written in `radix` is reduced modulo the radix modulus. Likewise, the value written in `crt`
+    /// is the radix value reduced modulo the product of the basis.
+    ///
+    /// To ensure correct results, use_radix must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// - if `radix` has no blocks
+    /// - if the parameters do not have room for twice the biggest modulus of the basis
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_crt;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let basis = vec![2, 3, 5];
+    /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128, basis);
+    ///
+    /// // 2 blocks of 3 bits are enough to hold any value modulo 30
+    /// let num_blocks = 2;
+    /// let mut ctxt_radix = cks.as_ref().encrypt_radix(17u64, num_blocks);
+    /// let mut ctxt_crt = cks.encrypt(17);
+    ///
+    /// // The radix value is updated, and is the authoritative one
+    /// sks.scalar_add_assign_parallelized(&mut ctxt_radix, 5u64);
+    /// let use_radix = cks.as_ref().encrypt_bool(true);
+    /// sks.conditional_sync_representations_parallelized(
+    ///     &mut ctxt_radix,
+    ///     &mut ctxt_crt,
+    ///     &use_radix,
+    ///     true,
+    /// );
+    ///
+    /// // Decrypt
+    /// let res_radix: u64 = cks.as_ref().decrypt_radix(&ctxt_radix);
+    /// let res_crt = cks.decrypt(&ctxt_crt);
+    /// assert_eq!(res_radix, 22);
+    /// assert_eq!(res_crt, 22);
+    /// ```
+    pub fn conditional_sync_representations_parallelized(
+        &self,
+        radix: &mut RadixCiphertext,
+        crt: &mut CrtCiphertext,
+        use_radix: &BooleanBlock,
+        constant_time: bool,
+    ) {
+        let num_blocks = radix.blocks.len();
+        assert!(num_blocks > 0, "radix must have at least one block");
+
+        let crt_to_radix = |crt: &CrtCiphertext| {
+            let mut converted = self.crt_to_radix_parallelized(crt);
+            let converted_num_blocks = converted.blocks.len();
+            if converted_num_blocks > num_blocks {
+                self.trim_radix_blocks_msb_assign(
+                    &mut converted,
+                    converted_num_blocks - num_blocks,
+                );
+            } else {
+                self.extend_radix_with_trivial_zero_blocks_msb_assign(
+                    &mut converted,
+                    num_blocks - converted_num_blocks,
+                );
+            }
+            converted
+        };
+
+        if !constant_time {
+            if let Ok(use_radix) = use_radix.decrypt_trivial() {
+                if use_radix {
+                    *crt = self.radix_to_crt_parallelized(radix, &crt.moduli);
+                } else {
+                    *radix = crt_to_radix(crt);
+                }
+                return;
+            }
+        }
+
+        let (converted_crt, converted_radix) = rayon::join(
+            || self.radix_to_crt_parallelized(radix, &crt.moduli),
+            || crt_to_radix(crt),
+        );
+
+        let (new_crt, new_radix) = rayon::join(
+            || self.crt_if_then_else_parallelized(use_radix, &converted_crt, crt),
+            || self.if_then_else_parallelized(use_radix, &*radix, &converted_radix),
+        );
+        *crt = new_crt;
+        *radix = new_radix;
+    }
+}
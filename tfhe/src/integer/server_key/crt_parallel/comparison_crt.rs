@@ -0,0 +1,193 @@
+use crate::integer::{BooleanBlock, CrtCiphertext, RadixCiphertext, ServerKey};
+use crate::shortint::{CarryModulus, MessageModulus};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes homomorphically `a < b` between two ciphertexts in CRT decomposition.
+    ///
+    /// Returns a [BooleanBlock] that can be used as the condition of a radix CMUX
+    /// (e.g. [Self::if_then_else_parallelized] or [Self::crt_if_then_else_parallelized]).
+    ///
+    /// The CRT representation is not ordered, so both ciphertexts are first reconstructed
+    /// into radix ciphertexts using the Chinese remainder theorem:
+    /// `x = (sum_i r_i * c_i) mod M`, where `M` is the product of the basis, and
+    /// `c_i = (M / m_i) * ((M / m_i)^-1 mod m_i)`.
+    ///
+    /// # Cost
+    ///
+    /// For each operand, with `n` the number of moduli in the basis, and `k` the number of blocks
+    /// needed to represent `n * M`:
+    /// - `n * k` PBS to compute the radix decomposition of each `r_i * c_i mod M`,
+    /// - a sum of `n` radix ciphertexts of `k` blocks,
+    /// - a scalar remainder by `M`.
+    ///
+    /// Then, a radix comparison of the two reconstructed values is done. This is much more
+    /// expensive than CRT additions or multiplications, so it should be used sparingly.
+    ///
+    /// # Panics
+    ///
+    /// - if a and b do not have the same basis
+    /// - if the reconstructed values do not fit in a u128
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_crt;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let basis = vec![2, 3, 5];
+    /// let (cks, sks) = gen_keys_crt(PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128, basis);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 23;
+    ///
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// let ct_res = sks.crt_lt_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.as_ref().decrypt_bool(&ct_res);
+    /// assert!(res);
+    /// ```
+    pub fn crt_lt_parallelized(&self, a: &CrtCiphertext, b: &CrtCiphertext) -> BooleanBlock {
+        assert_eq!(a.moduli, b.moduli, "a and b must have the same basis");
+
+        let (a, b) = rayon::join(
+            || self.crt_to_radix_parallelized(a),
+            || self.crt_to_radix_parallelized(b),
+        );
+
+        self.lt_parallelized(&a, &b)
+    }
+
+    /// Reconstructs the value of a ciphertext in CRT decomposition into a radix ciphertext
+    pub(crate) fn crt_to_radix_parallelized(&self, ct: &CrtCiphertext) -> RadixCiphertext {
+        let message_modulus = self.message_modulus().0;
+
+        let modulus = ct
+            .moduli
+            .iter()
+            .try_fold(1u128, |acc, modulus| acc.checked_mul(u128::from(*modulus)))
+            .expect("The product of the basis must fit in a u128");
+
+        // Number of blocks to represent the sum of all the r_i * c_i mod M
+        let max_sum = modulus * ct.moduli.len() as u128;
+        let mut num_blocks = 0u32;
+        let mut num_representable = 1u128;
+        while num_representable < max_sum {
+            num_representable = num_representable.saturating_mul(u128::from(message_modulus));
+            num_blocks += 1;
+        }
+
+        let terms = ct
+            .blocks
+            .par_iter()
+            .zip(ct.moduli.par_iter())
+            .map(|(block, &modulus_i)| {
+                let modulus_i = u128::from(modulus_i);
+                let partial_modulus = modulus / modulus_i;
+                let inverse = (1..modulus_i)
+                    .find(|candidate| (partial_modulus % modulus_i) * candidate % modulus_i == 1)
+                    .unwrap_or(0);
+                let coefficient = partial_modulus * inverse;
+
+                let blocks = (0..num_blocks)
+                    .into_par_iter()
+                    .map(|block_index| {
+                        let lut = self.key.generate_lookup_table(|x| {
+                            let residue = u128::from(x) % modulus_i;
+                            let term = residue * coefficient % modulus;
+                            u128::from(message_modulus)
+                                .checked_pow(block_index)
+                                .map_or(0, |weight| term / weight % u128::from(message_modulus))
+                                as u64
+                        });
+                        let mut radix_block = self.key.apply_lookup_table(block, &lut);
+                        // The output is a radix block, not a residue of the CRT basis
+                        radix_block.message_modulus = self.key.message_modulus;
+                        radix_block.carry_modulus = self.key.carry_modulus;
+                        radix_block
+                    })
+                    .collect::<Vec<_>>();
+                RadixCiphertext::from(blocks)
+            })
+            .collect::<Vec<_>>();
+
+        let sum: RadixCiphertext = self
+            .sum_ciphertexts_parallelized(terms.iter())
+            .expect("The basis must not be empty");
+
+        self.scalar_rem_parallelized(&sum, modulus)
+    }
+
+    /// Decomposes the value of a radix ciphertext into residues modulo each of the `moduli`
+    ///
+    /// Each residue is `r_i = (sum_j b_j * (B^j mod m_i)) mod m_i`, where `b_j` are the blocks of
+    /// ct and `B` is the message modulus. This costs one PBS per block of ct per modulus for the
+    /// terms of the sum, plus the PBS needed to reduce the partial sums when they cannot receive
+    /// more terms.
+    pub(crate) fn radix_to_crt_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        moduli: &[u64],
+    ) -> CrtCiphertext {
+        assert!(!ct.blocks.is_empty(), "Cannot convert an empty ciphertext");
+
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        let message_modulus = self.message_modulus().0;
+        let total_modulus = message_modulus * self.carry_modulus().0;
+
+        let blocks = moduli
+            .par_iter()
+            .map(|&modulus| {
+                let terms = ct
+                    .blocks
+                    .par_iter()
+                    .enumerate()
+                    .map(|(block_index, block)| {
+                        let weight = (0..block_index)
+                            .fold(1 % modulus, |acc, _| acc * message_modulus % modulus);
+                        let lut = self.key.generate_lookup_table(|x| x * weight % modulus);
+                        let mut term = self.key.apply_lookup_table(block, &lut);
+                        // The output is a residue of the CRT basis, not a radix block
+                        term.message_modulus = MessageModulus(modulus);
+                        term.carry_modulus = CarryModulus(total_modulus / modulus);
+                        term
+                    })
+                    .collect::<Vec<_>>();
+
+                let reduce_lut = self.key.generate_lookup_table(|x| x % modulus);
+                let mut terms = terms.into_iter();
+                let mut residue = terms.next().unwrap();
+                for term in terms {
+                    if self
+                        .key
+                        .is_add_possible(residue.noise_degree(), term.noise_degree())
+                        .is_err()
+                    {
+                        self.key
+                            .apply_lookup_table_assign(&mut residue, &reduce_lut);
+                    }
+                    self.key.unchecked_add_assign(&mut residue, &term);
+                }
+                if residue.degree.get() >= modulus {
+                    self.key
+                        .apply_lookup_table_assign(&mut residue, &reduce_lut);
+                }
+                residue
+            })
+            .collect::<Vec<_>>();
+
+        CrtCiphertext::from((blocks, moduli.to_vec()))
+    }
+}
@@ -1,4 +1,6 @@
 mod add_crt;
+mod cmux_crt;
+mod comparison_crt;
 mod mul_crt;
 mod neg_crt;
 mod scalar_add_crt;
@@ -59,7 +59,10 @@ impl ServerKey {
             .zip(ct.moduli.par_iter())
             .for_each(|(ct_i, mod_i)| {
                 let scalar_i = scalar % mod_i;
-                self.key.unchecked_scalar_add_assign(ct_i, scalar_i as u8);
+                // Adding 0 to this residue is a no-op
+                if scalar_i != 0 {
+                    self.key.unchecked_scalar_add_assign(ct_i, scalar_i as u8);
+                }
             });
     }
 
@@ -202,7 +202,7 @@ impl ServerKey {
     /// assert_eq!((clear_1 * clear_2) % modulus, res);
     /// ```
     pub fn smart_crt_scalar_mul_assign_parallelized(&self, ctxt: &mut CrtCiphertext, scalar: u64) {
-        if self.is_crt_small_scalar_mul_possible(ctxt, scalar).is_err() {
+        if self.is_crt_scalar_mul_possible(ctxt, scalar).is_err() {
             self.full_extract_message_assign_parallelized(ctxt);
         }
         self.is_crt_scalar_mul_possible(ctxt, scalar).unwrap();
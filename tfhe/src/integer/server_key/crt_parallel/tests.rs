@@ -207,3 +207,210 @@ fn integer_unchecked_crt_scalar_sub_parallelized_32_bits() {
         );
     }
 }
+
+#[test]
+fn integer_crt_if_then_else_parallelized_32_bits() {
+    // Define CRT basis, and global modulus
+    let basis = [3u64, 11, 13, 19, 23, 29, 31, 32];
+
+    // Use u128 to avoid overflows as the modulus is slightly larger than 32 bits
+    let modulus = basis.iter().copied().map(u128::from).product::<u128>();
+    let (cks, sks) = KEY_CACHE.get_from_params(PARAM, IntegerKeyKind::CRT);
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u128>() % modulus;
+        let clear_1 = rng.gen::<u128>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let ct_zero = cks.encrypt_crt(clear_0 as u64, basis.to_vec());
+        let ct_one = cks.encrypt_crt(clear_1 as u64, basis.to_vec());
+        let ct_condition = cks.encrypt_bool(clear_condition);
+
+        // Select between a fresh value and the result of a multiplication
+        let ct_product = sks.unchecked_crt_mul_parallelized(&ct_zero, &ct_one);
+
+        let ct_res = sks.crt_if_then_else_parallelized(&ct_condition, &ct_product, &ct_zero);
+        let dec_res = cks.decrypt_crt(&ct_res);
+        let expected = if clear_condition {
+            (clear_0 * clear_1) % modulus
+        } else {
+            clear_0
+        };
+        assert_eq!(expected as u64, dec_res % modulus as u64);
+
+        let ct_res = sks.crt_if_then_else_parallelized(&ct_condition, &ct_zero, &ct_product);
+        let dec_res = cks.decrypt_crt(&ct_res);
+        let expected = if clear_condition {
+            clear_0
+        } else {
+            (clear_0 * clear_1) % modulus
+        };
+        assert_eq!(expected as u64, dec_res % modulus as u64);
+    }
+}
+
+#[test]
+fn integer_crt_lt_parallelized() {
+    let basis = [2u64, 3, 5];
+    let modulus = basis.iter().product::<u64>();
+
+    let param = TEST_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128;
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::CRT);
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        // Also test equal values
+        let clear_1 = if rng.gen_bool(0.2) {
+            clear_0
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let ct_zero = cks.encrypt_crt(clear_0, basis.to_vec());
+        let ct_one = cks.encrypt_crt(clear_1, basis.to_vec());
+
+        let ct_res = sks.crt_lt_parallelized(&ct_zero, &ct_one);
+        let dec_res = cks.decrypt_bool(&ct_res);
+        assert_eq!(
+            dec_res,
+            clear_0 < clear_1,
+            "Invalid result for {clear_0} < {clear_1}"
+        );
+    }
+}
+
+#[test]
+fn integer_crt_conditional_add_from_radix_bool_parallelized() {
+    let basis = [2u64, 3, 5, 7];
+    let modulus = basis.iter().product::<u64>();
+    // Radix values of 2 blocks of 3 bits
+    let num_blocks = 2;
+    let radix_modulus = 1u64 << 6;
+
+    let param = TEST_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128;
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::CRT);
+    let mut rng = rand::thread_rng();
+
+    let mut ct_acc = cks.encrypt_crt(0, basis.to_vec());
+    let mut clear_acc = 0u64;
+
+    // Accumulate many times so that the residues of acc need to be cleaned
+    for _ in 0..NB_TESTS {
+        let clear_x = rng.gen::<u64>() % modulus;
+        let clear_a = rng.gen::<u64>() % radix_modulus;
+        let clear_b = rng.gen::<u64>() % radix_modulus;
+
+        let ct_x = cks.encrypt_crt(clear_x, basis.to_vec());
+        let ct_a = cks.encrypt_radix(clear_a, num_blocks);
+        let ct_b = cks.encrypt_radix(clear_b, num_blocks);
+
+        let ct_condition = sks.lt_parallelized(&ct_a, &ct_b);
+        sks.crt_conditional_add_from_radix_bool_parallelized(&mut ct_acc, &ct_x, &ct_condition);
+
+        if clear_a < clear_b {
+            clear_acc = (clear_acc + clear_x) % modulus;
+        }
+        let dec_res = cks.decrypt_crt(&ct_acc);
+        assert_eq!(
+            dec_res % modulus,
+            clear_acc,
+            "Invalid result after adding {clear_x} if {clear_a} < {clear_b}"
+        );
+    }
+}
+
+#[test]
+fn integer_crt_mask_if_parallelized() {
+    let basis = [2u64, 3, 5];
+    let modulus = basis.iter().product::<u64>();
+
+    let param = TEST_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128;
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::CRT);
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let ct_zero = cks.encrypt_crt(clear_0, basis.to_vec());
+        let ct_one = cks.encrypt_crt(clear_1, basis.to_vec());
+        let ct_condition = cks.encrypt_bool(clear_condition);
+
+        let ct_res = sks.crt_mask_if_parallelized(&ct_zero, &ct_condition);
+        assert_eq!(ct_res.moduli, basis.to_vec());
+        let dec_res = cks.decrypt_crt(&ct_res);
+        let expected = if clear_condition { clear_0 } else { 0 };
+        assert_eq!(dec_res % modulus, expected);
+
+        // Residues with degrees above their modulus
+        let ct_sum = sks.unchecked_crt_add_parallelized(&ct_zero, &ct_one);
+        let ct_res = sks.crt_mask_if_parallelized(&ct_sum, &ct_condition);
+        let dec_res = cks.decrypt_crt(&ct_res);
+        let expected = if clear_condition {
+            (clear_0 + clear_1) % modulus
+        } else {
+            0
+        };
+        assert_eq!(dec_res % modulus, expected);
+    }
+}
+
+#[test]
+fn integer_crt_conditional_sync_representations_parallelized() {
+    let basis = [2u64, 3, 5];
+    let modulus = basis.iter().product::<u64>();
+    // Radix values of 2 blocks of 3 bits
+    let num_blocks = 2;
+    let radix_modulus = 1u64 << 6;
+
+    let param = TEST_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128;
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::CRT);
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TESTS {
+        let clear_radix = rng.gen::<u64>() % radix_modulus;
+        let clear_crt = rng.gen::<u64>() % modulus;
+        let clear_use_radix = rng.gen_bool(0.5);
+        let constant_time = rng.gen_bool(0.5);
+
+        let use_radix = if rng.gen_bool(0.5) {
+            cks.encrypt_bool(clear_use_radix)
+        } else {
+            sks.create_trivial_boolean_block(clear_use_radix)
+        };
+
+        let mut ct_radix = cks.encrypt_radix(clear_radix, num_blocks);
+        let mut ct_crt = cks.encrypt_crt(clear_crt, basis.to_vec());
+
+        sks.conditional_sync_representations_parallelized(
+            &mut ct_radix,
+            &mut ct_crt,
+            &use_radix,
+            constant_time,
+        );
+
+        let expected = if clear_use_radix {
+            clear_radix
+        } else {
+            clear_crt
+        };
+        assert_eq!(ct_radix.blocks.len(), num_blocks);
+        assert_eq!(ct_crt.moduli, basis.to_vec());
+        let dec_radix: u64 = cks.decrypt_radix(&ct_radix);
+        let dec_crt = cks.decrypt_crt(&ct_crt);
+        assert_eq!(
+            dec_radix, expected,
+            "Invalid radix after sync of {clear_radix} and {clear_crt} \
+            with use_radix {clear_use_radix}"
+        );
+        assert_eq!(
+            dec_crt % modulus,
+            expected % modulus,
+            "Invalid CRT after sync of {clear_radix} and {clear_crt} \
+            with use_radix {clear_use_radix}"
+        );
+    }
+}
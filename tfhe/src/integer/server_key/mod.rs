@@ -18,8 +18,13 @@ pub use crate::shortint::CheckError;
 use crate::shortint::{CarryModulus, MessageModulus, PBSParameters};
 pub use radix::scalar_mul::ScalarMultiplier;
 pub use radix::scalar_sub::TwosComplementNegation;
-pub use radix_parallel::{MatchValues, MiniUnsignedInteger, Reciprocable};
+use radix_parallel::cmux::BooleanCmuxLuts;
+pub use radix_parallel::{
+    ArithmeticSelectPlan, CarrySave, MatchValues, MiniUnsignedInteger, ProgrammableSelectPlan,
+    Reciprocable, ReduceOp, SelectBuilder, ShiftDir,
+};
 use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
 use tfhe_versionable::Versionize;
 
 /// A structure containing the server public key.
@@ -33,6 +38,10 @@ use tfhe_versionable::Versionize;
 #[versionize(ServerKeyVersions)]
 pub struct ServerKey {
     pub(crate) key: crate::shortint::ServerKey,
+    // Lookup tables of the BooleanBlock cmux, they only depend on the parameters of the key and
+    // are generated on first use
+    #[serde(skip)]
+    pub(crate) boolean_cmux_luts: OnceLock<BooleanCmuxLuts>,
 }
 
 impl From<ServerKey> for crate::shortint::ServerKey {
@@ -105,7 +114,7 @@ impl ServerKey {
             max_degree,
         );
 
-        Self { key: sks }
+        Self::from_raw_parts(sks)
     }
 
     pub fn new_crt_server_key<C>(cks: C) -> Self
@@ -123,7 +132,7 @@ impl ServerKey {
             max_degree,
         );
 
-        Self { key: sks }
+        Self::from_raw_parts(sks)
     }
 
     /// Creates a ServerKey destined to be used with
@@ -154,7 +163,7 @@ impl ServerKey {
             MaxDegree::integer_radix_server_key(key.message_modulus, key.carry_modulus);
 
         key.max_degree = max_degree;
-        Self { key }
+        Self::from_raw_parts(key)
     }
 
     /// Creates a ServerKey destined to be used with
@@ -181,7 +190,7 @@ impl ServerKey {
         mut key: crate::shortint::server_key::ServerKey,
     ) -> Self {
         key.max_degree = MaxDegree::integer_crt_server_key(key.message_modulus, key.carry_modulus);
-        Self { key }
+        Self::from_raw_parts(key)
     }
 
     /// Deconstruct a [`ServerKey`] into its constituents.
@@ -191,7 +200,10 @@ impl ServerKey {
 
     /// Construct a [`ServerKey`] from its constituents.
     pub fn from_raw_parts(key: crate::shortint::ServerKey) -> Self {
-        Self { key }
+        Self {
+            key,
+            boolean_cmux_luts: OnceLock::new(),
+        }
     }
 
     pub fn deterministic_pbs_execution(&self) -> bool {
@@ -270,9 +282,7 @@ impl CompressedServerKey {
 
     /// Decompress a [`CompressedServerKey`] into a [`ServerKey`].
     pub fn decompress(&self) -> ServerKey {
-        ServerKey {
-            key: self.key.decompress(),
-        }
+        ServerKey::from_raw_parts(self.key.decompress())
     }
 
     /// Deconstruct a [`CompressedServerKey`] into its constituents.
@@ -306,7 +316,10 @@ impl ParameterSetConformant for ServerKey {
     type ParameterSet = PBSParameters;
 
     fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
-        let Self { key } = self;
+        let Self {
+            key,
+            boolean_cmux_luts: _,
+        } = self;
 
         let expected_max_degree = MaxDegree::integer_radix_server_key(
             parameter_set.message_modulus(),
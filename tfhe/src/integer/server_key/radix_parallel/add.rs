@@ -1,5 +1,6 @@
 use crate::core_crypto::commons::numeric::UnsignedInteger;
 use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::prelude::ServerKeyDefaultCMux;
 use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
 use crate::shortint::ciphertext::Degree;
 use crate::shortint::Ciphertext;
@@ -336,6 +337,160 @@ impl ServerKey {
         self.overflowing_add_assign_with_carry(lhs, rhs, None)
     }
 
+    /// Computes `a + b + carry_in` and returns the carry out of the addition
+    ///
+    /// The `carry_in` is added to the first (least significant) block and propagated with the
+    /// carries of the addition. The returned carry out encrypts `true` if there is a carry out of
+    /// the last block, i.e. if the exact sum does not fit in the number of bits of the inputs.
+    ///
+    /// This is the limb adder of integers made of many radix ciphertexts: adding the limbs from
+    /// the least significant to the most significant one, using the carry out of a limb as the
+    /// carry in of the next one, gives the sum of the whole integers.
+    ///
+    /// The carry out is the unsigned carry even for signed ciphertexts, as the limbs of a
+    /// multi-limb integer are digits, use [Self::overflowing_add_parallelized] to know whether a
+    /// signed addition overflowed.
+    ///
+    /// To ensure correct results, `carry_in` must encrypt either 0 or 1.
+    ///
+    /// # Panics
+    ///
+    /// - if `a` and `b` do not have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message per limb
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// // 16 bits integers, as two limbs of 8 bits
+    /// let a = 0x12F0u16;
+    /// let b = 0x3420u16;
+    ///
+    /// let a_limbs = [cks.encrypt(a as u8), cks.encrypt((a >> 8) as u8)];
+    /// let b_limbs = [cks.encrypt(b as u8), cks.encrypt((b >> 8) as u8)];
+    ///
+    /// let mut carry = sks.create_trivial_boolean_block(false);
+    /// let mut sum_limbs = vec![];
+    /// for (a_limb, b_limb) in a_limbs.iter().zip(b_limbs.iter()) {
+    ///     let (sum_limb, carry_out) = sks.add_with_carry_in_parallelized(a_limb, b_limb, &carry);
+    ///     sum_limbs.push(sum_limb);
+    ///     carry = carry_out;
+    /// }
+    ///
+    /// // Decrypt:
+    /// let low: u8 = cks.decrypt(&sum_limbs[0]);
+    /// let high: u8 = cks.decrypt(&sum_limbs[1]);
+    /// assert_eq!(u16::from(low) | (u16::from(high) << 8), a.wrapping_add(b));
+    /// assert_eq!(cks.decrypt_bool(&carry), a.checked_add(b).is_none());
+    /// ```
+    pub fn add_with_carry_in_parallelized<T>(
+        &self,
+        a: &T,
+        b: &T,
+        carry_in: &BooleanBlock,
+    ) -> (T, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            a.blocks().len(),
+            b.blocks().len(),
+            "a and b must have the same number of blocks"
+        );
+
+        if a.blocks().is_empty() {
+            return (a.clone(), carry_in.clone());
+        }
+
+        let [a, b] = self.propagated_if_needed_parallelized([a, b]);
+        let mut sum = a.into_owned();
+
+        let carry_out = self
+            .advanced_add_assign_with_carry_parallelized(
+                sum.blocks_mut(),
+                b.blocks(),
+                Some(carry_in),
+                OutputFlag::Carry,
+                CarryPropagationAlgorithm::Automatic,
+            )
+            .expect("internal error, carry computation was not returned as was requested");
+
+        (sum, carry_out)
+    }
+
+    /// Computes `a + b`, then adds a fixup value selected by the overflow of that addition
+    ///
+    /// Returns `a + b + on_overflow` if `a + b` overflowed, `a + b + on_ok` otherwise, the final
+    /// addition wrapping around like [Self::add_parallelized].
+    ///
+    /// The overflow is the one of [Self::overflowing_add_parallelized]:
+    ///
+    /// - for unsigned ciphertexts, the addition overflowed if the exact sum does not fit in the
+    ///   number of bits of the inputs, that is, if there is a carry out of the last block;
+    /// - for signed ciphertexts, the addition overflowed if the exact sum is not in the range of
+    ///   the two's complement integers of the number of bits of the inputs, that is, if `a` and `b`
+    ///   have the same sign and the wrapped sum has a different sign.
+    ///
+    /// Using `on_ok = 0` keeps the sum as is when there is no overflow, so e.g. the modular
+    /// reductions that add a correction on overflow are a single call.
+    ///
+    /// # Panics
+    ///
+    /// - if the inputs do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 200u8;
+    /// let b = 100u8;
+    /// let fixup = 5u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    /// let ct_fixup = cks.encrypt(fixup);
+    /// let ct_zero = cks.encrypt(0u8);
+    ///
+    /// let ct_res = sks.add_then_select_on_overflow_parallelized(&ct_a, &ct_b, &ct_fixup, &ct_zero);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, a.wrapping_add(b).wrapping_add(fixup));
+    /// ```
+    pub fn add_then_select_on_overflow_parallelized<T>(
+        &self,
+        a: &T,
+        b: &T,
+        on_overflow: &T,
+        on_ok: &T,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = a.blocks().len();
+        assert!(
+            [b, on_overflow, on_ok]
+                .iter()
+                .all(|ct| ct.blocks().len() == num_blocks),
+            "All inputs must have the same number of blocks"
+        );
+
+        let (sum, overflowed) = self.overflowing_add_parallelized(a, b);
+        let fixup = self.if_then_else_parallelized(&overflowed, on_overflow, on_ok);
+        self.add_parallelized(&sum, &fixup)
+    }
+
     /// Computes the addition of two unsigned ciphertexts and returns the overflow flag
     ///
     /// # Example
@@ -458,6 +613,170 @@ impl ServerKey {
         );
     }
 
+    /// Adds a carry to a digit of a mixed-radix number
+    ///
+    /// Does `digit += carry_in`, and if the result reaches `base`, subtracts `base` from it.
+    /// Returns a boolean block that encrypts `true` if `base` was subtracted, i.e. the carry to add
+    /// to the next digit.
+    ///
+    /// This is the building block of additions in bases that are not the message modulus (e.g. a
+    /// mixed-radix representation where each digit has its own base).
+    ///
+    /// The digit must encrypt a value in `0..base`, with `base <= message_modulus`, so that it
+    /// holds in a single block. The digit returned has clean blocks and encrypts a value in
+    /// `0..base`.
+    ///
+    /// Costs 2 PBS, computed in parallel.
+    ///
+    /// # Panics
+    ///
+    /// - if the digit does not have exactly one block
+    /// - if base is 0 or greater than the message modulus
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // A digit of a single block
+    /// let size = 1;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// // Digits in base 3
+    /// let base = 3;
+    /// let mut digit = cks.encrypt(2u8);
+    /// let carry_in = cks.encrypt_bool(true);
+    ///
+    /// let carry_out = sks.conditional_digit_carry_parallelized(&mut digit, &carry_in, base);
+    ///
+    /// // Decrypt:
+    /// let dec_digit: u8 = cks.decrypt(&digit);
+    /// let dec_carry_out = cks.decrypt_bool(&carry_out);
+    /// assert_eq!(dec_digit, 0);
+    /// assert!(dec_carry_out);
+    /// ```
+    pub fn conditional_digit_carry_parallelized(
+        &self,
+        digit: &mut RadixCiphertext,
+        carry_in: &BooleanBlock,
+        base: u64,
+    ) -> BooleanBlock {
+        assert_eq!(
+            digit.blocks.len(),
+            1,
+            "The digit must have exactly one block"
+        );
+        assert!(
+            base > 0 && base <= self.message_modulus().0,
+            "The base must be in 1..=message_modulus"
+        );
+
+        let block = &mut digit.blocks[0];
+        if !block.carry_is_empty() {
+            self.key.message_extract_assign(block);
+        }
+
+        // As digit < base <= message_modulus, the sum is at most the message modulus,
+        // which fits in the block
+        self.key.unchecked_add_assign(block, &carry_in.0);
+
+        // As the sum is in 0..=base, subtracting base when the sum reaches it is a remainder
+        let sum = &*block;
+        let (carry_out, new_digit) = rayon::join(
+            || {
+                let lut = self.key.generate_lookup_table(|x| u64::from(x >= base));
+                BooleanBlock::new_unchecked(self.key.apply_lookup_table(sum, &lut))
+            },
+            || {
+                let lut = self.key.generate_lookup_table(|x| x % base);
+                self.key.apply_lookup_table(sum, &lut)
+            },
+        );
+        *block = new_digit;
+
+        carry_out
+    }
+
+    /// Computes homomorphically `(a + b) mod p`, for a clear modulus `p`
+    ///
+    /// `a` and `b` must both encrypt values smaller than `p`, so that `a + b < 2p` and a single
+    /// conditional subtraction of `p` is enough to reduce the sum.
+    ///
+    /// The sum is computed with one extra block when `2 * (p - 1)` does not fit in the number of
+    /// blocks of the inputs. `p` is then subtracted from the sum, and the borrow of that
+    /// subtraction, which encrypts `a + b < p`, selects between the sum and the difference. Thus
+    /// a single comparison to `p` is done, and it is shared with the subtraction.
+    ///
+    /// The result has the same number of blocks as the inputs, and clean blocks.
+    ///
+    /// # Panics
+    ///
+    /// - if `a` and `b` do not have the same number of blocks
+    /// - if `p` is 0, or if `p - 1` cannot be represented with the number of blocks of the inputs
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let p = 251u64;
+    /// let ct_a = cks.encrypt(200u64);
+    /// let ct_b = cks.encrypt(150u64);
+    ///
+    /// let ct_res = sks.add_mod_scalar_parallelized(&ct_a, &ct_b, p);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, (200 + 150) % p);
+    /// ```
+    pub fn add_mod_scalar_parallelized(
+        &self,
+        a: &RadixCiphertext,
+        b: &RadixCiphertext,
+        p: u64,
+    ) -> RadixCiphertext {
+        assert_eq!(
+            a.blocks.len(),
+            b.blocks.len(),
+            "a and b must have the same number of blocks"
+        );
+        assert!(p > 0, "The modulus must not be 0");
+        let num_blocks = a.blocks.len();
+        assert!(
+            self.num_blocks_to_represent_unsigned_value(p - 1) <= num_blocks,
+            "The modulus {p} is too large for {num_blocks} blocks"
+        );
+
+        let message_bits = self.message_modulus().0.ilog2() as usize;
+        let max_sum = 2 * (u128::from(p) - 1);
+        let needs_extra_block =
+            num_blocks * message_bits < 128 && max_sum >= 1u128 << (num_blocks * message_bits);
+
+        let sum = if needs_extra_block {
+            let (a, b) = rayon::join(
+                || self.extend_radix_with_trivial_zero_blocks_msb(a, 1),
+                || self.extend_radix_with_trivial_zero_blocks_msb(b, 1),
+            );
+            self.add_parallelized(&a, &b)
+        } else {
+            self.add_parallelized(a, b)
+        };
+
+        let (difference, is_below_p) = self.unsigned_overflowing_scalar_sub_parallelized(&sum, p);
+        let mut result = self.if_then_else_parallelized(&is_below_p, &sum, &difference);
+
+        if needs_extra_block {
+            self.trim_radix_blocks_msb_assign(&mut result, 1);
+        }
+        result
+    }
+
     /// Does lhs += (rhs + carry)
     ///
     /// Returns a boolean block that encrypts `true` if overflow happened
@@ -1,5 +1,5 @@
 use crate::integer::ciphertext::IntegerRadixCiphertext;
-use crate::integer::ServerKey;
+use crate::integer::{BooleanBlock, ServerKey};
 use rayon::prelude::*;
 
 impl ServerKey {
@@ -555,4 +555,151 @@ impl ServerKey {
             .iter_mut()
             .for_each(|block| self.key.bitnot_assign(block));
     }
+
+    /// Computes homomorphically a bitnot on a ciphertext, if the condition is true
+    ///
+    /// - If condition == 1, the returned ciphertext will encrypt `!ct`.
+    /// - If condition == 0, the returned ciphertext will encrypt the same value as `ct`.
+    ///
+    /// Each block is xored with a mask derived from the condition, this only needs one PBS per
+    /// block, which is cheaper than computing the bitnot and then selecting with a CMUX.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg = 14u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// for condition in [false, true] {
+    ///     let ct_condition = cks.encrypt_bool(condition);
+    ///     let ct_res = sks.conditional_bitnot_parallelized(&ct, &ct_condition);
+    ///
+    ///     // Decrypt:
+    ///     let dec_result: u8 = cks.decrypt(&ct_res);
+    ///     assert_eq!(dec_result, if condition { !msg } else { msg });
+    /// }
+    /// ```
+    pub fn conditional_bitnot_parallelized<T>(&self, ct: &T, condition: &BooleanBlock) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut ct_res = ct.clone();
+        self.conditional_bitnot_assign_parallelized(&mut ct_res, condition);
+        ct_res
+    }
+
+    pub fn conditional_bitnot_assign_parallelized<T>(&self, ct: &mut T, condition: &BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let condition_block = &condition.0;
+        assert!(condition_block.degree.get() <= 1);
+
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        if condition_block.degree.get() == 0 {
+            // The condition 'encrypts' 0, and only 0, nothing to do
+            return;
+        }
+
+        let message_mask = self.message_modulus().0 - 1;
+        let lut = self
+            .key
+            .generate_lookup_table_bivariate(move |block, condition| {
+                if condition == 1 {
+                    block ^ message_mask
+                } else {
+                    block
+                }
+            });
+
+        ct.blocks_mut().par_iter_mut().for_each(|block| {
+            self.key
+                .unchecked_apply_lookup_table_bivariate_assign(block, condition_block, &lut);
+        });
+    }
+
+    /// Computes homomorphically a bitxor between two ciphertexts, if the condition is true
+    ///
+    /// - If condition == 1, the returned ciphertext will encrypt `a ^ b`.
+    /// - If condition == 0, the returned ciphertext will encrypt the same value as `a`.
+    ///
+    /// `b` is first masked with the condition (i.e. zeroed if the condition is false), and then
+    /// xored into `a`, which is cheaper than computing the bitxor and then selecting with a CMUX.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` do not have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg1 = 14u8;
+    /// let msg2 = 97u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// for condition in [false, true] {
+    ///     let ct_condition = cks.encrypt_bool(condition);
+    ///     let ct_res = sks.conditional_xor_parallelized(&ct1, &ct2, &ct_condition);
+    ///
+    ///     // Decrypt:
+    ///     let dec_result: u8 = cks.decrypt(&ct_res);
+    ///     assert_eq!(dec_result, if condition { msg1 ^ msg2 } else { msg1 });
+    /// }
+    /// ```
+    pub fn conditional_xor_parallelized<T>(&self, a: &T, b: &T, condition: &BooleanBlock) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut ct_res = a.clone();
+        self.conditional_xor_assign_parallelized(&mut ct_res, b, condition);
+        ct_res
+    }
+
+    pub fn conditional_xor_assign_parallelized<T>(&self, a: &mut T, b: &T, condition: &BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            a.blocks().len(),
+            b.blocks().len(),
+            "Both ciphertexts must have the same number of blocks"
+        );
+
+        let (_, masked_b) = rayon::join(
+            || {
+                if !a.block_carries_are_empty() {
+                    self.full_propagate_parallelized(a);
+                }
+            },
+            || self.mask_with_selector(b, condition),
+        );
+
+        self.unchecked_bitxor_assign_parallelized(a, &masked_b);
+    }
 }
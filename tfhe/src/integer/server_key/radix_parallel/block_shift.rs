@@ -427,4 +427,96 @@ impl ServerKey {
         };
         self.block_barrel_shifter(lhs, amount, BarrelShifterOperation::LeftShift)
     }
+
+    /// Extracts `width` consecutive blocks of `ct`, starting at the block index
+    /// given by the encrypted `block_offset`
+    ///
+    /// Output block `k` is the input block at `block_offset + k`, positions that
+    /// fall past the end of `ct` (including when `block_offset` itself is out of range)
+    /// yield zero blocks.
+    ///
+    /// # Offset and endianness
+    ///
+    /// The offset is counted in blocks, not in bytes: a block holds
+    /// `log2(message_modulus)` bits, which is a byte only for parameters with 8 bits of
+    /// message per block. For a byte offset, multiply it by the number of blocks per byte
+    /// beforehand (e.g. with [Self::scalar_left_shift_parallelized], as it is a power of two).
+    ///
+    /// Blocks are read in the radix order, that is little-endian: block 0 is the least
+    /// significant one, and the extracted field has the same order. To parse a big-endian
+    /// stream, reverse the blocks of `ct` (the block order is public, so this is done in clear)
+    /// and index the field from the other end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// // The blocks are, from the least significant one: 0, 1, 2, 3
+    /// let ct = cks.encrypt(0b11_10_01_00u8);
+    ///
+    /// let offset = cks.encrypt(1u8);
+    /// let field = sks.extract_field_parallelized(&ct, &offset, 2);
+    /// assert_eq!(field.blocks().len(), 2);
+    /// let dec: u8 = cks.decrypt(&field);
+    /// assert_eq!(dec, 0b10_01);
+    ///
+    /// // The position past the end of ct yields a zero block
+    /// let offset = cks.encrypt(3u8);
+    /// let field = sks.extract_field_parallelized(&ct, &offset, 2);
+    /// let dec: u8 = cks.decrypt(&field);
+    /// assert_eq!(dec, 0b00_11);
+    /// ```
+    pub fn extract_field_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        block_offset: &RadixCiphertext,
+        width: usize,
+    ) -> RadixCiphertext {
+        assert!(width > 0, "width must be greater than 0");
+
+        let num_blocks = ct.blocks.len();
+        let mut padded_ct = ct.clone();
+        if !padded_ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut padded_ct);
+        }
+        // Padding with `width` zero blocks guarantees that every
+        // position past the end of `ct` reads a zero after the shift
+        self.extend_radix_with_trivial_zero_blocks_msb_assign(&mut padded_ct, width);
+
+        // The barrel shifter only looks at the bits of the amount that can
+        // address the padded ciphertext, so the amount must have at least that many blocks
+        let padded_len = padded_ct.blocks.len();
+        let num_shift_bits =
+            padded_len.ilog2() as usize + usize::from(!padded_len.is_power_of_two());
+        let message_bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        let num_amount_blocks = num_shift_bits.div_ceil(message_bits_per_block);
+
+        let mut amount = block_offset.clone();
+        if !amount.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut amount);
+        }
+        if amount.blocks.len() < num_amount_blocks {
+            let num_missing_blocks = num_amount_blocks - amount.blocks.len();
+            self.extend_radix_with_trivial_zero_blocks_msb_assign(&mut amount, num_missing_blocks);
+        }
+
+        let (shifted, is_in_range) = rayon::join(
+            || self.block_shift_left(&padded_ct, &amount),
+            // Offsets that do not fit in the shift bits wrap around in the barrel shifter
+            || self.scalar_lt_parallelized(&amount, num_blocks as u64),
+        );
+
+        let field = self.trim_radix_blocks_msb(&shifted, padded_len - width);
+        self.mask_with_selector(&field, &is_in_range)
+    }
 }
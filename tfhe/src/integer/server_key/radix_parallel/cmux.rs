@@ -1,8 +1,14 @@
+use super::cleaned_if_needed;
 use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
 use crate::integer::ciphertext::boolean_value::BooleanBlock;
 use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::server_key::comparator::ZeroComparisonType;
 use crate::integer::{RadixCiphertext, ServerKey, SignedRadixCiphertext};
+use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::server_key::{BivariateLookupTableOwned, LookupTableOwned};
+use crate::shortint::{CarryModulus, MessageModulus};
 use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
 
 pub trait ServerKeyDefaultCMux<TrueCt, FalseCt> {
     type Output;
@@ -52,6 +58,11 @@ where
     /// Note that while the returned ciphertext encrypts the same value as
     /// either true_ct or false_ct, it won't exactly be true_ct or false_ct.
     ///
+    /// Each output block only depends on the matching input blocks, not on the order in which
+    /// the threads process them. So when the server key uses deterministic PBS execution
+    /// (see `set_deterministic_pbs_execution`), the same inputs always give the same output
+    /// ciphertext.
+    ///
     /// ```rust
     /// use tfhe::integer::gen_keys_radix;
     /// use tfhe::integer::prelude::*;
@@ -83,22 +94,8 @@ where
         true_ct: &T,
         false_ct: &T,
     ) -> Self::Output {
-        let mut ct_clones = [None, None];
-        let mut ct_refs = [true_ct, false_ct];
-
-        ct_refs
-            .par_iter_mut()
-            .zip(ct_clones.par_iter_mut())
-            .for_each(|(ct_ref, ct_clone)| {
-                if !ct_ref.block_carries_are_empty() {
-                    let mut cloned = ct_ref.clone();
-                    self.full_propagate_parallelized(&mut cloned);
-                    *ct_ref = ct_clone.insert(cloned);
-                }
-            });
-
-        let [true_ct, false_ct] = ct_refs;
-        self.unchecked_if_then_else_parallelized(condition, true_ct, false_ct)
+        let [true_ct, false_ct] = self.propagated_if_needed_parallelized([true_ct, false_ct]);
+        self.unchecked_if_then_else_parallelized(condition, &*true_ct, &*false_ct)
     }
 }
 
@@ -153,7 +150,7 @@ where
     ) -> Self::Output {
         let mut tmp_true_ct;
 
-        let true_ct_ref = if true_ct.block_carries_are_empty() {
+        let true_ct_ref = if self.is_unchecked_scalar_if_then_else_possible(condition, true_ct) {
             true_ct
         } else {
             tmp_true_ct = true_ct.clone();
@@ -233,7 +230,7 @@ where
     ) -> Self::Output {
         let mut tmp_true_ct;
 
-        let true_ct_ref = if true_ct.block_carries_are_empty() {
+        let true_ct_ref = if self.is_unchecked_scalar_if_then_else_possible(condition, true_ct) {
             true_ct
         } else {
             tmp_true_ct = true_ct.clone();
@@ -355,41 +352,112 @@ impl ServerKeyDefaultCMux<&BooleanBlock, &BooleanBlock> for ServerKey {
             "At least 2 bits of plaintext are required"
         );
 
-        let zero_lut = self.key.generate_lookup_table(|x| {
-            let cond = (x >> 1) & 1 == 1;
-            let value = x & 1;
-
-            if cond {
-                value
-            } else {
-                0
-            }
-        });
+        let luts = self.boolean_cmux_luts();
+        let zero_lut = &luts.zero_lut;
 
         let negated_cond = self.boolean_bitnot(condition);
         let (mut lhs, rhs) = rayon::join(
             || {
                 let mut block = self.key.unchecked_scalar_mul(&condition.0, 2);
                 self.key.unchecked_add_assign(&mut block, &true_ct.0);
-                self.key.apply_lookup_table_assign(&mut block, &zero_lut);
+                self.key.apply_lookup_table_assign(&mut block, zero_lut);
                 block
             },
             || {
                 let mut block = self.key.unchecked_scalar_mul(&negated_cond.0, 2);
                 self.key.unchecked_add_assign(&mut block, &false_ct.0);
-                self.key.apply_lookup_table_assign(&mut block, &zero_lut);
+                self.key.apply_lookup_table_assign(&mut block, zero_lut);
                 block
             },
         );
 
         self.key.unchecked_add_assign(&mut lhs, &rhs);
-        let clean_lut = self.key.generate_lookup_table(|x| x % 2);
-        self.key.apply_lookup_table_assign(&mut lhs, &clean_lut);
+        self.key
+            .apply_lookup_table_assign(&mut lhs, &luts.clean_lut);
 
         BooleanBlock::new_unchecked(lhs)
     }
 }
 
+/// Lookup tables of the [BooleanBlock] cmux
+///
+/// They only depend on the parameters of the key, they are stored in the [ServerKey] and
+/// generated on first use, see [ServerKey::boolean_cmux_luts].
+#[derive(Clone)]
+pub(crate) struct BooleanCmuxLuts {
+    zero_lut: LookupTableOwned,
+    clean_lut: LookupTableOwned,
+}
+
+impl ServerKey {
+    /// Returns the lookup tables used by the [BooleanBlock] cmux
+    ///
+    /// They are generated on the first call, and then reused by all the following calls
+    /// with this key.
+    fn boolean_cmux_luts(&self) -> &BooleanCmuxLuts {
+        self.boolean_cmux_luts.get_or_init(|| {
+            let zero_lut = self.key.generate_lookup_table(|x| {
+                let cond = (x >> 1) & 1 == 1;
+                let value = x & 1;
+
+                if cond {
+                    value
+                } else {
+                    0
+                }
+            });
+            let clean_lut = self.key.generate_lookup_table(|x| x % 2);
+
+            BooleanCmuxLuts {
+                zero_lut,
+                clean_lut,
+            }
+        })
+    }
+}
+
+/// Precomputed data to do many arithmetic selections between the same two ciphertexts
+///
+/// Created with [ServerKey::prepare_arithmetic_select] and used with
+/// [ServerKey::arithmetic_select_with_plan].
+///
+/// A plan is only valid for the two ciphertexts it was built from.
+#[derive(Clone)]
+pub struct ArithmeticSelectPlan<T> {
+    false_ct: T,
+    difference: T,
+}
+
+/// Precomputed lookup tables to do many programmable selections with the same predicate
+///
+/// Created with [ServerKey::prepare_programmable_select] and used with
+/// [ServerKey::programmable_select_with_plan].
+///
+/// The lookup tables depend on the parameters of the [ServerKey] used to create the plan,
+/// so a plan must only be used with server keys that have the same message and carry moduli.
+pub struct ProgrammableSelectPlan {
+    true_lut: BivariateLookupTableOwned,
+    false_lut: BivariateLookupTableOwned,
+    predicate_on_zero: bool,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+}
+
+/// A radix ciphertext in carry-save form
+///
+/// The blocks of the ciphertext may have non-empty carries: each block holds a value in
+/// `0..message_modulus * carry_modulus`, and the encrypted integer is the sum of the values of
+/// the blocks, each weighted by `message_modulus^block_index`, modulo the modulus of the
+/// ciphertext. This is a redundant representation, many of them encrypt the same integer.
+///
+/// Values in carry-save form are created with [ServerKey::if_then_else_carry_save_parallelized],
+/// summed with [ServerKey::carry_save_add_assign_parallelized], and turned back into a ciphertext
+/// with clean blocks with [ServerKey::resolve_carry_save], which does the only carry propagation.
+#[derive(Clone)]
+pub struct CarrySave<T> {
+    ct: T,
+}
+
 impl ServerKey {
     pub fn unchecked_if_then_else_parallelized<T>(
         &self,
@@ -411,6 +479,30 @@ impl ServerKey {
         )
     }
 
+    /// Returns whether the blocks of `true_ct` have a degree and a noise level low enough to be
+    /// used by [Self::unchecked_scalar_if_then_else_parallelized]
+    ///
+    /// Having empty carries is not enough, as each block is multiplied by 2 and then added to the
+    /// condition before the PBS.
+    fn is_unchecked_scalar_if_then_else_possible<T>(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: &T,
+    ) -> bool
+    where
+        T: IntegerRadixCiphertext,
+    {
+        true_ct.blocks().iter().all(|block| {
+            block.carry_is_empty()
+                && block.degree.get() * 2 < block.message_modulus.0 * block.carry_modulus.0
+                && self
+                    .key
+                    .max_noise_level
+                    .validate(block.noise_level() * 2 + condition.0.noise_level())
+                    .is_ok()
+        })
+    }
+
     fn unchecked_scalar_if_then_else_parallelized<T, Scalar>(
         &self,
         condition: &BooleanBlock,
@@ -550,6 +642,107 @@ impl ServerKey {
         T::from_blocks(result_blocks)
     }
 
+    /// FHE "if then else" selection between two clear booleans.
+    ///
+    /// Returns a [BooleanBlock] that encrypts `true_value` if the condition is true,
+    /// and `false_value` otherwise.
+    ///
+    /// This is a specialization of [Self::scalar_if_then_else_parallelized] for a single
+    /// boolean output. As the result only depends on the condition, all four cases are computed
+    /// without any PBS:
+    ///
+    /// - `(true, true)` and `(false, false)` give a trivial boolean
+    /// - `(true, false)` gives a copy of the condition
+    /// - `(false, true)` gives the negation of the condition
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let condition = cks.encrypt_bool(true);
+    ///
+    /// let ct_res = sks.scalar_boolean_if_then_else_parallelized(&condition, false, true);
+    ///
+    /// // Decrypt:
+    /// let dec = cks.decrypt_bool(&ct_res);
+    /// assert!(!dec);
+    /// ```
+    pub fn scalar_boolean_if_then_else_parallelized(
+        &self,
+        condition: &BooleanBlock,
+        true_value: bool,
+        false_value: bool,
+    ) -> BooleanBlock {
+        match (true_value, false_value) {
+            (true, true) | (false, false) => self.create_trivial_boolean_block(true_value),
+            (true, false) => condition.clone(),
+            (false, true) => self.boolean_bitnot(condition),
+        }
+    }
+
+    /// FHE "if then else" selection between two clear values.
+    ///
+    /// Same as [Self::scalar_if_then_else_parallelized], but instead of silently truncating
+    /// the clear values that do not fit in `n_blocks`, an error is returned.
+    ///
+    /// A value does not fit if it is outside the range of values a ciphertext of type `T` with
+    /// `n_blocks` blocks can represent (e.g. negative values for unsigned ciphertexts).
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let condition = cks.encrypt_bool(true);
+    ///
+    /// let ct_res: RadixCiphertext = sks
+    ///     .try_scalar_if_then_else_parallelized(&condition, 255u64, 12u64, size)
+    ///     .unwrap();
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 255);
+    ///
+    /// // 256 does not fit in 8 bits
+    /// let res = sks.try_scalar_if_then_else_parallelized::<_, RadixCiphertext>(
+    ///     &condition, 256u64, 12u64, size,
+    /// );
+    /// assert!(res.is_err());
+    /// ```
+    pub fn try_scalar_if_then_else_parallelized<Scalar, T>(
+        &self,
+        condition: &BooleanBlock,
+        true_value: Scalar,
+        false_value: Scalar,
+        n_blocks: usize,
+    ) -> crate::Result<T>
+    where
+        Scalar: DecomposableInto<u64>,
+        T: IntegerRadixCiphertext,
+    {
+        for (name, value) in [("true_value", true_value), ("false_value", false_value)] {
+            let ordering = self.is_scalar_out_of_bounds_for_num_blocks::<T, _>(n_blocks, value);
+            if ordering != std::cmp::Ordering::Equal {
+                let signedness = if T::IS_SIGNED { "signed" } else { "unsigned" };
+                return Err(crate::error!(
+                    "{name} does not fit in a {signedness} ciphertext of {n_blocks} blocks"
+                ));
+            }
+        }
+
+        Ok(self.scalar_if_then_else_parallelized(condition, true_value, false_value, n_blocks))
+    }
+
     pub fn unchecked_cmux<T>(&self, condition: &BooleanBlock, true_ct: &T, false_ct: &T) -> T
     where
         T: IntegerRadixCiphertext,
@@ -644,6 +837,100 @@ impl ServerKey {
         self.smart_if_then_else_parallelized(condition, true_ct, false_ct)
     }
 
+    /// FHE "if then else" selection, using a predicate on a single-block condition.
+    ///
+    /// Returns a new ciphertext that encrypts the same value
+    /// as either true_ct or false_ct depending on `predicate(condition)`:
+    ///
+    /// - If `predicate(condition)` is true, the returned ciphertext will encrypt the same value as
+    ///   true_ct.
+    /// - Otherwise, the returned ciphertext will encrypt the same value as false_ct.
+    ///
+    /// Unlike [Self::if_then_else_parallelized], the condition is not restricted to 0 or 1,
+    /// it can be any value of a block, which allows to build custom selectors
+    /// (e.g. select when the condition block equals 2).
+    ///
+    /// `predicate` is evaluated in clear on all the values `0..message_modulus` to build the
+    /// lookup tables, so it must be deterministic and total over that range, i.e. it must
+    /// return a result (without panicking) for each of these values.
+    ///
+    /// Inputs with non-empty carries are propagated first, and the result has clean blocks.
+    ///
+    /// See [Self::prepare_programmable_select] to reuse the lookup tables across calls.
+    ///
+    /// # Panics
+    ///
+    /// - if condition does not have exactly one block
+    /// - if true_ct and false_ct do not have the same number of blocks
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// for selector in [0u64, 1, 2, 3] {
+    ///     // The condition is a ciphertext with a single block
+    ///     let condition = cks.as_ref().encrypt_radix(selector, 1);
+    ///
+    ///     // Selects ct_a when the condition equals 2
+    ///     let ct_res =
+    ///         sks.programmable_if_then_else_parallelized(&condition, &ct_a, &ct_b, |x| x == 2);
+    ///
+    ///     // Decrypt:
+    ///     let dec: u8 = cks.decrypt(&ct_res);
+    ///     assert_eq!(if selector == 2 { a } else { b }, dec);
+    /// }
+    /// ```
+    pub fn programmable_if_then_else_parallelized<T, F>(
+        &self,
+        condition: &RadixCiphertext,
+        true_ct: &T,
+        false_ct: &T,
+        predicate: F,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(u64) -> bool + Send + Sync + Copy,
+    {
+        assert_eq!(
+            condition.blocks.len(),
+            1,
+            "condition must have exactly one block"
+        );
+        assert_eq!(
+            true_ct.blocks().len(),
+            false_ct.blocks().len(),
+            "true_ct and false_ct must have the same number of blocks"
+        );
+
+        let mut tmp_condition_block;
+        let condition_block = if condition.blocks[0].carry_is_empty() {
+            &condition.blocks[0]
+        } else {
+            tmp_condition_block = condition.blocks[0].clone();
+            self.key.message_extract_assign(&mut tmp_condition_block);
+            &tmp_condition_block
+        };
+
+        let [true_ct, false_ct] = self.propagated_if_needed_parallelized([true_ct, false_ct]);
+        self.unchecked_programmable_if_then_else_parallelized(
+            condition_block,
+            &*true_ct,
+            &*false_ct,
+            predicate,
+            true,
+        )
+    }
+
     /// if do clean message is false, the resulting ciphertext won't be cleaned (message_extract)
     /// meaning that yes, the resulting ciphertext's encrypted message is within 0..msg_msg
     /// but its degree is the same as after adding to ciphertext
@@ -664,22 +951,11 @@ impl ServerKey {
         T: IntegerRadixCiphertext,
         F: Fn(u64) -> bool + Send + Sync + Copy,
     {
-        let inverted_predicate = |x| !predicate(x);
-
-        // Although our mul algorithm has special path for when rhs or lhs is a boolean value,
-        // we don't call it as for the ct_false we would need an extra pbs to 'invert' the
-        // ciphertext from true to false.
-        let (mut true_ct, false_ct) = rayon::join(
-            move || {
-                let mut true_ct = true_ct.clone();
-                self.zero_out_if(&mut true_ct, condition_block, inverted_predicate);
-                true_ct
-            },
-            move || {
-                let mut false_ct = false_ct.clone();
-                self.zero_out_if(&mut false_ct, condition_block, predicate);
-                false_ct
-            },
+        let (mut true_ct, false_ct) = self.unchecked_programmable_select_zero_pair_parallelized(
+            condition_block,
+            true_ct,
+            false_ct,
+            predicate,
         );
         // If the condition was true, true_ct will have kept its value and false_ct will be 0
         // If the condition was false, true_ct will be 0 and false_ct will have kept its value
@@ -708,71 +984,3174 @@ impl ServerKey {
         true_ct
     }
 
-    /// This function takes a ciphertext encrypting any integer value
-    /// and block encrypting a boolean value (0 or 1).
+    /// Zeroes out the branch that is not selected by `predicate(condition_block)`
     ///
-    /// The input integer ciphertext will have all its block zeroed if condition_block
-    /// encrypts 0, otherwise each block keeps its value.
-    pub(crate) fn zero_out_if_condition_is_false<T>(
+    /// Returns `(true_ct, false_ct)` where `true_ct` is zeroed if the predicate is false, and
+    /// `false_ct` is zeroed if the predicate is true.
+    pub(crate) fn unchecked_programmable_select_zero_pair_parallelized<T, F>(
         &self,
-        ct: &mut T,
         condition_block: &crate::shortint::Ciphertext,
-    ) where
+        true_ct: &T,
+        false_ct: &T,
+        predicate: F,
+    ) -> (T, T)
+    where
         T: IntegerRadixCiphertext,
+        F: Fn(u64) -> bool + Send + Sync + Copy,
     {
-        assert!(condition_block.degree.get() <= 1);
+        let inverted_predicate = |x| !predicate(x);
 
-        self.zero_out_if_condition_equals(ct, condition_block, 0);
+        // Although our mul algorithm has special path for when rhs or lhs is a boolean value,
+        // we don't call it as for the ct_false we would need an extra pbs to 'invert' the
+        // ciphertext from true to false.
+        rayon::join(
+            move || {
+                let mut true_ct = true_ct.clone();
+                self.zero_out_if(&mut true_ct, condition_block, inverted_predicate);
+                true_ct
+            },
+            move || {
+                let mut false_ct = false_ct.clone();
+                self.zero_out_if(&mut false_ct, condition_block, predicate);
+                false_ct
+            },
+        )
     }
 
-    pub(crate) fn zero_out_if_condition_equals<T>(
+    /// Computes the two masked branches of an "if then else" selection
+    ///
+    /// Returns `(true_ct_masked, false_ct_masked)`:
+    ///
+    /// - If condition == 1, `true_ct_masked` encrypts the value of true_ct and `false_ct_masked`
+    ///   encrypts 0.
+    /// - If condition == 0, `true_ct_masked` encrypts 0 and `false_ct_masked` encrypts the value of
+    ///   false_ct.
+    ///
+    /// Adding the two returned ciphertexts block-wise and cleaning the message gives the same
+    /// result as [Self::if_then_else_parallelized], this function lets the caller do
+    /// the combination itself.
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// let condition = sks.scalar_ge_parallelized(&ct_a, 66);
+    ///
+    /// let (masked_a, masked_b) = sks.select_zero_pair_parallelized(&condition, &ct_a, &ct_b);
+    ///
+    /// // Decrypt:
+    /// let dec_a: u8 = cks.decrypt(&masked_a);
+    /// let dec_b: u8 = cks.decrypt(&masked_b);
+    /// assert_eq!(dec_a, if a >= 66 { a } else { 0 });
+    /// assert_eq!(dec_b, if a >= 66 { 0 } else { b });
+    /// ```
+    pub fn select_zero_pair_parallelized<T>(
         &self,
-        ct: &mut T,
-        condition_block: &crate::shortint::Ciphertext,
-        value: u64,
-    ) where
+        condition: &BooleanBlock,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> (T, T)
+    where
         T: IntegerRadixCiphertext,
     {
-        assert!(condition_block.degree.get() < condition_block.message_modulus.0);
-        assert!(value < condition_block.message_modulus.0);
-
-        self.zero_out_if(ct, condition_block, |x| x == value);
+        let [true_ct, false_ct] = self.propagated_if_needed_parallelized([true_ct, false_ct]);
+        self.unchecked_programmable_select_zero_pair_parallelized(
+            &condition.0,
+            &*true_ct,
+            &*false_ct,
+            |x| x == 1,
+        )
     }
 
-    pub(crate) fn zero_out_if<T, F>(
-        &self,
-        ct: &mut T,
-        condition_block: &crate::shortint::Ciphertext,
-        predicate: F,
-    ) where
-        T: IntegerRadixCiphertext,
-        F: Fn(u64) -> bool,
-    {
-        assert!(condition_block.degree.get() < condition_block.message_modulus.0);
-
-        if condition_block.degree.get() == 0 {
-            // The block 'encrypts'  0, and only 0
-            if predicate(0u64) {
-                self.create_trivial_zero_assign_radix(ct);
-            }
-            // else, condition is false, don't do anything
-            return;
-        }
-
-        let lut =
-            self.key.generate_lookup_table_bivariate(
-                |block, condition| if predicate(condition) { 0 } else { block },
-            );
+    /// Computes the two masked branches of an "if then else" selection, alongside their degrees
+    ///
+    /// Same as [Self::select_zero_pair_parallelized], but also returns the degree of each block
+    /// of the two masked branches, in the same order: `(true_ct_masked, false_ct_masked,
+    /// true_ct_masked_degrees, false_ct_masked_degrees)`.
+    ///
+    /// The degrees are the ones tracked by the blocks metadata, they can be used to know
+    /// how the masked branches can be scaled and combined before a PBS is required.
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// let condition = sks.scalar_ge_parallelized(&ct_a, 66);
+    ///
+    /// let (masked_a, masked_b, degrees_a, degrees_b) =
+    ///     sks.select_zero_pair_with_degrees_parallelized(&condition, &ct_a, &ct_b);
+    ///
+    /// assert_eq!(degrees_a.len(), size);
+    /// assert_eq!(degrees_b.len(), size);
+    ///
+    /// // Combine the two branches
+    /// let mut result = sks.unchecked_add_parallelized(&masked_a, &masked_b);
+    /// sks.full_propagate_parallelized(&mut result);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&result);
+    /// assert_eq!(dec, if a >= 66 { a } else { b });
+    /// ```
+    pub fn select_zero_pair_with_degrees_parallelized<T>(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> (T, T, Vec<usize>, Vec<usize>)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let (true_ct, false_ct) = self.select_zero_pair_parallelized(condition, true_ct, false_ct);
+
+        let block_degrees = |ct: &T| {
+            ct.blocks()
+                .iter()
+                .map(|block| block.degree.get() as usize)
+                .collect::<Vec<_>>()
+        };
+        let true_degrees = block_degrees(&true_ct);
+        let false_degrees = block_degrees(&false_ct);
+
+        (true_ct, false_ct, true_degrees, false_degrees)
+    }
+
+    /// FHE "if then else" selection of a pair of ciphertexts.
+    ///
+    /// Returns a new pair of ciphertexts that encrypts the same values
+    /// as either true_pair or false_pair depending on the value of condition:
+    ///
+    /// - If condition == 1, the returned pair will encrypt the same values as true_pair.
+    /// - If condition == 0, the returned pair will encrypt the same values as false_pair.
+    ///
+    /// This is useful to select results that come in pairs, e.g the quotient and remainder of a
+    /// division. The same condition block is used for the selection of both components, and the
+    /// two selections are done in parallel.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let n = 128u8;
+    /// let d = 7u8;
+    ///
+    /// let ct_n = cks.encrypt(n);
+    /// let ct_d = cks.encrypt(d);
+    ///
+    /// let (q, r) = sks.div_rem_parallelized(&ct_n, &ct_d);
+    ///
+    /// let condition = sks.scalar_ge_parallelized(&ct_n, 66);
+    ///
+    /// let (ct_q, ct_r) = sks.if_then_else_pair_parallelized(&condition, (&q, &r), (&ct_n, &ct_d));
+    ///
+    /// // Decrypt:
+    /// let dec_q: u8 = cks.decrypt(&ct_q);
+    /// let dec_r: u8 = cks.decrypt(&ct_r);
+    /// if n >= 66 {
+    ///     assert_eq!((dec_q, dec_r), (n / d, n % d));
+    /// } else {
+    ///     assert_eq!((dec_q, dec_r), (n, d));
+    /// }
+    /// ```
+    pub fn if_then_else_pair_parallelized<T>(
+        &self,
+        condition: &BooleanBlock,
+        true_pair: (&T, &T),
+        false_pair: (&T, &T),
+    ) -> (T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let [true_0, true_1, false_0, false_1] = self.propagated_if_needed_parallelized([
+            true_pair.0,
+            true_pair.1,
+            false_pair.0,
+            false_pair.1,
+        ]);
+        rayon::join(
+            || self.unchecked_if_then_else_parallelized(condition, &*true_0, &*false_0),
+            || self.unchecked_if_then_else_parallelized(condition, &*true_1, &*false_1),
+        )
+    }
+
+    /// FHE "if then else" selection, that also returns the branch that was not selected.
+    ///
+    /// Returns `(winner, loser)`:
+    ///
+    /// - If condition == 1, winner encrypts the same value as true_ct, and loser encrypts the same
+    ///   value as false_ct.
+    /// - If condition == 0, winner encrypts the same value as false_ct, and loser encrypts the same
+    ///   value as true_ct.
+    ///
+    /// The loser is the branch that was not selected: false_ct when the condition is true, and
+    /// true_ct when it is false.
+    ///
+    /// This is useful for compare-and-swap like operations (e.g. sorting networks, tournaments),
+    /// where both values need to be kept. It is computed with
+    /// [Self::conditional_swap_parallelized] (as `conditional_swap(condition, false_ct, true_ct)`),
+    /// so the loser comes at no extra PBS cost: the two outputs share the same zeroing masks,
+    /// which takes 4 PBS per block position instead of 6 for two independent selections.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `true_ct` and `false_ct` do not have the same number of blocks
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// let condition = sks.gt_parallelized(&ct_a, &ct_b);
+    ///
+    /// let (ct_max, ct_min) = sks.if_then_else_keep_loser_parallelized(&condition, &ct_a, &ct_b);
+    ///
+    /// // Decrypt:
+    /// let dec_max: u8 = cks.decrypt(&ct_max);
+    /// let dec_min: u8 = cks.decrypt(&ct_min);
+    /// assert_eq!(dec_max, a.max(b));
+    /// assert_eq!(dec_min, a.min(b));
+    /// ```
+    pub fn if_then_else_keep_loser_parallelized<T>(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> (T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        // Swapping (false_ct, true_ct) when the condition is true gives (true_ct, false_ct)
+        self.conditional_swap_parallelized(condition, false_ct, true_ct)
+    }
+
+    /// FHE partition of two values into the chosen one and the other one.
+    ///
+    /// Returns `(chosen, other)`:
+    ///
+    /// - If condition == 1, chosen encrypts the same value as a, and other encrypts the same value
+    ///   as b.
+    /// - If condition == 0, chosen encrypts the same value as b, and other encrypts the same value
+    ///   as a.
+    ///
+    /// Unlike [Self::select_zero_pair_parallelized], none of the two values is zeroed, both are
+    /// returned in full. This is the building block of reversible branch merges: the value that
+    /// was not chosen is kept, so the merge can be undone later by partitioning `(chosen, other)`
+    /// again with the same condition, which gives back `(a, b)`.
+    ///
+    /// This is the same operation as [Self::if_then_else_keep_loser_parallelized], the two
+    /// outputs share the same zeroing masks.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// let condition = sks.scalar_ge_parallelized(&ct_a, 200u8);
+    ///
+    /// let (chosen, other) = sks.partition_parallelized(&condition, &ct_a, &ct_b);
+    ///
+    /// // Decrypt:
+    /// let dec_chosen: u8 = cks.decrypt(&chosen);
+    /// let dec_other: u8 = cks.decrypt(&other);
+    /// assert_eq!((dec_chosen, dec_other), (b, a));
+    ///
+    /// // Undo the partition
+    /// let (ct_a, ct_b) = sks.partition_parallelized(&condition, &chosen, &other);
+    /// let dec_a: u8 = cks.decrypt(&ct_a);
+    /// let dec_b: u8 = cks.decrypt(&ct_b);
+    /// assert_eq!((dec_a, dec_b), (a, b));
+    /// ```
+    pub fn partition_parallelized<T>(&self, condition: &BooleanBlock, a: &T, b: &T) -> (T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.if_then_else_keep_loser_parallelized(condition, a, b)
+    }
+
+    /// FHE conditional swap of two ciphertexts.
+    ///
+    /// Returns `(first, second)`:
+    ///
+    /// - If condition == 1, first encrypts the same value as b, and second encrypts the same value
+    ///   as a.
+    /// - If condition == 0, first encrypts the same value as a, and second encrypts the same value
+    ///   as b.
+    ///
+    /// This is the compare-and-swap step of sorting networks, and it is the same operation as
+    /// `sks.if_then_else_keep_loser_parallelized(condition, b, a)`. The zeroing masks are
+    /// shared between the two outputs: only `c * a` and `c * b` are computed with a PBS, using
+    /// the same lookup table, and the outputs are then `a + c * b - c * a` and
+    /// `b + c * a - c * b`. This takes 4 PBS per block position instead of 6 for two selections.
+    ///
+    /// The returned ciphertexts have clean carries.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` do not have the same number of blocks
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// // Sort the pair in ascending order
+    /// let condition = sks.gt_parallelized(&ct_a, &ct_b);
+    ///
+    /// let (ct_min, ct_max) = sks.conditional_swap_parallelized(&condition, &ct_a, &ct_b);
+    ///
+    /// // Decrypt:
+    /// let dec_min: u8 = cks.decrypt(&ct_min);
+    /// let dec_max: u8 = cks.decrypt(&ct_max);
+    /// assert_eq!(dec_min, a.min(b));
+    /// assert_eq!(dec_max, a.max(b));
+    /// ```
+    pub fn conditional_swap_parallelized<T>(&self, condition: &BooleanBlock, a: &T, b: &T) -> (T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            a.blocks().len(),
+            b.blocks().len(),
+            "a and b must have the same number of blocks"
+        );
+
+        let [a, b] = self.propagated_if_needed_parallelized([a, b]);
+
+        // The same mask is used for both values, a block is kept if the condition is true
+        let lut = self.key.generate_lookup_table_bivariate(
+            |block, condition| {
+                if condition == 1 {
+                    block
+                } else {
+                    0
+                }
+            },
+        );
+        let (masked_a, masked_b) = rayon::join(
+            || {
+                let mut masked_a = T::clone(&a);
+                self.zero_out_with_lut(&mut masked_a, &condition.0, &lut);
+                masked_a
+            },
+            || {
+                let mut masked_b = T::clone(&b);
+                self.zero_out_with_lut(&mut masked_b, &condition.0, &lut);
+                masked_b
+            },
+        );
+
+        // Computes base + added - removed, block-wise
+        let combine = |base: &T, added: &T, removed: &T| {
+            let blocks = base
+                .blocks()
+                .par_iter()
+                .zip(added.blocks().par_iter())
+                .zip(removed.blocks().par_iter())
+                .map(|((base_block, added_block), removed_block)| {
+                    let mut block = base_block.clone();
+                    self.key.unchecked_add_assign(&mut block, added_block);
+                    // Only happens for parameters with a small carry space
+                    if self
+                        .key
+                        .is_sub_possible(block.noise_degree(), removed_block.noise_degree())
+                        .is_err()
+                    {
+                        self.key.message_extract_assign(&mut block);
+                    }
+                    self.key.unchecked_sub_assign(&mut block, removed_block);
+                    self.key.message_extract_assign(&mut block);
+                    block
+                })
+                .collect();
+
+            T::from_blocks(blocks)
+        };
+
+        rayon::join(
+            || combine(&a, &masked_b, &masked_a),
+            || combine(&b, &masked_a, &masked_b),
+        )
+    }
+
+    /// FHE "if then else" selection, using one bit of a block as the condition.
+    ///
+    /// Returns a new ciphertext that encrypts the same value
+    /// as either true_ct or false_ct depending on the value of the `bit`-th bit
+    /// of the message of `packed`:
+    ///
+    /// - If the bit is 1, the returned ciphertext will encrypt the same value as true_ct.
+    /// - If the bit is 0, the returned ciphertext will encrypt the same value as false_ct.
+    ///
+    /// This allows to use boolean flags packed into a single block as conditions,
+    /// without having to extract them into a [BooleanBlock] first.
+    ///
+    /// # Panics
+    ///
+    /// - if `bit` is not less than the number of message bits of `packed`
+    /// - if `packed` has a non-empty carry
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// // Two flags packed in one block, bit 0 is false and bit 1 is true
+    /// let flags = 0b10;
+    /// let packed = cks.encrypt_one_block(flags);
+    ///
+    /// let ct_res = sks.select_packed_bit_parallelized(&packed, 0, &ct_a, &ct_b);
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, b);
+    ///
+    /// let ct_res = sks.select_packed_bit_parallelized(&packed, 1, &ct_a, &ct_b);
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, a);
+    /// ```
+    pub fn select_packed_bit_parallelized<T>(
+        &self,
+        packed: &crate::shortint::Ciphertext,
+        bit: u32,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_bits_in_message = packed.message_modulus.0.ilog2();
+        assert!(
+            bit < num_bits_in_message,
+            "bit ({bit}) must be less than the number of message bits ({num_bits_in_message})"
+        );
+        assert!(
+            packed.carry_is_empty(),
+            "The packed block must have an empty carry"
+        );
+
+        let [true_ct, false_ct] = self.propagated_if_needed_parallelized([true_ct, false_ct]);
+        self.unchecked_programmable_if_then_else_parallelized(
+            packed,
+            &*true_ct,
+            &*false_ct,
+            |x| (x >> bit) & 1 == 1,
+            true,
+        )
+    }
+
+    /// Gated read of a field packed into the message of a block.
+    ///
+    /// - If condition == 1, the returned block encrypts `(packed >> field_shift) & field_mask`
+    /// - If condition == 0, the returned block encrypts 0
+    ///
+    /// The field extraction and the gating are done in a single bivariate PBS, taking the
+    /// packed block and the condition as inputs.
+    ///
+    /// # Bit budget
+    ///
+    /// The fields can only be packed into the message bits of the block, so `field_shift`
+    /// must be less than the number of message bits, and the field must fit in the bits that
+    /// remain above the shift, that is `field_mask <= (message_modulus - 1) >> field_shift`.
+    /// The returned block has clean carries.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// - if `field_shift` is not less than the number of message bits of `packed`
+    /// - if `field_mask` does not fit in the message bits above `field_shift`
+    /// - if `packed` has a non-empty carry
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// // Two 1-bit fields packed in one block, the field at bit 1 is set
+    /// let packed = cks.encrypt_one_block(0b10);
+    ///
+    /// for cond in [false, true] {
+    ///     let condition = cks.encrypt_bool(cond);
+    ///     let field = sks.conditional_unpack_field_parallelized(&packed, 1, 0b1, &condition);
+    ///
+    ///     // Decrypt:
+    ///     let dec = cks.decrypt_one_block(&field);
+    ///     assert_eq!(dec, u64::from(cond));
+    /// }
+    /// ```
+    pub fn conditional_unpack_field_parallelized(
+        &self,
+        packed: &crate::shortint::Ciphertext,
+        field_shift: u32,
+        field_mask: u64,
+        condition: &BooleanBlock,
+    ) -> crate::shortint::Ciphertext {
+        let message_modulus = packed.message_modulus.0;
+        let num_bits_in_message = message_modulus.ilog2();
+        assert!(
+            field_shift < num_bits_in_message,
+            "field_shift ({field_shift}) must be less than the number of message bits \
+            ({num_bits_in_message})"
+        );
+        let max_field_mask = (message_modulus - 1) >> field_shift;
+        assert!(
+            field_mask <= max_field_mask,
+            "field_mask ({field_mask:#b}) does not fit in the {} message bits above the shift",
+            num_bits_in_message - field_shift
+        );
+        assert!(
+            packed.carry_is_empty(),
+            "The packed block must have an empty carry"
+        );
+
+        let lut = self
+            .key
+            .generate_lookup_table_bivariate(|packed, condition| {
+                if condition == 1 {
+                    (packed >> field_shift) & field_mask
+                } else {
+                    0
+                }
+            });
+        self.key
+            .apply_lookup_table_bivariate(packed, &condition.0, &lut)
+    }
+
+    /// FHE "if then else" selection, with a ternary condition that allows a "don't care" value.
+    ///
+    /// Returns a new ciphertext that encrypts the same value
+    /// as either true_ct or false_ct depending on the value of `condition_block`:
+    ///
+    /// - If condition == 1, the returned ciphertext will encrypt the same value as true_ct.
+    /// - If condition == 0, the returned ciphertext will encrypt the same value as false_ct.
+    /// - If condition == 2, either branch is acceptable; the current implementation treats it as
+    ///   false and returns false_ct, callers must not rely on this choice.
+    ///
+    /// If `condition_block` has more than the nominal noise, it is first refreshed with one PBS.
+    ///
+    /// # Panics
+    ///
+    /// - if the message modulus of `condition_block` cannot hold the value 2
+    /// - if `condition_block` has a degree greater than 2, i.e. it may encrypt a value outside of
+    ///   {0, 1, 2}
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// // The sum of two boolean flags is a condition block of degree 2
+    /// let shortint_sks: &tfhe::shortint::ServerKey = sks.as_ref();
+    /// for (flag_0, flag_1) in [(false, false), (true, false), (true, true)] {
+    ///     let mut condition = cks.encrypt_bool(flag_0).into_raw_parts();
+    ///     shortint_sks.unchecked_add_assign(&mut condition, cks.encrypt_bool(flag_1).as_ref());
+    ///
+    ///     let ct_res = sks.if_then_else_dontcare_parallelized(&condition, &ct_a, &ct_b);
+    ///
+    ///     // Decrypt:
+    ///     let dec: u8 = cks.decrypt(&ct_res);
+    ///     match u8::from(flag_0) + u8::from(flag_1) {
+    ///         0 => assert_eq!(dec, b),
+    ///         1 => assert_eq!(dec, a),
+    ///         _ => assert!(dec == a || dec == b),
+    ///     }
+    /// }
+    /// ```
+    pub fn if_then_else_dontcare_parallelized<T>(
+        &self,
+        condition_block: &crate::shortint::Ciphertext,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(
+            condition_block.message_modulus.0 > 2,
+            "The condition block must be able to hold the value 2"
+        );
+        assert!(
+            condition_block.degree.get() <= 2,
+            "The condition block must have a degree of at most 2, got {:?}",
+            condition_block.degree
+        );
+
+        let [true_ct, false_ct] = self.propagated_if_needed_parallelized([true_ct, false_ct]);
+
+        // The condition is packed with each block in a bivariate PBS, which does not leave room
+        // for more than the nominal noise (e.g. a condition that is the sum of two booleans)
+        let tmp_condition;
+        let condition_block = if condition_block.noise_level() > NoiseLevel::NOMINAL {
+            let lut = self.key.generate_lookup_table(|x| x.min(2));
+            tmp_condition = self.key.apply_lookup_table(condition_block, &lut);
+            &tmp_condition
+        } else {
+            condition_block
+        };
+
+        // The "don't care" value 2 selects the false branch
+        self.unchecked_programmable_if_then_else_parallelized(
+            condition_block,
+            &*true_ct,
+            &*false_ct,
+            |x| x == 1,
+            true,
+        )
+    }
+
+    /// FHE "if then else" selection that cleans the branches with message extractions instead
+    /// of carry propagations.
+    ///
+    /// - If condition == 1, the returned ciphertext will encrypt the message part of the blocks of
+    ///   true_ct.
+    /// - If condition == 0, the returned ciphertext will encrypt the message part of the blocks of
+    ///   false_ct.
+    ///
+    /// The selection only requires the blocks of the branches to have empty carries.
+    /// [ServerKeyDefaultCMux::if_then_else_parallelized] ensures that with a full carry
+    /// propagation of the dirty branches, which is a sequential chain over the blocks. This
+    /// function instead does one message extraction (one PBS) per block that has a non-empty
+    /// carry, all in parallel, which is cheaper.
+    ///
+    /// # Validity
+    ///
+    /// Message extraction drops the carry of each block instead of adding it to the next block.
+    /// The result is thus the same as the one of the standard selection only if the carries of
+    /// the dirty blocks encrypt 0, which is the case when:
+    ///
+    /// - the degree of the blocks is only a pessimistic bound, e.g. the blocks are the sums of
+    ///   values known to never overflow the message space (disjoint bits, a value and 0, etc.)
+    /// - the blocks are independent values (e.g. a vector of small integers packed one per block)
+    ///   for which the carries are meant to be discarded
+    ///
+    /// In all other cases, the standard selection must be used.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 0b1010_0000u8;
+    /// let b = 55u8;
+    ///
+    /// // Adding values with disjoint bits never produces a carry,
+    /// // even though the degrees say otherwise
+    /// let mut ct_a = cks.encrypt(a);
+    /// sks.unchecked_add_assign(&mut ct_a, &cks.encrypt(0b0101_0101u8));
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// let condition = sks.scalar_gt_parallelized(&ct_b, 10u8);
+    /// let ct_res = sks.lean_if_then_else_parallelized(&condition, &ct_a, &ct_b);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, a | 0b0101_0101);
+    /// ```
+    pub fn lean_if_then_else_parallelized<T>(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let [true_ct, false_ct] = cleaned_if_needed([true_ct, false_ct], |ct: &mut T| {
+            ct.blocks_mut()
+                .par_iter_mut()
+                .filter(|block| !block.carry_is_empty())
+                .for_each(|block| self.key.message_extract_assign(block));
+        });
+
+        self.unchecked_if_then_else_parallelized(condition, &*true_ct, &*false_ct)
+    }
+
+    /// FHE "if then else" selection, with one condition per block.
+    ///
+    /// Returns a new ciphertext whose i-th block encrypts the same value as either the i-th
+    /// block of `true_ct` or the i-th block of `false_ct`, depending on `conditions[i]`:
+    ///
+    /// - If conditions\[i\] == 1, the i-th block is the one of true_ct.
+    /// - If conditions\[i\] == 0, the i-th block is the one of false_ct.
+    ///
+    /// Each block is selected independently of the others, all in parallel, which allows
+    /// SIMD-style masking of values packed one per block.
+    ///
+    /// To ensure correct results, each condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// - if `true_ct` and `false_ct` do not have the same number of blocks
+    /// - if `conditions.len()` is not the number of blocks
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 0b11_10_01_00u8;
+    /// let b = 0b00_01_10_11u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// // Blocks are ordered from the least significant to the most significant
+    /// let conditions = [true, false, false, true]
+    ///     .map(|condition| cks.encrypt_bool(condition))
+    ///     .to_vec();
+    ///
+    /// let ct_res = sks.blockwise_select_parallelized(&conditions, &ct_a, &ct_b);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 0b11_01_10_00);
+    /// ```
+    pub fn blockwise_select_parallelized<T>(
+        &self,
+        conditions: &[BooleanBlock],
+        true_ct: &T,
+        false_ct: &T,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = true_ct.blocks().len();
+        assert_eq!(
+            num_blocks,
+            false_ct.blocks().len(),
+            "true_ct and false_ct must have the same number of blocks"
+        );
+        assert_eq!(
+            conditions.len(),
+            num_blocks,
+            "There must be exactly one condition per block"
+        );
+
+        let [true_ct, false_ct] = self.propagated_if_needed_parallelized([true_ct, false_ct]);
+
+        let keep_if_true = self
+            .key
+            .generate_lookup_table_bivariate(|block, condition| block * (condition & 1));
+        let keep_if_false = self
+            .key
+            .generate_lookup_table_bivariate(|block, condition| block * (1 - (condition & 1)));
+
+        let blocks = true_ct
+            .blocks()
+            .par_iter()
+            .zip(false_ct.blocks().par_iter())
+            .zip(conditions.par_iter())
+            .map(|((true_block, false_block), condition)| {
+                let (mut selected, other) = rayon::join(
+                    || {
+                        self.key.unchecked_apply_lookup_table_bivariate(
+                            true_block,
+                            &condition.0,
+                            &keep_if_true,
+                        )
+                    },
+                    || {
+                        self.key.unchecked_apply_lookup_table_bivariate(
+                            false_block,
+                            &condition.0,
+                            &keep_if_false,
+                        )
+                    },
+                );
+                // One of the two blocks is 0, so the sum has no carry
+                self.key.unchecked_add_assign(&mut selected, &other);
+                self.key.message_extract_assign(&mut selected);
+                selected
+            })
+            .collect::<Vec<_>>();
+
+        T::from_blocks(blocks)
+    }
+
+    /// FHE "if then else" selection, that also xors the selected value into a checksum.
+    ///
+    /// Returns a new ciphertext that encrypts the same value
+    /// as either true_ct or false_ct depending on the value of condition:
+    ///
+    /// - If condition == 1, the returned ciphertext will encrypt the same value as true_ct.
+    /// - If condition == 0, the returned ciphertext will encrypt the same value as false_ct.
+    ///
+    /// and updates `checksum` with `checksum ^= result`, so that after a sequence of selections,
+    /// `checksum` encrypts the xor of its initial value and of all the selected values.
+    ///
+    /// Each block of the checksum is updated as soon as the corresponding block of the result is
+    /// computed, in the same parallel pass. As the xor does not create carries, the checksum
+    /// never needs a carry propagation, and its blocks stay clean.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `true_ct`, `false_ct` and `checksum` do not have the same number of blocks
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// let mut checksum = cks.encrypt(0u8);
+    ///
+    /// let mut expected_checksum = 0u8;
+    /// for threshold in [20u8, 200] {
+    ///     let condition = sks.scalar_ge_parallelized(&ct_a, threshold);
+    ///     let ct_res =
+    ///         sks.if_then_else_with_checksum_parallelized(&condition, &ct_a, &ct_b, &mut checksum);
+    ///
+    ///     // Decrypt:
+    ///     let dec: u8 = cks.decrypt(&ct_res);
+    ///     assert_eq!(if a >= threshold { a } else { b }, dec);
+    ///     expected_checksum ^= dec;
+    /// }
+    ///
+    /// let dec_checksum: u8 = cks.decrypt(&checksum);
+    /// assert_eq!(dec_checksum, a ^ b);
+    /// assert_eq!(dec_checksum, expected_checksum);
+    /// ```
+    pub fn if_then_else_with_checksum_parallelized<T>(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: &T,
+        false_ct: &T,
+        checksum: &mut RadixCiphertext,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            true_ct.blocks().len(),
+            false_ct.blocks().len(),
+            "true_ct and false_ct must have the same number of blocks"
+        );
+        assert_eq!(
+            true_ct.blocks().len(),
+            checksum.blocks.len(),
+            "checksum must have the same number of blocks as the selected values"
+        );
+
+        let ([true_ct, false_ct], ()) = rayon::join(
+            || self.propagated_if_needed_parallelized([true_ct, false_ct]),
+            || {
+                if !checksum.block_carries_are_empty() {
+                    self.full_propagate_parallelized(checksum);
+                }
+            },
+        );
+
+        let (mut true_ct, false_ct) = self.unchecked_programmable_select_zero_pair_parallelized(
+            &condition.0,
+            &*true_ct,
+            &*false_ct,
+            |x| x == 1,
+        );
+
+        let xor_lut = self.key.generate_lookup_table_bivariate(|x, y| x ^ y);
+        true_ct
+            .blocks_mut()
+            .par_iter_mut()
+            .zip(false_ct.blocks().par_iter())
+            .zip(checksum.blocks.par_iter_mut())
+            .for_each(|((lhs_block, rhs_block), checksum_block)| {
+                self.key.unchecked_add_assign(lhs_block, rhs_block);
+                self.key.message_extract_assign(lhs_block);
+                self.key.unchecked_apply_lookup_table_bivariate_assign(
+                    checksum_block,
+                    lhs_block,
+                    &xor_lut,
+                );
+            });
+
+        true_ct
+    }
+
+    /// FHE "if then else" selection, that also returns a tag of the branch that was taken.
+    ///
+    /// Returns `(result, tag)` where `result` encrypts the same value as either true_ct or
+    /// false_ct depending on the value of condition (see
+    /// [ServerKeyDefaultCMux::if_then_else_parallelized]), and `tag` is the condition widened to
+    /// an unsigned [RadixCiphertext] with the same number of blocks as `result`.
+    ///
+    /// # Tag encoding
+    ///
+    /// - The tag encrypts 1 if the true branch was selected (condition == 1).
+    /// - The tag encrypts 0 if the false branch was selected (condition == 0).
+    ///
+    /// The least significant block of the tag is the condition block itself, and the other blocks
+    /// are trivial zeros, so the tag costs no PBS. It can be kept next to the result and be
+    /// decrypted later, by a party owning the client key, to audit which branch was taken.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// let condition = sks.scalar_ge_parallelized(&ct_a, 66);
+    ///
+    /// let (ct_res, tag) = sks.if_then_else_with_branch_tag_parallelized(&condition, &ct_a, &ct_b);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// let dec_tag: u8 = cks.decrypt(&tag);
+    /// assert_eq!(dec, a);
+    /// assert_eq!(dec_tag, 1);
+    /// ```
+    pub fn if_then_else_with_branch_tag_parallelized<T>(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> (T, RadixCiphertext)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let result = self.if_then_else_parallelized(condition, true_ct, false_ct);
+        let tag = condition.clone().into_radix(result.blocks().len(), self);
+        (result, tag)
+    }
+
+    /// FHE "if then else" selection, that also returns the degree of each block of the result.
+    ///
+    /// Returns `(result, degrees)` where `result` is the same as the output of
+    /// [ServerKeyDefaultCMux::if_then_else_parallelized], and `degrees[i]` is the degree of the
+    /// i-th block of `result` (its maximum possible value, message and carry included).
+    ///
+    /// The degrees are read from the metadata of the blocks after the selection, no decryption
+    /// is involved, so they can be checked without a client key, e.g. to catch degree
+    /// regressions in tests.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// let condition = sks.scalar_ge_parallelized(&ct_a, 66);
+    ///
+    /// let (ct_res, degrees) =
+    ///     sks.if_then_else_with_degree_report_parallelized(&condition, &ct_a, &ct_b);
+    ///
+    /// // The result has clean blocks
+    /// assert_eq!(degrees.len(), size);
+    /// assert!(degrees.iter().all(|degree| *degree < 4));
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, a);
+    /// ```
+    pub fn if_then_else_with_degree_report_parallelized<T>(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> (T, Vec<usize>)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let result = self.if_then_else_parallelized(condition, true_ct, false_ct);
+        let degrees = result
+            .blocks()
+            .iter()
+            .map(|block| block.degree.get() as usize)
+            .collect();
+        (result, degrees)
+    }
+
+    /// FHE "if then else" selection, for ciphertexts whose blocks do not share the same moduli.
+    ///
+    /// Returns a new ciphertext that encrypts the same value
+    /// as either true_ct or false_ct depending on the value of condition:
+    ///
+    /// - If condition == 1, the returned ciphertext will encrypt the same value as true_ct.
+    /// - If condition == 0, the returned ciphertext will encrypt the same value as false_ct.
+    ///
+    /// Contrary to [Self::if_then_else_parallelized], which assumes that all blocks use the
+    /// moduli of the server key, the lookup tables are built from the `message_modulus` of each
+    /// block, so that blocks encrypted with a different message modulus (e.g. with
+    /// [crate::shortint::ClientKey::encrypt_with_message_modulus], like CRT blocks) keep their
+    /// encoding.
+    ///
+    /// The blocks are not propagated, as there is no carry propagation between blocks that do not
+    /// share the same moduli, so they must have empty carries.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// - if true_ct and false_ct do not have the same number of blocks
+    /// - if the blocks at the same index in true_ct and false_ct do not have the same moduli
+    /// - if a block does not have an empty carry
+    /// - if a block plaintext space is larger than the one of the server key, or if it cannot hold
+    ///   twice its message modulus
+    ///
+    /// ```rust
+    /// use tfhe::integer::prelude::*;
+    /// use tfhe::integer::{gen_keys_radix, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let size = 2;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// // Blocks of moduli 2, 3 and 5
+    /// let basis = vec![2, 3, 5];
+    /// let a = 14;
+    /// let b = 23;
+    ///
+    /// let ct_a =
+    ///     RadixCiphertext::from_blocks(cks.as_ref().encrypt_crt(a, basis.clone()).blocks().to_vec());
+    /// let ct_b =
+    ///     RadixCiphertext::from_blocks(cks.as_ref().encrypt_crt(b, basis.clone()).blocks().to_vec());
+    ///
+    /// let condition = cks.encrypt_bool(false);
+    /// let ct_res = sks.if_then_else_heterogeneous_parallelized(&condition, &ct_a, &ct_b);
+    ///
+    /// // Decrypt:
+    /// let residues = ct_res
+    ///     .blocks()
+    ///     .iter()
+    ///     .map(|block| cks.decrypt_one_block(block))
+    ///     .collect::<Vec<_>>();
+    /// let expected = basis.iter().map(|modulus| b % modulus).collect::<Vec<_>>();
+    /// assert_eq!(residues, expected);
+    /// ```
+    pub fn if_then_else_heterogeneous_parallelized<T>(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            true_ct.blocks().len(),
+            false_ct.blocks().len(),
+            "true_ct and false_ct must have the same number of blocks"
+        );
+
+        let plaintext_modulus = self.message_modulus().0 * self.carry_modulus().0;
+        for (index, (true_block, false_block)) in true_ct
+            .blocks()
+            .iter()
+            .zip(false_ct.blocks().iter())
+            .enumerate()
+        {
+            assert_eq!(
+                (true_block.message_modulus, true_block.carry_modulus),
+                (false_block.message_modulus, false_block.carry_modulus),
+                "Blocks at index {index} do not have the same moduli"
+            );
+            assert!(
+                true_block.carry_is_empty() && false_block.carry_is_empty(),
+                "Blocks at index {index} must have empty carries"
+            );
+            assert!(
+                true_block.message_modulus.0 * true_block.carry_modulus.0 <= plaintext_modulus,
+                "Blocks at index {index} have a plaintext space larger than the server key's"
+            );
+            // Each block is multiplied by 2 and added to the condition before the PBS
+            assert!(
+                2 * true_block.message_modulus.0 <= plaintext_modulus,
+                "Blocks at index {index} have a message modulus too large to be selected"
+            );
+        }
+
+        // The same message modulus is likely to appear in many blocks
+        let luts = true_ct
+            .blocks()
+            .iter()
+            .map(|block| block.message_modulus)
+            .collect::<HashSet<_>>()
+            .into_par_iter()
+            .map(|message_modulus| {
+                let modulus = message_modulus.0;
+                let true_lut = self.key.generate_lookup_table(|block_condition| {
+                    let block = (block_condition / 2) % modulus;
+                    let condition = block_condition % 2;
+                    if condition == 1 {
+                        block
+                    } else {
+                        0
+                    }
+                });
+                let false_lut = self.key.generate_lookup_table(|block_condition| {
+                    let block = (block_condition / 2) % modulus;
+                    let condition = block_condition % 2;
+                    if condition == 0 {
+                        block
+                    } else {
+                        0
+                    }
+                });
+                let message_extract_lut = self.key.generate_lookup_table(|x| x % modulus);
+                (message_modulus, [true_lut, false_lut, message_extract_lut])
+            })
+            .collect::<HashMap<_, _>>();
+
+        let condition_noise_level = condition.0.noise_level();
+        let result_blocks = true_ct
+            .blocks()
+            .par_iter()
+            .zip(false_ct.blocks().par_iter())
+            .map(|(true_block, false_block)| {
+                let [true_lut, false_lut, message_extract_lut] = &luts[&true_block.message_modulus];
+
+                let select_one = |block: &crate::shortint::Ciphertext, lut| {
+                    let mut result = block.clone();
+                    if self
+                        .key
+                        .max_noise_level
+                        .validate(result.noise_level() * 2 + condition_noise_level)
+                        .is_err()
+                    {
+                        // As the carry is empty, this only resets the noise
+                        self.key
+                            .apply_lookup_table_assign(&mut result, message_extract_lut);
+                    }
+                    self.key.unchecked_scalar_mul_assign(&mut result, 2);
+                    self.key.unchecked_add_assign(&mut result, &condition.0);
+                    self.key.apply_lookup_table_assign(&mut result, lut);
+                    result
+                };
+
+                let (mut result, false_result) = rayon::join(
+                    || select_one(true_block, true_lut),
+                    || select_one(false_block, false_lut),
+                );
+                self.key.unchecked_add_assign(&mut result, &false_result);
+                self.key
+                    .apply_lookup_table_assign(&mut result, message_extract_lut);
+                result
+            })
+            .collect();
+
+        T::from_blocks(result_blocks)
+    }
+
+    /// Conditionally widens `ct` by `extra_blocks` blocks
+    ///
+    /// **The condition is not used to compute the result**: both branches encrypt the same value
+    /// with the same blocks (see below), so the result does not depend on it. It is only taken so
+    /// that the call site reads like the other conditional operations.
+    ///
+    /// - If condition == 1, the result is `ct` zero-extended with `extra_blocks` blocks
+    /// - If condition == 0, the result is `ct` at its original width, padded with `extra_blocks`
+    ///   trivial zero blocks so that it has the same shape as the widened branch
+    ///
+    /// Both branches always have `ct.blocks().len() + extra_blocks` blocks, so the type of the
+    /// result does not depend on the condition, and code that logically widens a value only in
+    /// some cases can keep a single, consistent, block count.
+    ///
+    /// # Padding of the narrow branch
+    ///
+    /// The narrow branch is padded in the most significant blocks with trivial zeros, which is
+    /// exactly how the zero extension of the widened branch is done. As `ct` is unsigned, the two
+    /// branches thus encrypt the same value with the same blocks, and no selection is needed:
+    /// the result is computed without any PBS (other than the propagation of the carries of
+    /// `ct`, if any), whatever the condition is. The padding blocks being trivial zeros, an
+    /// operation done afterwards on the result (e.g. an addition that would have overflowed the
+    /// original width) uses them to hold the high part of its result in both cases.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// The returned ciphertext has clean blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let ct = cks.encrypt(200u8);
+    /// let widen = cks.encrypt_bool(true);
+    /// let keep = cks.encrypt_bool(false);
+    ///
+    /// let ct_wide = sks.conditional_widen_parallelized(&ct, 4, &widen);
+    /// let ct_narrow = sks.conditional_widen_parallelized(&ct, 4, &keep);
+    /// assert_eq!(ct_wide.blocks().len(), 8);
+    /// assert_eq!(ct_narrow.blocks().len(), 8);
+    ///
+    /// // The extra blocks can hold the carry of a following operation
+    /// let ct_sum = sks.scalar_add_parallelized(&ct_wide, 100u16);
+    ///
+    /// // Decrypt:
+    /// let dec_narrow: u16 = cks.decrypt(&ct_narrow);
+    /// let dec_sum: u16 = cks.decrypt(&ct_sum);
+    /// assert_eq!(dec_narrow, 200);
+    /// assert_eq!(dec_sum, 300);
+    /// ```
+    pub fn conditional_widen_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        extra_blocks: usize,
+        condition: &BooleanBlock,
+    ) -> RadixCiphertext {
+        debug_assert!(condition.0.degree.get() <= 1);
+
+        // Carries are propagated at the original width, so that they do not flow
+        // into the padding blocks
+        let mut result = ct.clone();
+        if !result.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut result);
+        }
+        self.extend_radix_with_trivial_zero_blocks_msb_assign(&mut result, extra_blocks);
+        result
+    }
+
+    /// Casts `ct` to `target_blocks` blocks, saturating if the value does not fit
+    ///
+    /// - If `ct` has at most `target_blocks` blocks, it is extended (with zeros if unsigned, or
+    ///   with its sign if signed), and the value is unchanged.
+    /// - Otherwise, if the value fits in `target_blocks` blocks, it is truncated.
+    /// - Otherwise, the result is the maximum value representable with `target_blocks` blocks, or
+    ///   for signed ciphertexts, the minimum value if `ct` is negative.
+    ///
+    /// Whether the value fits is computed from the high blocks (the ones that are removed), and
+    /// then used to select between the truncated value and the saturation constant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_blocks` is 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 8 * 2 = 16 bits of message
+    /// let size = 8;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let ct = cks.encrypt(300u16);
+    /// let ct_res = sks.saturating_cast_parallelized(&ct, 4);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, u8::MAX);
+    ///
+    /// let ct = cks.encrypt_signed(-300i16);
+    /// let ct_res = sks.saturating_cast_parallelized(&ct, 4);
+    ///
+    /// // Decrypt:
+    /// let dec: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec, i8::MIN);
+    /// ```
+    pub fn saturating_cast_parallelized<T>(&self, ct: &T, target_blocks: usize) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(target_blocks > 0, "Cannot cast to 0 blocks");
+
+        let mut ct = ct.clone();
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut ct);
+        }
+
+        let current_blocks = ct.blocks().len();
+        if target_blocks >= current_blocks {
+            let num_blocks_to_add = target_blocks - current_blocks;
+            let mut blocks = ct.into_blocks();
+            if T::IS_SIGNED {
+                let mut ct = SignedRadixCiphertext::from(blocks);
+                self.extend_radix_with_sign_msb_assign(&mut ct, num_blocks_to_add);
+                blocks = ct.blocks;
+            } else {
+                blocks.resize(target_blocks, self.key.create_trivial(0));
+            }
+            return T::from_blocks(blocks);
+        }
+
+        let mut blocks = ct.into_blocks();
+        let high_blocks = blocks.split_off(target_blocks);
+        let truncated = T::from_blocks(blocks);
+
+        let message_modulus = self.message_modulus().0;
+        let (is_in_range, saturated) = if T::IS_SIGNED {
+            let num_bits_in_block = message_modulus.ilog2();
+            let sign_bit = |x: u64| (x >> (num_bits_in_block - 1)) & 1;
+            let original_last_block = high_blocks.last().unwrap();
+
+            // The value fits if all the high blocks are the sign extension of the original value,
+            // and if the sign bit of the truncated value is the original sign bit
+            let is_in_range = || {
+                let extension_lut = self.key.generate_lookup_table_bivariate(|block, last| {
+                    let extension = (message_modulus - 1) * sign_bit(last);
+                    u64::from(block != extension)
+                });
+                let sign_lut = self.key.generate_lookup_table_bivariate(|block, last| {
+                    u64::from(sign_bit(block) != sign_bit(last))
+                });
+                let mut differences = high_blocks
+                    .par_iter()
+                    .map(|block| {
+                        self.key.unchecked_apply_lookup_table_bivariate(
+                            block,
+                            original_last_block,
+                            &extension_lut,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                differences.push(self.key.unchecked_apply_lookup_table_bivariate(
+                    truncated.blocks().last().unwrap(),
+                    original_last_block,
+                    &sign_lut,
+                ));
+                let comparisons =
+                    self.compare_blocks_with_zero(&differences, ZeroComparisonType::Equality);
+                BooleanBlock::new_unchecked(self.are_all_comparisons_block_true(comparisons))
+            };
+
+            // MIN if the original value is negative, MAX otherwise
+            let saturated = || {
+                let low_lut = self
+                    .key
+                    .generate_lookup_table(|last| (message_modulus - 1) * (1 - sign_bit(last)));
+                let high_lut = self.key.generate_lookup_table(|last| {
+                    if sign_bit(last) == 1 {
+                        message_modulus / 2
+                    } else {
+                        message_modulus / 2 - 1
+                    }
+                });
+                let (low_block, high_block) = rayon::join(
+                    || self.key.apply_lookup_table(original_last_block, &low_lut),
+                    || self.key.apply_lookup_table(original_last_block, &high_lut),
+                );
+                let mut saturated_blocks = vec![low_block; target_blocks - 1];
+                saturated_blocks.push(high_block);
+                T::from_blocks(saturated_blocks)
+            };
+
+            rayon::join(is_in_range, saturated)
+        } else {
+            let comparisons =
+                self.compare_blocks_with_zero(&high_blocks, ZeroComparisonType::Equality);
+            let is_in_range =
+                BooleanBlock::new_unchecked(self.are_all_comparisons_block_true(comparisons));
+            (is_in_range, self.create_trivial_max_radix(target_blocks))
+        };
+
+        self.if_then_else_parallelized(&is_in_range, &truncated, &saturated)
+    }
+
+    /// Selects between two optional branches, an absent branch being treated as a trivial 0
+    ///
+    /// Returns `true_ct` if `condition` is true, `false_ct` otherwise, where a `None` branch is an
+    /// encryption of 0 on `num_blocks` blocks. This avoids materializing zero ciphertexts for
+    /// branches that are logically absent:
+    ///
+    /// - when only one branch is present, the present branch is zeroed out depending on the
+    ///   condition, which is cheaper than a full selection
+    /// - when both branches are absent, a trivial 0 of `num_blocks` blocks is returned
+    /// - when both branches are present, `num_blocks` is not used, and this is the same as
+    ///   [Self::if_then_else_parallelized]
+    ///
+    /// # Panics
+    ///
+    /// Panics if exactly one branch is `None` and the present branch does not have `num_blocks`
+    /// blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let ct = cks.encrypt(42u8);
+    /// let condition = cks.encrypt_bool(false);
+    ///
+    /// let ct_res = sks.if_then_else_opt_parallelized(&condition, None, Some(&ct), size);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 42);
+    ///
+    /// let ct_res = sks.if_then_else_opt_parallelized(&condition, Some(&ct), None, size);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 0);
+    ///
+    /// let ct_res = sks.if_then_else_opt_parallelized::<RadixCiphertext>(&condition, None, None, size);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 0);
+    /// ```
+    pub fn if_then_else_opt_parallelized<T>(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: Option<&T>,
+        false_ct: Option<&T>,
+        num_blocks: usize,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let (present_ct, zero_out_value) = match (true_ct, false_ct) {
+            (Some(true_ct), Some(false_ct)) => {
+                return self.if_then_else_parallelized(condition, true_ct, false_ct);
+            }
+            (None, None) => return self.create_trivial_zero_radix(num_blocks),
+            // The true branch is kept only if the condition is true
+            (Some(true_ct), None) => (true_ct, 0),
+            // The false branch is kept only if the condition is false
+            (None, Some(false_ct)) => (false_ct, 1),
+        };
+
+        assert_eq!(
+            present_ct.blocks().len(),
+            num_blocks,
+            "The present branch must have num_blocks blocks"
+        );
+
+        let mut result = present_ct.clone();
+        if !result.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut result);
+        }
+        self.zero_out_if_condition_equals(&mut result, condition.as_ref(), zero_out_value);
+        result
+    }
+
+    /// Returns `a` if `a != sentinel`, otherwise returns `b`
+    ///
+    /// The `sentinel` is a clear value used to encode a missing/invalid value,
+    /// this selects the first of the two candidates that is valid.
+    ///
+    /// If both `a` and `b` encrypt the sentinel, `b` (and so the sentinel) is returned,
+    /// which means the function can be chained to select the first valid value among
+    /// many candidates, the result being the sentinel only if no candidate is valid.
+    ///
+    /// The returned ciphertext has clean blocks.
+    ///
+    /// # Panics
+    ///
+    /// - if a and b do not have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let sentinel = u8::MAX;
+    /// let candidates = [sentinel, 17u8, 42u8]
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let ct_res = candidates
+    ///     .iter()
+    ///     .rev()
+    ///     .cloned()
+    ///     .reduce(|acc, candidate| sks.select_valid_parallelized(&candidate, &acc, sentinel as u64))
+    ///     .unwrap();
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 17);
+    /// ```
+    pub fn select_valid_parallelized(
+        &self,
+        a: &RadixCiphertext,
+        b: &RadixCiphertext,
+        sentinel: u64,
+    ) -> RadixCiphertext {
+        assert_eq!(
+            a.blocks.len(),
+            b.blocks.len(),
+            "a and b must have the same number of blocks"
+        );
+
+        let mut tmp_a;
+        let a = if a.block_carries_are_empty() {
+            a
+        } else {
+            tmp_a = a.clone();
+            self.full_propagate_parallelized(&mut tmp_a);
+            &tmp_a
+        };
+
+        let is_valid = self.scalar_ne_parallelized(a, sentinel);
+        self.if_then_else_parallelized(&is_valid, a, b)
+    }
+
+    /// Prepares the arithmetic selection between `true_ct` and `false_ct`
+    ///
+    /// The arithmetic selection computes `false_ct + condition * (true_ct - false_ct)`, the
+    /// returned plan stores the difference `true_ct - false_ct`, so that it is computed only once
+    /// when selecting between the same two ciphertexts with many different conditions.
+    ///
+    /// The plan is only valid for the `true_ct` and `false_ct` it was built from,
+    /// see [Self::arithmetic_select_with_plan].
+    ///
+    /// # Cost
+    ///
+    /// The plan only saves the subtraction `true_ct - false_ct`. Each selection done with
+    /// [Self::arithmetic_select_with_plan] still costs one PBS per block to mask the difference
+    /// followed by a full addition with carry propagation, which is more than a single
+    /// [Self::if_then_else_parallelized]. When throughput matters, prefer
+    /// [Self::if_then_else_parallelized].
+    pub fn prepare_arithmetic_select<T>(&self, true_ct: &T, false_ct: &T) -> ArithmeticSelectPlan<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let (false_ct, difference) = rayon::join(
+            || {
+                let mut false_ct = false_ct.clone();
+                if !false_ct.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut false_ct);
+                }
+                false_ct
+            },
+            || self.sub_parallelized(true_ct, false_ct),
+        );
+
+        ArithmeticSelectPlan {
+            false_ct,
+            difference,
+        }
+    }
+
+    /// FHE "if then else" selection, using a precomputed [ArithmeticSelectPlan]
+    ///
+    /// Returns a new ciphertext that encrypts the same value
+    /// as either the true_ct or the false_ct the plan was built from,
+    /// depending on the value of condition:
+    ///
+    /// - If condition == 1, the returned ciphertext will encrypt the same value as true_ct.
+    /// - If condition == 0, the returned ciphertext will encrypt the same value as false_ct.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Cost
+    ///
+    /// Each call masks the difference stored in the plan (one PBS per block) and then adds it to
+    /// `false_ct` with a full carry propagation. This is more expensive than
+    /// [Self::if_then_else_parallelized], the plan only saves the subtraction done once by
+    /// [Self::prepare_arithmetic_select]. When throughput matters, prefer
+    /// [Self::if_then_else_parallelized].
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// let plan = sks.prepare_arithmetic_select(&ct_a, &ct_b);
+    ///
+    /// for threshold in [20u8, 100, 200] {
+    ///     let condition = sks.scalar_ge_parallelized(&ct_a, threshold);
+    ///     let ct_res = sks.arithmetic_select_with_plan(&condition, &plan);
+    ///
+    ///     // Decrypt:
+    ///     let dec: u8 = cks.decrypt(&ct_res);
+    ///     assert_eq!(if a >= threshold { a } else { b }, dec);
+    /// }
+    /// ```
+    pub fn arithmetic_select_with_plan<T>(
+        &self,
+        condition: &BooleanBlock,
+        plan: &ArithmeticSelectPlan<T>,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut difference = plan.difference.clone();
+        self.zero_out_if_condition_is_false(&mut difference, &condition.0);
+        self.add_parallelized(&plan.false_ct, &difference)
+    }
+
+    /// Prepares the lookup tables of a programmable selection with the given predicate
+    ///
+    /// The returned plan can then be used with [Self::programmable_select_with_plan]
+    /// to select between two ciphertexts depending on `predicate(condition_block)`, without
+    /// generating the lookup tables again at each call.
+    ///
+    /// The plan is specific to the parameters of this server key, using it with a server key
+    /// that has different message or carry moduli will panic.
+    pub fn prepare_programmable_select<F>(&self, predicate: F) -> ProgrammableSelectPlan
+    where
+        F: Fn(u64) -> bool,
+    {
+        let true_lut =
+            self.key.generate_lookup_table_bivariate(
+                |block, condition| {
+                    if predicate(condition) {
+                        block
+                    } else {
+                        0
+                    }
+                },
+            );
+        let false_lut =
+            self.key.generate_lookup_table_bivariate(
+                |block, condition| {
+                    if predicate(condition) {
+                        0
+                    } else {
+                        block
+                    }
+                },
+            );
+
+        ProgrammableSelectPlan {
+            true_lut,
+            false_lut,
+            predicate_on_zero: predicate(0),
+            message_modulus: self.message_modulus(),
+            carry_modulus: self.carry_modulus(),
+        }
+    }
+
+    /// FHE programmable selection, using a precomputed [ProgrammableSelectPlan]
+    ///
+    /// Returns a new ciphertext that encrypts the same value
+    /// as either true_ct or false_ct depending on the predicate the plan was built with:
+    ///
+    /// - If predicate(condition_block) is true, the returned ciphertext will encrypt the same value
+    ///   as true_ct.
+    /// - Otherwise, the returned ciphertext will encrypt the same value as false_ct.
+    ///
+    /// # Panics
+    ///
+    /// - if the plan was created with a server key with different parameters
+    /// - if true_ct and false_ct do not have the same number of blocks
+    /// - if condition_block does not have an empty carry
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// // Selects ct_a when the condition block is odd
+    /// let plan = sks.prepare_programmable_select(|x| x % 2 == 1);
+    ///
+    /// for selector in [0u8, 1, 2, 3] {
+    ///     let ct_selector = cks.encrypt_one_block(u64::from(selector));
+    ///     let ct_res = sks.programmable_select_with_plan(&ct_selector, &ct_a, &ct_b, &plan);
+    ///
+    ///     // Decrypt:
+    ///     let dec: u8 = cks.decrypt(&ct_res);
+    ///     assert_eq!(if selector % 2 == 1 { a } else { b }, dec);
+    /// }
+    /// ```
+    pub fn programmable_select_with_plan<T>(
+        &self,
+        condition_block: &crate::shortint::Ciphertext,
+        true_ct: &T,
+        false_ct: &T,
+        plan: &ProgrammableSelectPlan,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            (plan.message_modulus, plan.carry_modulus),
+            (self.message_modulus(), self.carry_modulus()),
+            "The plan was not created with a server key with the same parameters"
+        );
+        assert_eq!(
+            true_ct.blocks().len(),
+            false_ct.blocks().len(),
+            "true_ct and false_ct must have the same number of blocks"
+        );
+
+        assert!(
+            condition_block.degree.get() < condition_block.message_modulus.0,
+            "condition_block must have an empty carry"
+        );
+
+        if condition_block.degree.get() == 0 {
+            // The block 'encrypts' 0, and only 0
+            let selected = if plan.predicate_on_zero {
+                true_ct
+            } else {
+                false_ct
+            };
+            let mut result = selected.clone();
+            if !result.block_carries_are_empty() {
+                self.full_propagate_parallelized(&mut result);
+            }
+            return result;
+        }
+
+        let (mut true_ct, false_ct) = rayon::join(
+            || {
+                let mut true_ct = true_ct.clone();
+                if !true_ct.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut true_ct);
+                }
+                self.zero_out_with_lut(&mut true_ct, condition_block, &plan.true_lut);
+                true_ct
+            },
+            || {
+                let mut false_ct = false_ct.clone();
+                if !false_ct.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut false_ct);
+                }
+                self.zero_out_with_lut(&mut false_ct, condition_block, &plan.false_lut);
+                false_ct
+            },
+        );
+
+        true_ct
+            .blocks_mut()
+            .par_iter_mut()
+            .zip(false_ct.blocks().par_iter())
+            .for_each(|(lhs_block, rhs_block)| {
+                self.key.unchecked_add_assign(lhs_block, rhs_block);
+                self.key.message_extract_assign(lhs_block);
+            });
+
+        true_ct
+    }
+
+    /// FHE "if then else" selection, written into `dst`.
+    ///
+    /// - If condition == 1, `dst` will encrypt the same value as true_ct.
+    /// - If condition == 0, `dst` will encrypt the same value as false_ct.
+    ///
+    /// This is the same as `*dst = sks.if_then_else_parallelized(condition, true_ct, false_ct)`,
+    /// and inputs with non-empty carries are propagated the same way, but the result is written
+    /// in the blocks of `dst`: `true_ct` is copied into the existing blocks of `dst` (reusing
+    /// their allocations when `dst` already has the right number of blocks), zeroed when the
+    /// condition is false, and added to a copy of `false_ct` that is zeroed when the condition is
+    /// true. This avoids allocating a new ciphertext for each selection, e.g. when updating an
+    /// accumulator in a loop.
+    ///
+    /// The previous value of `dst` is not used, and `dst` can have any number of blocks. It ends
+    /// up with the number of blocks of the branches, and has clean blocks.
+    ///
+    /// # Aliasing
+    ///
+    /// The borrow rules prevent `dst` from being one of the branches. To select between `dst`
+    /// itself and another value, use [Self::conditional_move_parallelized], which does the
+    /// selection in place (`dst` is then the `false_ct`), or pass a copy of `dst` as a branch.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `true_ct` and `false_ct` do not have the same number of blocks
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clears = [12u8, 200, 7, 99];
+    /// let cts = clears.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    ///
+    /// // Running maximum, the same ciphertext is used for all the selections
+    /// let mut max = cts[0].clone();
+    /// let mut selected = cks.encrypt(0u8);
+    /// for ct in &cts[1..] {
+    ///     let condition = sks.gt_parallelized(ct, &max);
+    ///     sks.if_then_else_assign_parallelized(&mut selected, &condition, ct, &max);
+    ///     std::mem::swap(&mut max, &mut selected);
+    /// }
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&max);
+    /// assert_eq!(dec, 200);
+    /// ```
+    pub fn if_then_else_assign_parallelized<T>(
+        &self,
+        dst: &mut T,
+        condition: &BooleanBlock,
+        true_ct: &T,
+        false_ct: &T,
+    ) where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            true_ct.blocks().len(),
+            false_ct.blocks().len(),
+            "true_ct and false_ct must have the same number of blocks"
+        );
+
+        let [true_ct, false_ct] = self.propagated_if_needed_parallelized([true_ct, false_ct]);
+
+        if dst.blocks().len() == true_ct.blocks().len() {
+            dst.blocks_mut().clone_from_slice(true_ct.blocks());
+        } else {
+            *dst = true_ct.into_owned();
+        }
+
+        // Both zeroings use the same condition block,
+        // dst is kept when it is true, and the copy of false_ct is kept when it is false
+        let (_, false_ct) = rayon::join(
+            || self.zero_out_if(dst, &condition.0, |x| x == 0),
+            || {
+                let mut false_ct = false_ct.into_owned();
+                self.zero_out_if(&mut false_ct, &condition.0, |x| x == 1);
+                false_ct
+            },
+        );
+
+        dst.blocks_mut()
+            .par_iter_mut()
+            .zip(false_ct.blocks().par_iter())
+            .for_each(|(lhs_block, rhs_block)| {
+                self.key.unchecked_add_assign(lhs_block, rhs_block);
+                self.key.message_extract_assign(lhs_block);
+            });
+    }
+
+    /// FHE conditional move.
+    ///
+    /// Overwrites `dst` with the value of `src` if `condition` encrypts true,
+    /// otherwise `dst` keeps its value:
+    ///
+    /// - If condition == 1, `dst` will encrypt the same value as `src`.
+    /// - If condition == 0, `dst` will encrypt the same value it encrypted before the call.
+    ///
+    /// This is the same as `*dst = sks.if_then_else_parallelized(condition, src, dst)`, but
+    /// the selection is done in place in the blocks of `dst`: `dst` is zeroed when the condition
+    /// is true, a copy of `src` is zeroed when the condition is false, and the two are then
+    /// added together. In both cases, `dst` gets new blocks, even if it keeps its value.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` and `src` do not have the same number of blocks
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let mut ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// let condition = sks.scalar_ge_parallelized(&ct_a, 66);
+    ///
+    /// sks.conditional_move_parallelized(&mut ct_a, &ct_b, &condition);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_a);
+    /// assert_eq!(if a >= 66 { b } else { a }, dec);
+    /// ```
+    pub fn conditional_move_parallelized<T>(&self, dst: &mut T, src: &T, condition: &BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            dst.blocks().len(),
+            src.blocks().len(),
+            "dst and src must have the same number of blocks"
+        );
+
+        // Both zeroings use the same condition block,
+        // dst is kept when it is false, and the copy of src is kept when it is true
+        let (_, src) = rayon::join(
+            || {
+                if !dst.block_carries_are_empty() {
+                    self.full_propagate_parallelized(dst);
+                }
+                self.zero_out_if(dst, &condition.0, |x| x == 1);
+            },
+            || {
+                let mut src = src.clone();
+                if !src.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut src);
+                }
+                self.zero_out_if(&mut src, &condition.0, |x| x == 0);
+                src
+            },
+        );
+
+        dst.blocks_mut()
+            .par_iter_mut()
+            .zip(src.blocks().par_iter())
+            .for_each(|(lhs_block, rhs_block)| {
+                self.key.unchecked_add_assign(lhs_block, rhs_block);
+                self.key.message_extract_assign(lhs_block);
+            });
+    }
+
+    /// FHE gated broadcast of a single block.
+    ///
+    /// - If condition == 1, every block of `ct` is set to the value of `value_block`.
+    /// - If condition == 0, `ct` keeps its value.
+    ///
+    /// Only the message of `value_block` is broadcast, its carries (if any) are discarded.
+    ///
+    /// The fill block is zeroed once when the condition is false, then each block of `ct` is
+    /// zeroed when the condition is true and added to it, all the blocks being processed
+    /// in parallel.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    ///
+    /// let mut ct_a = cks.encrypt(a);
+    /// let fill = cks.encrypt_one_block(2);
+    /// let condition = sks.scalar_ge_parallelized(&ct_a, 66);
+    ///
+    /// sks.conditional_broadcast_parallelized(&mut ct_a, &fill, &condition);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_a);
+    /// assert_eq!(if a >= 66 { 0b10_10_10_10 } else { a }, dec);
+    /// ```
+    pub fn conditional_broadcast_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        value_block: &crate::shortint::Ciphertext,
+        condition: &BooleanBlock,
+    ) {
+        let (_, fill) = rayon::join(
+            || {
+                if !ct.block_carries_are_empty() {
+                    self.full_propagate_parallelized(ct);
+                }
+                self.zero_out_if(ct, &condition.0, |x| x == 1);
+            },
+            || {
+                let mut fill = value_block.clone();
+                if !fill.carry_is_empty() {
+                    self.key.message_extract_assign(&mut fill);
+                }
+                let mut fill = RadixCiphertext::from(vec![fill]);
+                self.zero_out_if(&mut fill, &condition.0, |x| x == 0);
+                fill.blocks.pop().unwrap()
+            },
+        );
+
+        ct.blocks.par_iter_mut().for_each(|block| {
+            self.key.unchecked_add_assign(block, &fill);
+            self.key.message_extract_assign(block);
+        });
+    }
+
+    /// Applies a boolean mask to a matrix of encrypted values.
+    ///
+    /// For each position `(i, j)`:
+    ///
+    /// - If `mask[i][j]` == 1, `scores[i][j]` keeps its value.
+    /// - If `mask[i][j]` == 0, `scores[i][j]` is set to `masked_value`.
+    ///
+    /// `masked_value` is encoded in two's complement on the number of blocks of each score, that
+    /// is, a negative value like `-100` gives `2^num_bits - 100`, and the scores can be
+    /// interpreted as signed values by the client.
+    ///
+    /// Each position is a gated scalar assignment (a single PBS per block), and all the
+    /// positions of the grid are processed in parallel.
+    ///
+    /// To ensure correct results, the mask must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// - if `scores` and `mask` do not have the same number of rows
+    /// - if a row of `scores` and the matching row of `mask` do not have the same length
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clear_scores = [[10u8, 20], [30, 40]];
+    /// let clear_mask = [[true, false], [false, true]];
+    ///
+    /// let mut scores = clear_scores
+    ///     .iter()
+    ///     .map(|row| row.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    /// let mask = clear_mask
+    ///     .iter()
+    ///     .map(|row| row.iter().map(|m| cks.encrypt_bool(*m)).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// sks.apply_mask_matrix_parallelized(&mut scores, &mask, -100);
+    ///
+    /// // Decrypt:
+    /// let dec = scores
+    ///     .iter()
+    ///     .map(|row| row.iter().map(|ct| cks.decrypt::<u8>(ct) as i8).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(dec, vec![vec![10, -100], vec![-100, 40]]);
+    /// ```
+    pub fn apply_mask_matrix_parallelized(
+        &self,
+        scores: &mut [Vec<RadixCiphertext>],
+        mask: &[Vec<BooleanBlock>],
+        masked_value: i64,
+    ) {
+        assert_eq!(
+            scores.len(),
+            mask.len(),
+            "scores and mask must have the same number of rows"
+        );
+        for (i, (scores_row, mask_row)) in scores.iter().zip(mask.iter()).enumerate() {
+            assert_eq!(
+                scores_row.len(),
+                mask_row.len(),
+                "Row {i} of scores and mask must have the same length"
+            );
+        }
+
+        scores
+            .par_iter_mut()
+            .zip(mask.par_iter())
+            .flat_map(|(scores_row, mask_row)| scores_row.par_iter_mut().zip(mask_row.par_iter()))
+            .for_each(|(score, keep)| {
+                *score = self.if_then_else_parallelized(keep, &*score, masked_value);
+            });
+    }
+
+    /// Returns `n_blocks` copies of the block of the condition
+    ///
+    /// Each copy encrypts the same boolean as `condition` and has the same degree
+    /// (at most 1), so they can be used as per-block masks, e.g. as one of the operands of
+    /// bivariate lookup tables applied to each block of a radix ciphertext.
+    ///
+    /// The copies are independent ciphertexts: no PBS is done, they are clones of the condition
+    /// block, and modifying one of them does not affect the others nor the condition.
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let condition = cks.encrypt_bool(true);
+    ///
+    /// let masks = sks.broadcast_condition_to_blocks_parallelized(&condition, size);
+    ///
+    /// // Decrypt:
+    /// assert_eq!(masks.len(), size);
+    /// for mask in &masks {
+    ///     assert_eq!(cks.decrypt_one_block(mask), 1);
+    /// }
+    /// ```
+    pub fn broadcast_condition_to_blocks_parallelized(
+        &self,
+        condition: &BooleanBlock,
+        n_blocks: usize,
+    ) -> Vec<crate::shortint::Ciphertext> {
+        (0..n_blocks)
+            .into_par_iter()
+            .map(|_| condition.0.clone())
+            .collect()
+    }
+
+    /// FHE gated step of an exponential moving average.
+    ///
+    /// - If condition == 1, `acc` is updated to `acc - (acc >> shift) + (sample >> shift)`
+    /// - If condition == 0, `acc` keeps its value.
+    ///
+    /// Seen as fixed-point numbers (with any implicit scale shared by `acc` and `sample`), this
+    /// is the EMA update `acc += alpha * (sample - acc)` with a smoothing factor
+    /// `alpha = 2^-shift`. Both terms are shifted before being combined, so each of them is
+    /// rounded towards zero, and the result may be off by one unit in the last place
+    /// compared to the exact fixed-point update. The update is computed modulo
+    /// `2^(num_bits)`, like the rest of the radix arithmetic.
+    ///
+    /// Rather than computing the update and selecting between it and the old value, the
+    /// difference `(sample >> shift) - (acc >> shift)` is zeroed when the condition is false
+    /// and then added to `acc`, so a single addition is done regardless of the condition.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// - if `acc` and `sample` do not have the same number of blocks
+    /// - if `shift` is greater or equal to the number of bits in `acc`
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let acc = 100u8;
+    /// let sample = 20u8;
+    /// let shift = 2;
+    ///
+    /// let mut ct_acc = cks.encrypt(acc);
+    /// let ct_sample = cks.encrypt(sample);
+    /// let condition = sks.scalar_gt_parallelized(&ct_sample, 10u8);
+    ///
+    /// sks.conditional_ema_step_parallelized(&mut ct_acc, &ct_sample, &condition, shift);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_acc);
+    /// assert_eq!(dec, acc - (acc >> shift) + (sample >> shift));
+    /// ```
+    pub fn conditional_ema_step_parallelized(
+        &self,
+        acc: &mut RadixCiphertext,
+        sample: &RadixCiphertext,
+        condition: &BooleanBlock,
+        shift: u32,
+    ) {
+        assert_eq!(
+            acc.blocks.len(),
+            sample.blocks.len(),
+            "acc and sample must have the same number of blocks"
+        );
+        let num_bits = self.message_modulus().0.ilog2() as usize * acc.blocks.len();
+        assert!(
+            (shift as usize) < num_bits,
+            "shift ({shift}) must be smaller than the number of bits ({num_bits})"
+        );
+
+        let (shifted_acc, shifted_sample) = rayon::join(
+            || {
+                if !acc.block_carries_are_empty() {
+                    self.full_propagate_parallelized(acc);
+                }
+                self.unchecked_scalar_right_shift_parallelized(acc, shift)
+            },
+            || {
+                let mut sample = sample.clone();
+                if !sample.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut sample);
+                }
+                self.unchecked_scalar_right_shift_parallelized(&sample, shift)
+            },
+        );
+
+        let mut delta = self.sub_parallelized(&shifted_sample, &shifted_acc);
+        self.zero_out_if_condition_is_false(&mut delta, &condition.0);
+        self.add_assign_parallelized(acc, &delta);
+    }
+
+    /// FHE conditional application of a clear affine map.
+    ///
+    /// - If condition == 1, the returned ciphertext encrypts `ct * scale + bias`
+    /// - If condition == 0, the returned ciphertext encrypts the same value as `ct`
+    ///
+    /// The carries of `ct` are propagated once, then the affine map is computed (a scalar
+    /// multiplication followed by a scalar addition) and a single selection is done between
+    /// its result and the original value.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Overflow
+    ///
+    /// The affine map is computed modulo `2^(num_bits)`, like the rest of the radix arithmetic,
+    /// so when `ct * scale + bias` does not fit in the number of blocks of `ct`, the result
+    /// wraps around. With a large `scale`, callers that need the exact value should first
+    /// extend `ct` with enough blocks (e.g. with
+    /// [Self::extend_radix_with_trivial_zero_blocks_msb]).
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let msg = 20u8;
+    /// let scale = 3;
+    /// let bias = 7;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let condition = sks.scalar_lt_parallelized(&ct, 50u8);
+    ///
+    /// let ct_res = sks.conditional_affine_parallelized(&ct, scale, bias, &condition);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg * scale as u8 + bias as u8);
+    /// ```
+    pub fn conditional_affine_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        scale: u64,
+        bias: u64,
+        condition: &BooleanBlock,
+    ) -> RadixCiphertext {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        let mut affine = self.unchecked_scalar_mul_parallelized(ct, scale);
+        self.scalar_add_assign_parallelized(&mut affine, bias);
+
+        self.unchecked_if_then_else_parallelized(condition, &affine, ct)
+    }
+
+    /// FHE conditional bitxor with a clear keystream word: `ct ^= keystream` if condition is true
+    ///
+    /// - If condition == 1, `ct` is updated to encrypt `ct ^ keystream`
+    /// - If condition == 0, `ct` keeps its value
+    ///
+    /// # Keystream slicing
+    ///
+    /// The keystream is sliced in the same way as the radix decomposition of `ct`: block `i` of
+    /// `ct` is xored with the bits `[i * b, (i + 1) * b)` of `keystream`, where `b` is the number
+    /// of bits of message in a block (`log2(message_modulus)`). Bits of the keystream that lie
+    /// beyond the last block of `ct` are ignored, and blocks of `ct` that lie beyond the last
+    /// non-zero slice of the keystream are left untouched.
+    ///
+    /// Each block whose keystream slice is not zero is updated with a single bivariate PBS,
+    /// taking the block and the condition as inputs, and applying `block ^ slice` only when the
+    /// condition is 1. All those blocks are processed in parallel, and blocks whose slice is 0
+    /// cost no PBS.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let msg = 0b1010_0110u8;
+    /// let keystream = 0b0110_1100u64;
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    /// let mut ct_skipped = cks.encrypt(msg);
+    ///
+    /// sks.conditional_xor_scalar_parallelized(&mut ct, keystream, &cks.encrypt_bool(true));
+    /// sks.conditional_xor_scalar_parallelized(&mut ct_skipped, keystream, &cks.encrypt_bool(false));
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct);
+    /// let dec_skipped: u8 = cks.decrypt(&ct_skipped);
+    /// assert_eq!(dec, msg ^ keystream as u8);
+    /// assert_eq!(dec_skipped, msg);
+    /// ```
+    pub fn conditional_xor_scalar_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        keystream: u64,
+        condition: &BooleanBlock,
+    ) {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        let message_modulus = self.message_modulus().0;
+        let clear_blocks =
+            BlockDecomposer::with_early_stop_at_zero(keystream, message_modulus.ilog2())
+                .iter_as::<u64>()
+                .collect::<Vec<_>>();
+
+        // One lookup table per distinct non-zero slice
+        let used_slices = clear_blocks
+            .iter()
+            .take(ct.blocks.len())
+            .copied()
+            .filter(|slice| *slice != 0)
+            .collect::<HashSet<_>>();
+        let luts = used_slices
+            .into_par_iter()
+            .map(|slice| {
+                let lut = self
+                    .key
+                    .generate_lookup_table_bivariate(|block, condition| {
+                        if condition == 1 {
+                            block ^ slice
+                        } else {
+                            block
+                        }
+                    });
+                (slice, lut)
+            })
+            .collect::<HashMap<_, _>>();
+
+        ct.blocks
+            .par_iter_mut()
+            .zip(clear_blocks.par_iter())
+            .filter(|(_, slice)| **slice != 0)
+            .for_each(|(block, slice)| {
+                self.key.unchecked_apply_lookup_table_bivariate_assign(
+                    block,
+                    &condition.0,
+                    &luts[slice],
+                );
+            });
+    }
+
+    /// FHE conditional CRC update: folds `data_byte` into the CRC register `crc` if condition is
+    /// true
+    ///
+    /// - If condition == 1, `crc` is updated to encrypt the CRC register after processing the data
+    ///   bits of `data_byte`
+    /// - If condition == 0, `crc` keeps its value
+    ///
+    /// This allows computing CRCs over a stream where only some (encrypted) selection of the
+    /// bytes is included.
+    ///
+    /// # CRC width and polynomial convention
+    ///
+    /// - The width `W` of the CRC is the number of message bits of `crc`, that is `num_blocks *
+    ///   log2(message_modulus)`, e.g. a CRC-8 uses 4 blocks with 2 bits of message per block. It
+    ///   must be at most 64.
+    /// - `poly` is the generator polynomial in normal (MSB-first) notation, without its implicit
+    ///   `x^W` term, e.g. `0x07` for CRC-8 or `0x1021` for CRC-16/CCITT. It must fit in `W` bits.
+    /// - The register is not reflected, and data bits are processed most significant first. The
+    ///   initial value and the final xor are left to the caller, who respectively encrypts the
+    ///   initial value in `crc` and can apply the final xor with a scalar bitxor.
+    ///
+    /// `data_byte` is a single block, and it holds `log2(message_modulus)` bits of data (e.g. 2
+    /// bits with 2 bits of message per block): a full byte is folded by calling this function on
+    /// each block of its radix decomposition, from the most significant block to the least
+    /// significant one.
+    ///
+    /// # Implementation
+    ///
+    /// As a data block holds as many bits as a block of the register, the `b` bit-steps of the
+    /// bitwise CRC algorithm (xor the data bit into the top bit, shift left, xor `poly` if the
+    /// bit shifted out was 1) are done at once with a table indexed by the top block of the
+    /// register xored with the data. The register is shifted left by one block (which is free)
+    /// and each of its blocks is xored with the matching block of the table entry with a single
+    /// bivariate PBS, all the blocks being processed in parallel. The result is then selected
+    /// with the condition.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// - if `crc` has no blocks, or is wider than 64 bits
+    /// - if `poly` does not fit in the width of `crc`
+    /// - if `data_byte` does not have an empty carry
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message, for a CRC-8
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let poly = 0x07;
+    /// let data = b"12";
+    /// let included = [true, false];
+    ///
+    /// let mut crc = cks.encrypt(0u8);
+    /// for (byte, include) in data.iter().zip(included) {
+    ///     let condition = cks.encrypt_bool(include);
+    ///     // Fold the 2-bit blocks of the byte, most significant first
+    ///     let byte_blocks = cks.encrypt(*byte).into_blocks();
+    ///     for block in byte_blocks.iter().rev() {
+    ///         sks.conditional_crc_step_parallelized(&mut crc, block, poly, &condition);
+    ///     }
+    /// }
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&crc);
+    /// // CRC-8 of "1"
+    /// assert_eq!(dec, 0x97);
+    /// ```
+    pub fn conditional_crc_step_parallelized(
+        &self,
+        crc: &mut RadixCiphertext,
+        data_byte: &crate::shortint::Ciphertext,
+        poly: u64,
+        condition: &BooleanBlock,
+    ) {
+        let message_modulus = self.message_modulus().0;
+        let bits_in_block = message_modulus.ilog2();
+        let num_blocks = crc.blocks.len();
+        let width = bits_in_block * num_blocks as u32;
+        assert!(
+            num_blocks > 0 && width <= u64::BITS,
+            "The CRC register must have between 1 and 64 bits, it has {width}"
+        );
+        let width_mask = u64::MAX >> (u64::BITS - width);
+        assert!(
+            poly <= width_mask,
+            "poly ({poly:#x}) does not fit in the {width} bits of the CRC register"
+        );
+        assert!(
+            data_byte.carry_is_empty(),
+            "The data block must have an empty carry"
+        );
+
+        if !crc.block_carries_are_empty() {
+            self.full_propagate_parallelized(crc);
+        }
+
+        // The effect on the register of shifting out `b` bits whose value (xored with the data)
+        // is `top`, with the rest of the register being 0
+        let clear_crc_table = |top: u64| {
+            let mut register = top << (width - bits_in_block);
+            for _ in 0..bits_in_block {
+                let top_bit = (register >> (width - 1)) & 1;
+                register = (register << 1) & width_mask;
+                if top_bit == 1 {
+                    register ^= poly;
+                }
+            }
+            register
+        };
+
+        let xor_lut = self.key.generate_lookup_table_bivariate(|a, b| a ^ b);
+        let top = self.key.unchecked_apply_lookup_table_bivariate(
+            &crc.blocks[num_blocks - 1],
+            data_byte,
+            &xor_lut,
+        );
+
+        let new_blocks = (0..num_blocks)
+            .into_par_iter()
+            .map(|block_index| {
+                let table_block = |top: u64| {
+                    (clear_crc_table(top) >> (block_index as u32 * bits_in_block)) % message_modulus
+                };
+                if block_index == 0 {
+                    // The shift left brings a zero in the lowest block
+                    let lut = self.key.generate_lookup_table(table_block);
+                    self.key.apply_lookup_table(&top, &lut)
+                } else {
+                    let shifted = &crc.blocks[block_index - 1];
+                    if (0..message_modulus).all(|top| table_block(top) == 0) {
+                        return shifted.clone();
+                    }
+                    let lut = self
+                        .key
+                        .generate_lookup_table_bivariate(|shifted, top| shifted ^ table_block(top));
+                    self.key
+                        .unchecked_apply_lookup_table_bivariate(shifted, &top, &lut)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let new_crc = RadixCiphertext::from(new_blocks);
+        *crc = self.unchecked_if_then_else_parallelized(condition, &new_crc, &*crc);
+    }
+
+    /// FHE gated fused multiply-add with a clear coefficient: `acc += a * x` if condition is true
+    ///
+    /// - If condition == 1, `acc` is updated to encrypt `acc + a * x`
+    /// - If condition == 0, `acc` keeps its value
+    ///
+    /// The product `a * x` is computed with clean blocks, then it is zeroed when condition is
+    /// false, and added to `acc` without propagating the carries of the sum.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Carry budget
+    ///
+    /// The carries of `acc` are deferred: each call adds a value with clean blocks to `acc`,
+    /// which increases the degree of each of its blocks by at most `message_modulus - 1`.
+    /// `acc` is only propagated, before the addition, when the carry space left in its blocks is
+    /// not enough to hold the product. Along a chain of calls on the same accumulator, a
+    /// propagation is thus done once every few steps instead of at every step (e.g. once every
+    /// 5 steps with 2 bits of message and 2 bits of carry).
+    ///
+    /// As a result `acc` may have non-empty carries when this function returns. The final value
+    /// of the chain should be propagated with [Self::full_propagate_parallelized] (or passed to
+    /// a default operation, which takes care of it) before being used.
+    ///
+    /// The product is computed modulo `2^(num_bits)`, so the result wraps around like the rest
+    /// of the radix arithmetic.
+    ///
+    /// # Panics
+    ///
+    /// - if `acc` and `x` do not have the same number of blocks
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// // Evaluates 3 + 2 * x + 5 * x^2, only keeping the terms whose flag is set
+    /// let x = 4u8;
+    /// let ct_x = cks.encrypt(x);
+    /// let ct_x2 = sks.mul_parallelized(&ct_x, &ct_x);
+    /// let mut acc = cks.encrypt(3u8);
+    ///
+    /// let keep_linear = cks.encrypt_bool(true);
+    /// let keep_square = cks.encrypt_bool(false);
+    /// sks.conditional_fma_scalar_parallelized(&mut acc, &ct_x, 2, &keep_linear);
+    /// sks.conditional_fma_scalar_parallelized(&mut acc, &ct_x2, 5, &keep_square);
+    /// sks.full_propagate_parallelized(&mut acc);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&acc);
+    /// assert_eq!(dec, 3 + 2 * x);
+    /// ```
+    pub fn conditional_fma_scalar_parallelized(
+        &self,
+        acc: &mut RadixCiphertext,
+        x: &RadixCiphertext,
+        a: u64,
+        condition: &BooleanBlock,
+    ) {
+        assert_eq!(
+            acc.blocks.len(),
+            x.blocks.len(),
+            "acc and x must have the same number of blocks"
+        );
+
+        let product = self.scalar_mul_parallelized(x, a);
+        self.conditional_accumulate(acc, product, condition);
+    }
+
+    /// FHE gated fused multiply-add with an encrypted coefficient: `acc += a * x` if condition is
+    /// true
+    ///
+    /// This is the same as [Self::conditional_fma_scalar_parallelized], but the coefficient `a`
+    /// is encrypted, the product is computed with [Self::mul_parallelized]. The carries of `acc`
+    /// are deferred the same way, see the carry budget section of
+    /// [Self::conditional_fma_scalar_parallelized].
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// - if `acc`, `x` and `a` do not all have the same number of blocks
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let mut acc = cks.encrypt(10u8);
+    /// let x = cks.encrypt(6u8);
+    /// let a = cks.encrypt(7u8);
+    ///
+    /// let condition = cks.encrypt_bool(true);
+    /// sks.conditional_fma_parallelized(&mut acc, &x, &a, &condition);
+    /// sks.full_propagate_parallelized(&mut acc);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&acc);
+    /// assert_eq!(dec, 10 + 7 * 6);
+    /// ```
+    pub fn conditional_fma_parallelized(
+        &self,
+        acc: &mut RadixCiphertext,
+        x: &RadixCiphertext,
+        a: &RadixCiphertext,
+        condition: &BooleanBlock,
+    ) {
+        assert_eq!(
+            acc.blocks.len(),
+            x.blocks.len(),
+            "acc and x must have the same number of blocks"
+        );
+        assert_eq!(
+            x.blocks.len(),
+            a.blocks.len(),
+            "x and a must have the same number of blocks"
+        );
+
+        let product = self.mul_parallelized(x, a);
+        self.conditional_accumulate(acc, product, condition);
+    }
+
+    /// Adds `product`, which must have clean blocks, to `acc` if condition is true,
+    /// propagating `acc` first only if the addition would overflow its carry space
+    fn conditional_accumulate(
+        &self,
+        acc: &mut RadixCiphertext,
+        mut product: RadixCiphertext,
+        condition: &BooleanBlock,
+    ) {
+        self.zero_out_if_condition_is_false(&mut product, &condition.0);
+
+        if self.is_add_possible(acc, &product).is_err() {
+            self.full_propagate_parallelized(acc);
+        }
+        self.is_add_possible(acc, &product).unwrap();
+        self.unchecked_add_assign(acc, &product);
+    }
+
+    /// FHE conditional move of many fields at once.
+    ///
+    /// For each index `i`, overwrites `dst[i]` with the value of `src[i]` if `condition`
+    /// encrypts true, otherwise `dst[i]` keeps its value.
+    ///
+    /// This is the same as calling [Self::conditional_move_parallelized] on each pair of fields,
+    /// but all the fields are processed in parallel. It is meant for updating the fields of an
+    /// encrypted structure that share the same condition.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// - if `dst` and `src` do not have the same length
+    /// - if `dst[i]` and `src[i]` do not have the same number of blocks
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let mut x = cks.encrypt(1u8);
+    /// let mut y = cks.encrypt(2u8);
+    /// let new_x = cks.encrypt(10u8);
+    /// let new_y = cks.encrypt(20u8);
+    ///
+    /// let condition = sks.scalar_ge_parallelized(&x, 0u8);
+    ///
+    /// sks.select_struct_parallelized(&mut [&mut x, &mut y], &[&new_x, &new_y], &condition);
+    ///
+    /// // Decrypt:
+    /// let dec_x: u8 = cks.decrypt(&x);
+    /// let dec_y: u8 = cks.decrypt(&y);
+    /// assert_eq!((dec_x, dec_y), (10, 20));
+    /// ```
+    pub fn select_struct_parallelized<T>(
+        &self,
+        dst: &mut [&mut T],
+        src: &[&T],
+        condition: &BooleanBlock,
+    ) where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            dst.len(),
+            src.len(),
+            "dst and src must have the same number of fields"
+        );
+
+        dst.par_iter_mut()
+            .zip(src.par_iter())
+            .for_each(|(dst_field, src_field)| {
+                self.conditional_move_parallelized(*dst_field, *src_field, condition);
+            });
+    }
+
+    /// FHE "if then else" selection, where the work done does not depend on the branches
+    ///
+    /// Returns a new ciphertext that encrypts the same value
+    /// as either true_ct or false_ct depending on the value of condition:
+    ///
+    /// - If condition == 1, the returned ciphertext will encrypt the same value as true_ct.
+    /// - If condition == 0, the returned ciphertext will encrypt the same value as false_ct.
+    ///
+    /// The branches may have a different number of blocks, the smaller one is extended
+    /// (with zeros for unsigned ciphertexts, with its sign for signed ciphertexts) to match the
+    /// larger one, and the result has the number of blocks of the larger one.
+    ///
+    /// # Security
+    ///
+    /// [Self::if_then_else_parallelized] skips the blocks that are known to be zero, like the ones
+    /// of a trivial ciphertext or of a zero extension. So the number of PBS, and thus the timing,
+    /// depends on the content of the branches.
+    ///
+    /// Here, every block of both branches goes through a bivariate PBS with the condition block,
+    /// even when it is trivial. The condition block itself is not bootstrapped, it is only the
+    /// second input of these bivariate PBS. Each block position then costs 3 PBS (the two
+    /// bivariate PBS and the message extraction of their sum), so once the input carries are
+    /// clean, the selection always costs
+    /// `3 * max(true_ct.blocks().len(), false_ct.blocks().len())` PBS, plus one PBS to compute
+    /// the sign extension of a signed branch that is smaller. This only depends on the number of
+    /// blocks, which is public, so neither the timing nor the PBS count reveal the taken branch.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = cks.encrypt_signed(-5i8);
+    /// let b = cks.as_ref().encrypt_signed_radix(300i16, 8);
+    ///
+    /// let condition = cks.encrypt_bool(true);
+    ///
+    /// let result = sks.constant_shape_if_then_else_parallelized(&condition, &a, &b);
+    ///
+    /// // Decrypt:
+    /// let dec: i16 = cks.decrypt_signed(&result);
+    /// assert_eq!(dec, -5);
+    /// ```
+    pub fn constant_shape_if_then_else_parallelized<T>(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let condition_block = &condition.0;
+        assert!(condition_block.degree.get() <= 1);
+
+        let mut true_blocks = true_ct.blocks().to_vec();
+        let mut false_blocks = false_ct.blocks().to_vec();
+        let num_blocks = true_blocks.len().max(false_blocks.len());
+
+        rayon::join(
+            || self.extend_blocks_for_constant_shape::<T>(&mut true_blocks, num_blocks),
+            || self.extend_blocks_for_constant_shape::<T>(&mut false_blocks, num_blocks),
+        );
+
+        let true_lut =
+            self.key.generate_lookup_table_bivariate(
+                |block, condition| if condition == 1 { block } else { 0 },
+            );
+        let false_lut =
+            self.key.generate_lookup_table_bivariate(
+                |block, condition| if condition == 0 { block } else { 0 },
+            );
+
+        // No block is skipped, not even the trivial ones
+        let blocks = true_blocks
+            .par_iter()
+            .zip(false_blocks.par_iter())
+            .map(|(true_block, false_block)| {
+                let (mut result, masked_false_block) = rayon::join(
+                    || {
+                        self.key.unchecked_apply_lookup_table_bivariate(
+                            true_block,
+                            condition_block,
+                            &true_lut,
+                        )
+                    },
+                    || {
+                        self.key.unchecked_apply_lookup_table_bivariate(
+                            false_block,
+                            condition_block,
+                            &false_lut,
+                        )
+                    },
+                );
+                self.key
+                    .unchecked_add_assign(&mut result, &masked_false_block);
+                self.key.message_extract_assign(&mut result);
+                result
+            })
+            .collect();
+
+        T::from_blocks(blocks)
+    }
+
+    /// Cleans the blocks and extends them to `num_blocks`, with zeros or with the sign of the
+    /// last block depending on the signedness of `T`
+    fn extend_blocks_for_constant_shape<T>(
+        &self,
+        blocks: &mut Vec<crate::shortint::Ciphertext>,
+        num_blocks: usize,
+    ) where
+        T: IntegerRadixCiphertext,
+    {
+        if !blocks.iter().all(|block| block.carry_is_empty()) {
+            let mut ct = T::from_blocks(std::mem::take(blocks));
+            self.full_propagate_parallelized(&mut ct);
+            *blocks = ct.into_blocks();
+        }
 
+        if blocks.len() >= num_blocks {
+            return;
+        }
+
+        let padding_block = if T::IS_SIGNED {
+            let message_modulus = self.message_modulus().0;
+            let num_bits_in_block = message_modulus.ilog2();
+            let lut = self.key.generate_lookup_table(|x| {
+                let x_sign_bit = ((x % message_modulus) >> (num_bits_in_block - 1)) & 1;
+                (message_modulus - 1) * x_sign_bit
+            });
+            let last_block = blocks
+                .last()
+                .expect("Cannot sign extend an empty ciphertext");
+            self.key.apply_lookup_table(last_block, &lut)
+        } else {
+            self.key.create_trivial(0)
+        };
+        blocks.resize(num_blocks, padding_block);
+    }
+
+    /// FHE "if then else" selection, returning the result in carry-save form
+    ///
+    /// Same as [Self::if_then_else_parallelized], but the masked branches are only added
+    /// together: the message extraction that would clean the result is not done, and the result
+    /// is wrapped in a [CarrySave]. This saves one PBS per block, and many selected values can
+    /// then be summed with [Self::carry_save_add_assign_parallelized] and only propagated once,
+    /// at the end, with [Self::resolve_carry_save].
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    /// let c = 17u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    /// let ct_c = cks.encrypt(c);
+    ///
+    /// let condition_0 = sks.scalar_ge_parallelized(&ct_a, 66);
+    /// let condition_1 = sks.scalar_ge_parallelized(&ct_b, 66);
+    ///
+    /// let mut sum = sks.if_then_else_carry_save_parallelized(&condition_0, &ct_a, &ct_c);
+    /// let selected = sks.if_then_else_carry_save_parallelized(&condition_1, &ct_b, &ct_c);
+    /// sks.carry_save_add_assign_parallelized(&mut sum, &selected);
+    ///
+    /// let ct_res = sks.resolve_carry_save(sum);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, a.wrapping_add(c));
+    /// ```
+    pub fn if_then_else_carry_save_parallelized<T>(
+        &self,
+        condition: &BooleanBlock,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> CarrySave<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let [true_ct, false_ct] = self.propagated_if_needed_parallelized([true_ct, false_ct]);
+        CarrySave {
+            ct: self.unchecked_programmable_if_then_else_parallelized(
+                &condition.0,
+                &*true_ct,
+                &*false_ct,
+                |x| x == 1,
+                false,
+            ),
+        }
+    }
+
+    /// Adds `rhs` to `lhs`, keeping the result in carry-save form
+    ///
+    /// This is [Self::smart_add_assign_parallelized] on the wrapped ciphertexts: the blocks are
+    /// added without propagating the carries, as long as the degrees and the noise levels allow
+    /// it. When they do not, the accumulated carries are propagated before doing the addition,
+    /// so this never fails, but only saves work when the carry space of the parameters is large
+    /// enough to hold the sum of several selected values.
+    ///
+    /// # Panics
+    ///
+    /// - if lhs and rhs do not have the same number of blocks
+    pub fn carry_save_add_assign_parallelized<T>(&self, lhs: &mut CarrySave<T>, rhs: &CarrySave<T>)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            lhs.ct.blocks().len(),
+            rhs.ct.blocks().len(),
+            "lhs and rhs must have the same number of blocks"
+        );
+
+        let mut rhs = rhs.ct.clone();
+        self.smart_add_assign_parallelized(&mut lhs.ct, &mut rhs);
+    }
+
+    /// Propagates the carries of a value in carry-save form
+    ///
+    /// This is [Self::full_propagate_parallelized] on the wrapped ciphertext. It returns a
+    /// ciphertext with clean blocks, that can be used with any other function.
+    pub fn resolve_carry_save<T>(&self, carry_save: CarrySave<T>) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut ct = carry_save.ct;
+        self.full_propagate_parallelized(&mut ct);
+        ct
+    }
+
+    /// This function takes a ciphertext encrypting any integer value
+    /// and block encrypting a boolean value (0 or 1).
+    ///
+    /// The input integer ciphertext will have all its block zeroed if condition_block
+    /// encrypts 0, otherwise each block keeps its value.
+    pub(crate) fn zero_out_if_condition_is_false<T>(
+        &self,
+        ct: &mut T,
+        condition_block: &crate::shortint::Ciphertext,
+    ) where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(condition_block.degree.get() <= 1);
+
+        self.zero_out_if_condition_equals(ct, condition_block, 0);
+    }
+
+    pub(crate) fn zero_out_if_condition_equals<T>(
+        &self,
+        ct: &mut T,
+        condition_block: &crate::shortint::Ciphertext,
+        value: u64,
+    ) where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(condition_block.degree.get() < condition_block.message_modulus.0);
+        assert!(value < condition_block.message_modulus.0);
+
+        self.zero_out_if(ct, condition_block, |x| x == value);
+    }
+
+    pub(crate) fn zero_out_if<T, F>(
+        &self,
+        ct: &mut T,
+        condition_block: &crate::shortint::Ciphertext,
+        predicate: F,
+    ) where
+        T: IntegerRadixCiphertext,
+        F: Fn(u64) -> bool,
+    {
+        assert!(condition_block.degree.get() < condition_block.message_modulus.0);
+
+        if condition_block.degree.get() == 0 {
+            // The block 'encrypts'  0, and only 0
+            if predicate(0u64) {
+                self.create_trivial_zero_assign_radix(ct);
+            }
+            // else, condition is false, don't do anything
+            return;
+        }
+
+        let lut =
+            self.key.generate_lookup_table_bivariate(
+                |block, condition| if predicate(condition) { 0 } else { block },
+            );
+
+        self.zero_out_with_lut(ct, condition_block, &lut);
+    }
+
+    /// Applies the zeroing bivariate `lut` to each block of `ct` that is not already a trivial 0
+    pub(crate) fn zero_out_with_lut<T>(
+        &self,
+        ct: &mut T,
+        condition_block: &crate::shortint::Ciphertext,
+        lut: &BivariateLookupTableOwned,
+    ) where
+        T: IntegerRadixCiphertext,
+    {
         ct.blocks_mut()
             .par_iter_mut()
             .filter(|block| block.degree.get() != 0)
             .for_each(|block| {
-                self.key.unchecked_apply_lookup_table_bivariate_assign(
-                    block,
-                    condition_block,
-                    &lut,
-                );
+                self.key
+                    .unchecked_apply_lookup_table_bivariate_assign(block, condition_block, lut);
             });
     }
 }
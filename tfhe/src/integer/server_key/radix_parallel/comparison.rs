@@ -3,6 +3,7 @@ use crate::core_crypto::prelude::UnsignedInteger;
 use crate::integer::ciphertext::boolean_value::BooleanBlock;
 use crate::integer::ciphertext::IntegerRadixCiphertext;
 use crate::integer::prelude::ServerKeyDefaultCMux;
+use crate::integer::RadixCiphertext;
 use crate::shortint::{Ciphertext, MessageModulus};
 use rayon::prelude::*;
 
@@ -1060,4 +1061,537 @@ impl ServerKey {
 
         self.unchecked_min_parallelized(lhs, rhs)
     }
+
+    /// Compare-and-swap step of a merge
+    ///
+    /// Returns `(min, max, a_le_b)` where `a_le_b` encrypts `a <= b`, it tells which run
+    /// the minimum comes from, so that the caller can advance the correct run.
+    ///
+    /// A single comparison is done, its result is used for both selections and is returned.
+    ///
+    /// # Ties
+    ///
+    /// When `a == b`, `a_le_b` is `true`, `min` comes from `a` and `max` comes from `b`.
+    /// Thus, if `a` is taken from the run that comes first, a merge that advances the run of
+    /// `a` when `a_le_b` is `true` is stable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// let (ct_min, ct_max, a_le_b) = sks.merge_step_parallelized(&ct_a, &ct_b);
+    ///
+    /// // Decrypt:
+    /// let dec_min: u8 = cks.decrypt(&ct_min);
+    /// let dec_max: u8 = cks.decrypt(&ct_max);
+    /// assert_eq!(dec_min, a.min(b));
+    /// assert_eq!(dec_max, a.max(b));
+    /// assert_eq!(cks.decrypt_bool(&a_le_b), a <= b);
+    /// ```
+    pub fn merge_step_parallelized<T>(&self, a: &T, b: &T) -> (T, T, BooleanBlock)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let [a, b] = self.propagated_if_needed_parallelized([a, b]);
+
+        let a_le_b = self.unchecked_le_parallelized(&*a, &*b);
+        let (min, max) = self.if_then_else_keep_loser_parallelized(&a_le_b, &*a, &*b);
+        (min, max, a_le_b)
+    }
+
+    /// One sift-down step of a min-heap stored in `heap`
+    ///
+    /// The positions `parent`, `left` and `right` are clear, only the values are encrypted.
+    /// The parent is compared with its children, and if the smallest child is strictly smaller
+    /// than the parent, the two are swapped:
+    ///
+    /// - `right_lt_left = heap[right] < heap[left]` selects the smallest child
+    /// - `swap = smallest < heap[parent]` tells whether a swap is needed
+    ///
+    /// The three new values are then computed as three independent selections, done in
+    /// parallel, with `swap_left = swap & !right_lt_left` and `swap_right = swap & right_lt_left`
+    /// (at most one of the two is true):
+    ///
+    /// - `parent = if swap { smallest } else { parent }`
+    /// - `left = if swap_left { parent } else { left }`
+    /// - `right = if swap_right { parent } else { right }`
+    ///
+    /// If `right >= heap.len()`, the parent has no right child, and it is only compared
+    /// (and conditionally swapped) with the left child.
+    ///
+    /// When the children are equal, the left one is preferred, and a child equal to the parent
+    /// is not swapped.
+    ///
+    /// After the step, `heap[parent]` is the minimum of the three values. As the path taken by the
+    /// value that moved down is not known in clear, a full sift-down applies the step to every
+    /// node of each level below `parent`, from top to bottom. Repeated application this way
+    /// maintains the heap property, and applying a full sift-down to every internal node, from the
+    /// last one to the root, builds a heap from arbitrary data.
+    ///
+    /// The values at the three positions have clean blocks after the step.
+    ///
+    /// # Panics
+    ///
+    /// - if `parent` or `left` is out of bounds
+    /// - if `parent`, `left` and `right` (when in bounds) are not all different
+    /// - if the values at the positions do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clears = [50u8, 30, 10, 20];
+    /// let mut heap = clears.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    ///
+    /// sks.heap_siftdown_step_parallelized(&mut heap, 0, 1, 2);
+    /// // Node 1 only has a left child (3), its right child (4) is missing
+    /// sks.heap_siftdown_step_parallelized(&mut heap, 1, 3, 4);
+    ///
+    /// // Decrypt:
+    /// let dec = heap.iter().map(|ct| cks.decrypt::<u8>(ct)).collect::<Vec<_>>();
+    /// assert_eq!(dec, vec![10, 20, 50, 30]);
+    /// ```
+    pub fn heap_siftdown_step_parallelized<T>(
+        &self,
+        heap: &mut [T],
+        parent: usize,
+        left: usize,
+        right: usize,
+    ) where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(
+            parent < heap.len() && left < heap.len(),
+            "parent ({parent}) and left ({left}) must be in bounds (heap length is {})",
+            heap.len()
+        );
+        let has_right = right < heap.len();
+        assert!(
+            parent != left && (!has_right || (right != parent && right != left)),
+            "parent, left and right must be different positions"
+        );
+        let num_blocks = heap[parent].blocks().len();
+        assert!(
+            heap[left].blocks().len() == num_blocks
+                && (!has_right || heap[right].blocks().len() == num_blocks),
+            "All values must have the same number of blocks"
+        );
+
+        heap.par_iter_mut()
+            .enumerate()
+            .filter(|(i, ct)| {
+                (*i == parent || *i == left || (has_right && *i == right))
+                    && !ct.block_carries_are_empty()
+            })
+            .for_each(|(_, ct)| self.full_propagate_parallelized(ct));
+
+        if !has_right {
+            let swap = self.unchecked_lt_parallelized(&heap[left], &heap[parent]);
+            let (new_parent, new_left) =
+                self.if_then_else_keep_loser_parallelized(&swap, &heap[left], &heap[parent]);
+            heap[parent] = new_parent;
+            heap[left] = new_left;
+            return;
+        }
+
+        let right_lt_left = self.unchecked_lt_parallelized(&heap[right], &heap[left]);
+        let smallest =
+            self.unchecked_if_then_else_parallelized(&right_lt_left, &heap[right], &heap[left]);
+        let swap = self.unchecked_lt_parallelized(&smallest, &heap[parent]);
+
+        let (swap_left, swap_right) = rayon::join(
+            || {
+                let right_ge_left = self.boolean_bitnot(&right_lt_left);
+                self.boolean_bitand(&swap, &right_ge_left)
+            },
+            || self.boolean_bitand(&swap, &right_lt_left),
+        );
+
+        // The three outputs only depend on the current values, so they are independent
+        // selections:
+        // - parent = if swap { smallest } else { parent }
+        // - left = if swap_left { parent } else { left }
+        // - right = if swap_right { parent } else { right }
+        let (new_parent, (new_left, new_right)) = rayon::join(
+            || self.unchecked_if_then_else_parallelized(&swap, &smallest, &heap[parent]),
+            || {
+                rayon::join(
+                    || {
+                        self.unchecked_if_then_else_parallelized(
+                            &swap_left,
+                            &heap[parent],
+                            &heap[left],
+                        )
+                    },
+                    || {
+                        self.unchecked_if_then_else_parallelized(
+                            &swap_right,
+                            &heap[parent],
+                            &heap[right],
+                        )
+                    },
+                )
+            },
+        );
+        heap[parent] = new_parent;
+        heap[left] = new_left;
+        heap[right] = new_right;
+    }
+
+    /// Returns the two largest values of `values`, as `(max, second)`
+    ///
+    /// The first two values are ordered with one comparison, then each remaining value `v` is
+    /// compared to both the current maximum and the current second maximum. The two
+    /// comparisons are independent and done in parallel, and `v > max` is used by both
+    /// selections:
+    ///
+    /// - `max = if v > max { v } else { max }`
+    /// - `second = if v > max { max } else if v > second { v } else { second }`
+    ///
+    /// When the maximum appears more than once, `second` is equal to `max`.
+    ///
+    /// If `values` has a single element, it is returned as the maximum, and `second` is a
+    /// trivial encryption of the smallest value `T` can represent, so that it never outranks a
+    /// real value.
+    ///
+    /// The returned ciphertexts have clean blocks.
+    ///
+    /// # Panics
+    ///
+    /// - if `values` is empty
+    /// - if the values do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clears = [13u8, 200, 7, 42, 99];
+    /// let values = clears.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    ///
+    /// let (ct_max, ct_second) = sks.top2_parallelized(&values);
+    ///
+    /// // Decrypt:
+    /// let dec_max: u8 = cks.decrypt(&ct_max);
+    /// let dec_second: u8 = cks.decrypt(&ct_second);
+    /// assert_eq!(dec_max, 200);
+    /// assert_eq!(dec_second, 99);
+    /// ```
+    pub fn top2_parallelized<T>(&self, values: &[T]) -> (T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(
+            !values.is_empty(),
+            "Cannot compute the top 2 of an empty slice"
+        );
+        let num_blocks = values[0].blocks().len();
+        assert!(
+            values.iter().all(|ct| ct.blocks().len() == num_blocks),
+            "All values must have the same number of blocks"
+        );
+
+        let values = values
+            .par_iter()
+            .map(|ct| {
+                let mut ct = ct.clone();
+                if !ct.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut ct);
+                }
+                ct
+            })
+            .collect::<Vec<_>>();
+
+        let (mut max, mut second) = match values.as_slice() {
+            [single] => (single.clone(), self.create_trivial_min_radix(num_blocks)),
+            [first, second, ..] => {
+                let first_le_second = self.unchecked_le_parallelized(first, second);
+                let (min, max) =
+                    self.if_then_else_keep_loser_parallelized(&first_le_second, first, second);
+                (max, min)
+            }
+            [] => unreachable!(),
+        };
+
+        for value in values.iter().skip(2) {
+            let (gt_max, gt_second) = rayon::join(
+                || self.unchecked_gt_parallelized(value, &max),
+                || self.unchecked_gt_parallelized(value, &second),
+            );
+
+            let (new_max, new_second) = rayon::join(
+                || self.unchecked_if_then_else_parallelized(&gt_max, value, &max),
+                || {
+                    let candidate =
+                        self.unchecked_if_then_else_parallelized(&gt_second, value, &second);
+                    self.unchecked_if_then_else_parallelized(&gt_max, &max, &candidate)
+                },
+            );
+            max = new_max;
+            second = new_second;
+        }
+
+        (max, second)
+    }
+
+    /// Returns `(max(a, b), |a - b|)`
+    ///
+    /// The comparison `a > b` is computed once, and is used both to select the maximum and to
+    /// order the operands of the subtraction, so that `|a - b| = max(a, b) - min(a, b)`.
+    ///
+    /// Works for unsigned and signed ciphertexts.
+    ///
+    /// # Overflow
+    ///
+    /// - For unsigned ciphertexts, `|a - b|` always fits in the ciphertext, there is no overflow.
+    /// - For signed ciphertexts, `|a - b|` may not fit in the signed range: with 8 bits,
+    ///   `|127 - (-128)| = 255`. The subtraction wraps around, so the blocks of the returned
+    ///   difference always encrypt `|a - b|` as an *unsigned* integer of the same size, and the
+    ///   difference is correct when its blocks are interpreted as a
+    ///   [crate::integer::RadixCiphertext].
+    ///
+    /// The returned ciphertexts have clean blocks.
+    ///
+    /// # Panics
+    ///
+    /// - if a and b do not have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::prelude::*;
+    /// use tfhe::integer::{gen_keys_radix, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = 42u8;
+    /// let b = 200u8;
+    ///
+    /// let (ct_max, ct_diff) = sks.max_and_absdiff_parallelized(&cks.encrypt(a), &cks.encrypt(b));
+    ///
+    /// // Decrypt:
+    /// let dec_max: u8 = cks.decrypt(&ct_max);
+    /// let dec_diff: u8 = cks.decrypt(&ct_diff);
+    /// assert_eq!(dec_max, 200);
+    /// assert_eq!(dec_diff, 158);
+    ///
+    /// let a = 127i8;
+    /// let b = -128i8;
+    ///
+    /// let (ct_max, ct_diff) =
+    ///     sks.max_and_absdiff_parallelized(&cks.encrypt_signed(a), &cks.encrypt_signed(b));
+    ///
+    /// // Decrypt, the difference is read as unsigned:
+    /// let dec_max: i8 = cks.decrypt_signed(&ct_max);
+    /// let ct_diff = RadixCiphertext::from_blocks(ct_diff.into_blocks());
+    /// let dec_diff: u8 = cks.decrypt(&ct_diff);
+    /// assert_eq!(dec_max, 127);
+    /// assert_eq!(dec_diff, 255);
+    /// ```
+    pub fn max_and_absdiff_parallelized<T>(&self, a: &T, b: &T) -> (T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            a.blocks().len(),
+            b.blocks().len(),
+            "a and b must have the same number of blocks"
+        );
+
+        let [a, b] = self.propagated_if_needed_parallelized([a, b]);
+
+        let a_gt_b = self.unchecked_gt_parallelized(&*a, &*b);
+        let (max, min) = self.if_then_else_keep_loser_parallelized(&a_gt_b, &*a, &*b);
+        let absdiff = self.sub_parallelized(&max, &min);
+
+        (max, absdiff)
+    }
+
+    /// Compares two ciphertexts, and returns the result as an encrypted ordering
+    ///
+    /// The returned ciphertext encrypts:
+    ///
+    /// - 0 if `lhs < rhs`
+    /// - 1 if `lhs == rhs`
+    /// - 2 if `lhs > rhs`
+    ///
+    /// It has the number of blocks needed to represent 2, which is a single block when the
+    /// message modulus is at least 4.
+    ///
+    /// Instead of computing `lhs < rhs` and `lhs == rhs` separately and then selecting, the
+    /// ordering of each pair of blocks is computed (which contains both the sign of the
+    /// difference and whether it is zero), and these orderings are then reduced in a tree.
+    ///
+    /// # Panics
+    ///
+    /// - if lhs and rhs do not have the same number of blocks
+    /// - if the carry modulus is smaller than the message modulus
+    /// - if the message modulus * carry modulus is less than 16
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// for (clear_a, clear_b, expected) in [(-7i8, 12i8, 0u8), (-7, -7, 1), (12, -7, 2)] {
+    ///     let a = cks.encrypt_signed(clear_a);
+    ///     let b = cks.encrypt_signed(clear_b);
+    ///
+    ///     let ordering = sks.compare_three_way_parallelized(&a, &b);
+    ///
+    ///     // Decrypt:
+    ///     let dec: u8 = cks.decrypt(&ordering);
+    ///     assert_eq!(dec, expected);
+    /// }
+    /// ```
+    pub fn compare_three_way_parallelized<T>(&self, lhs: &T, rhs: &T) -> RadixCiphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        const IS_INFERIOR: u64 = 0;
+        const IS_EQUAL: u64 = 1;
+        const IS_SUPERIOR: u64 = 2;
+
+        assert_eq!(
+            lhs.blocks().len(),
+            rhs.blocks().len(),
+            "lhs and rhs must have the same number of blocks"
+        );
+        let message_modulus = self.message_modulus().0;
+        assert!(
+            self.carry_modulus().0 >= message_modulus,
+            "The carry modulus must be >= to the message modulus"
+        );
+        assert!(
+            message_modulus * self.carry_modulus().0 >= 16,
+            "At least 4 bits of space (message + carry) are required"
+        );
+
+        let [lhs, rhs] = self.propagated_if_needed_parallelized([lhs, rhs]);
+
+        let num_result_blocks = if message_modulus > IS_SUPERIOR { 1 } else { 2 };
+
+        let ordering = |x: u64, y: u64| match x.cmp(&y) {
+            std::cmp::Ordering::Less => IS_INFERIOR,
+            std::cmp::Ordering::Equal => IS_EQUAL,
+            std::cmp::Ordering::Greater => IS_SUPERIOR,
+        };
+
+        if lhs.blocks().is_empty() {
+            // We interpret empty as 0
+            return self.create_trivial_radix(IS_EQUAL, num_result_blocks);
+        }
+
+        // The most significant block decides, unless it is equal
+        let reduce_two_orderings = |msb: u64, lsb: u64| if msb == IS_EQUAL { lsb } else { msb };
+
+        // First, the ordering of each pair of blocks
+        let block_lut = self.key.generate_lookup_table_bivariate(ordering);
+        let sign_block_lut = self.key.generate_lookup_table_bivariate(|x, y| {
+            // Interpret the blocks as two's complement values
+            let half_modulus = message_modulus / 2;
+            let to_signed = |v: u64| {
+                if v >= half_modulus {
+                    v as i64 - message_modulus as i64
+                } else {
+                    v as i64
+                }
+            };
+            match to_signed(x).cmp(&to_signed(y)) {
+                std::cmp::Ordering::Less => IS_INFERIOR,
+                std::cmp::Ordering::Equal => IS_EQUAL,
+                std::cmp::Ordering::Greater => IS_SUPERIOR,
+            }
+        });
+        let num_blocks = lhs.blocks().len();
+        let mut orderings = lhs
+            .blocks()
+            .par_iter()
+            .zip(rhs.blocks().par_iter())
+            .enumerate()
+            .map(|(index, (lhs_block, rhs_block))| {
+                let lut = if T::IS_SIGNED && index == num_blocks - 1 {
+                    &sign_block_lut
+                } else {
+                    &block_lut
+                };
+                self.key
+                    .unchecked_apply_lookup_table_bivariate(lhs_block, rhs_block, lut)
+            })
+            .collect::<Vec<_>>();
+
+        // Then, reduce the orderings in a tree, each ordering is in 0..=2 so two of them
+        // packed as `msb * 4 + lsb` fit in 4 bits
+        let reduction_lut = self
+            .key
+            .generate_lookup_table(|x| reduce_two_orderings((x >> 2) & 3, x & 3));
+        while orderings.len() > 2 {
+            orderings = orderings
+                .par_chunks(2)
+                .map(|chunk| match chunk {
+                    [lsb, msb] => {
+                        let mut packed = self.key.unchecked_scalar_mul(msb, 4);
+                        self.key.unchecked_add_assign(&mut packed, lsb);
+                        self.key
+                            .apply_lookup_table_assign(&mut packed, &reduction_lut);
+                        packed
+                    }
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        // Finally, reduce the last orderings directly into the blocks of the result
+        if orderings.len() == 1 {
+            // A trivial equal ordering, on top of the single one, leaves it as is
+            orderings.push(self.key.create_trivial(IS_EQUAL));
+        }
+        let mut packed = self.key.unchecked_scalar_mul(&orderings[1], 4);
+        self.key.unchecked_add_assign(&mut packed, &orderings[0]);
+
+        let blocks = (0..num_result_blocks as u32)
+            .into_par_iter()
+            .map(|block_index| {
+                let lut = self.key.generate_lookup_table(|x| {
+                    let ordering = reduce_two_orderings((x >> 2) & 3, x & 3);
+                    ordering / message_modulus.pow(block_index) % message_modulus
+                });
+                self.key.apply_lookup_table(&packed, &lut)
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
 }
@@ -1,4 +1,5 @@
 use super::ServerKey;
+use crate::integer::prelude::ServerKeyDefaultCMux;
 use crate::integer::server_key::num_bits_to_represent_unsigned_value;
 use crate::integer::{IntegerRadixCiphertext, RadixCiphertext, SignedRadixCiphertext};
 use crate::shortint::ciphertext::Degree;
@@ -127,6 +128,57 @@ impl ServerKey {
         self.unchecked_count_bits_parallelized(ct, kind)
     }
 
+    /// Returns `true_ct` if the number of ones in the binary representation of `ct` is at least
+    /// `threshold`, `false_ct` otherwise
+    ///
+    /// * The number of ones is computed with [Self::count_ones_parallelized]
+    /// * The returned result has clean blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// // 0b1011_0010 has 4 ones
+    /// let ct = cks.encrypt(0b1011_0010u8);
+    /// let ct_true = cks.encrypt(17u8);
+    /// let ct_false = cks.encrypt(42u8);
+    ///
+    /// let ct_res = sks.select_if_popcount_ge_parallelized(&ct, 4, &ct_true, &ct_false);
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 17);
+    ///
+    /// let ct_res = sks.select_if_popcount_ge_parallelized(&ct, 5, &ct_true, &ct_false);
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 42);
+    /// ```
+    pub fn select_if_popcount_ge_parallelized<T>(
+        &self,
+        ct: &RadixCiphertext,
+        threshold: u32,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut count = self.count_ones_parallelized(ct);
+        // The count is at most the number of bits of ct, the other blocks are always 0
+        let num_bits_in_ct = ct.blocks.len() as u32 * self.message_modulus().0.ilog2();
+        let num_bits_in_count = num_bits_to_represent_unsigned_value(num_bits_in_ct);
+        let num_blocks_in_count =
+            num_bits_in_count.div_ceil(self.message_modulus().0.ilog2() as usize);
+        count.blocks.truncate(num_blocks_in_count);
+
+        let condition = self.scalar_ge_parallelized(&count, threshold);
+        self.if_then_else_parallelized(&condition, true_ct, false_ct)
+    }
+
     /// 'Naive' implementation of count zeros/ones
     ///
     /// * It will work for all parameters
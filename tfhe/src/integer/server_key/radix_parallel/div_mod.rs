@@ -1012,4 +1012,68 @@ impl ServerKey {
 
         (r, BooleanBlock::new_unchecked(rem_by_0))
     }
+
+    /// Divides both `num` and `den` by `g` if `condition` encrypts true
+    ///
+    /// - If condition == 1, `num` and `den` are updated to `num / g` and `den / g`.
+    /// - If condition == 0, `num` and `den` keep their values.
+    ///
+    /// This is a step of the reduction of the fraction `num / den`: `g` is a candidate common
+    /// divisor (e.g. from steps of a gcd computation), and `condition` encrypts whether it divides
+    /// both `num` and `den`. If `g` does not divide them while `condition` is true, the results are
+    /// the truncated quotients. As for [Self::div_parallelized], dividing by `g == 0` gives
+    /// the maximum value.
+    ///
+    /// # Cost
+    ///
+    /// Each call does two full divisions, computed in parallel, followed by two conditional moves.
+    /// The divisions are always computed whatever the value of `condition`, so the cost of a call
+    /// does not depend on any encrypted value.
+    ///
+    /// The resulting ciphertexts have clean blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let mut num = cks.encrypt(42u8);
+    /// let mut den = cks.encrypt(56u8);
+    /// let g = cks.encrypt(14u8);
+    ///
+    /// let num_rem = sks.rem_parallelized(&num, &g);
+    /// let den_rem = sks.rem_parallelized(&den, &g);
+    /// let num_rem_is_zero = sks.scalar_eq_parallelized(&num_rem, 0);
+    /// let den_rem_is_zero = sks.scalar_eq_parallelized(&den_rem, 0);
+    /// let is_divisible = sks.boolean_bitand(&num_rem_is_zero, &den_rem_is_zero);
+    ///
+    /// sks.conditional_reduce_fraction_step_parallelized(&mut num, &mut den, &g, &is_divisible);
+    ///
+    /// // Decrypt:
+    /// let dec_num: u8 = cks.decrypt(&num);
+    /// let dec_den: u8 = cks.decrypt(&den);
+    /// assert_eq!((dec_num, dec_den), (3, 4));
+    /// ```
+    pub fn conditional_reduce_fraction_step_parallelized(
+        &self,
+        num: &mut RadixCiphertext,
+        den: &mut RadixCiphertext,
+        g: &RadixCiphertext,
+        condition: &BooleanBlock,
+    ) {
+        let (reduced_num, reduced_den) = rayon::join(
+            || self.div_parallelized(num, g),
+            || self.div_parallelized(den, g),
+        );
+
+        rayon::join(
+            || self.conditional_move_parallelized(num, &reduced_num, condition),
+            || self.conditional_move_parallelized(den, &reduced_den, condition),
+        );
+    }
 }
@@ -0,0 +1,75 @@
+use crate::integer::prelude::ServerKeyDefaultCMux;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+
+impl ServerKey {
+    /// Increments a value encoded in Gray code
+    ///
+    /// The next value of a Gray code differs from the current one by exactly one bit:
+    ///
+    /// - if the number of ones is even, the bit flipped is the lowest bit
+    /// - otherwise, the bit flipped is the one on the left of the lowest set bit, or the highest
+    ///   bit if the lowest set bit is the highest bit, meaning that the counter wraps around.
+    ///
+    /// This is a default function, it will internally clone the ciphertext if it has
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let to_gray = |value: u8| value ^ (value >> 1);
+    ///
+    /// let msg = to_gray(41);
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.gray_increment_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, to_gray(42));
+    /// ```
+    pub fn gray_increment_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.clone();
+            self.full_propagate_parallelized(&mut tmp);
+            &tmp
+        };
+
+        let num_bits = self.message_modulus().0.ilog2() * ct.blocks.len() as u32;
+        if num_bits == 0 {
+            return ct.clone();
+        }
+
+        let (has_odd_parity, bit_to_flip_if_odd) = rayon::join(
+            || {
+                let num_ones = self.count_ones_parallelized(ct);
+                let lut = self.key.generate_lookup_table(|x| x & 1);
+                BooleanBlock::new_unchecked(self.key.apply_lookup_table(&num_ones.blocks[0], &lut))
+            },
+            || {
+                // When ct is 0, the parity is even, so the overflow of the addition does not matter
+                let trailing_zeros = self.trailing_zeros_parallelized(ct);
+                let bit_on_the_left = self.scalar_add_parallelized(&trailing_zeros, 1);
+                self.scalar_min_parallelized(&bit_on_the_left, num_bits - 1)
+            },
+        );
+
+        let bit_to_flip = self.if_then_else_parallelized(&has_odd_parity, &bit_to_flip_if_odd, 0);
+
+        let one = self.create_trivial_radix(1, ct.blocks.len());
+        let mask = self.left_shift_parallelized(&one, &bit_to_flip);
+
+        self.bitxor_parallelized(ct, &mask)
+    }
+}
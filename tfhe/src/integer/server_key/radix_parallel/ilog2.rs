@@ -1,3 +1,4 @@
+use crate::integer::prelude::ServerKeyDefaultCMux;
 use crate::integer::{
     BooleanBlock, IntegerCiphertext, IntegerRadixCiphertext, RadixCiphertext, ServerKey,
     SignedRadixCiphertext,
@@ -688,4 +689,53 @@ impl ServerKey {
             || self.scalar_gt_parallelized(ct, 0),
         )
     }
+
+    /// FHE "if then else" selection, where the condition is whether the number of leading
+    /// zeros of `ct` is greater or equal to `threshold`
+    ///
+    /// - If `ct.leading_zeros() >= threshold`, the returned ciphertext will encrypt the same value
+    ///   as true_ct.
+    /// - Otherwise, the returned ciphertext will encrypt the same value as false_ct.
+    ///
+    /// This is a default function, it will internally clone the ciphertexts if they have
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg = 5u8;
+    /// let a = 128u8;
+    /// let b = 55u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// let ct_res = sks.select_if_leading_zeros_ge_parallelized(&ct, 4, &ct_a, &ct_b);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, if msg.leading_zeros() >= 4 { a } else { b });
+    /// ```
+    pub fn select_if_leading_zeros_ge_parallelized<T>(
+        &self,
+        ct: &RadixCiphertext,
+        threshold: u32,
+        true_ct: &T,
+        false_ct: &T,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let leading_zeros = self.leading_zeros_parallelized(ct);
+        let condition = self.scalar_ge_parallelized(&leading_zeros, threshold);
+        self.if_then_else_parallelized(&condition, true_ct, false_ct)
+    }
 }
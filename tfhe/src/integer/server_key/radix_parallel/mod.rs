@@ -6,9 +6,11 @@ mod block_shift;
 pub(crate) mod cmux;
 mod comparison;
 mod div_mod;
+mod gray_code;
 mod modulus_switch_compression;
 mod mul;
 mod neg;
+mod reduce;
 mod rotate;
 mod scalar_add;
 mod scalar_bitwise_op;
@@ -18,6 +20,7 @@ mod scalar_mul;
 mod scalar_rotate;
 mod scalar_shift;
 mod scalar_sub;
+mod select_builder;
 mod shift;
 pub(crate) mod sub;
 mod sum;
@@ -37,14 +40,20 @@ pub(crate) mod tests_signed;
 pub(crate) mod tests_unsigned;
 mod vector_comparisons;
 mod vector_find;
+mod vector_select;
 
 use super::ServerKey;
 use crate::integer::ciphertext::IntegerRadixCiphertext;
 use crate::integer::RadixCiphertext;
 use crate::shortint::ciphertext::{Ciphertext, NoiseLevel};
 pub(crate) use add::OutputFlag;
+pub use cmux::{ArithmeticSelectPlan, CarrySave, ProgrammableSelectPlan};
 use rayon::prelude::*;
+pub use reduce::ReduceOp;
+use std::borrow::Cow;
 pub use scalar_div_mod::{MiniUnsignedInteger, Reciprocable};
+pub use scalar_shift::ShiftDir;
+pub use select_builder::SelectBuilder;
 pub use vector_find::MatchValues;
 
 // parallelized versions
@@ -252,4 +261,35 @@ impl ServerKey {
             }
         })
     }
+
+    /// Returns the given ciphertexts with empty carries
+    ///
+    /// The ciphertexts that already have empty carries are borrowed, the others are cloned
+    /// and their carries are propagated, in parallel.
+    pub(crate) fn propagated_if_needed_parallelized<'a, T, const N: usize>(
+        &self,
+        cts: [&'a T; N],
+    ) -> [Cow<'a, T>; N]
+    where
+        T: IntegerRadixCiphertext,
+    {
+        cleaned_if_needed(cts, |ct| self.full_propagate_parallelized(ct))
+    }
+}
+
+/// Returns the given ciphertexts, where the ones with non-empty carries are cloned and
+/// cleaned with `clean`, in parallel
+pub(crate) fn cleaned_if_needed<T, F, const N: usize>(
+    cts: [&T; N],
+    clean: F,
+) -> [Cow<'_, T>; N]
+where
+    T: IntegerRadixCiphertext,
+    F: Fn(&mut T) + Sync,
+{
+    let mut cts = cts.map(Cow::Borrowed);
+    cts.par_iter_mut()
+        .filter(|ct| !ct.block_carries_are_empty())
+        .for_each(|ct| clean(ct.to_mut()));
+    cts
 }
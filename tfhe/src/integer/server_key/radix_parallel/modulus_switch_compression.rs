@@ -3,7 +3,10 @@ use crate::integer::ciphertext::{
     CompressedModulusSwitchedRadixCiphertextGeneric,
     CompressedModulusSwitchedSignedRadixCiphertext,
 };
-use crate::integer::{RadixCiphertext, ServerKey, SignedRadixCiphertext};
+use crate::integer::prelude::ServerKeyDefaultCMux;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
+use crate::shortint::ciphertext::CompressedModulusSwitchedCiphertext;
+use crate::shortint::server_key::LookupTableOwned;
 use crate::shortint::Ciphertext;
 use rayon::prelude::*;
 
@@ -56,6 +59,143 @@ impl ServerKey {
         }
     }
 
+    /// Compresses a boolean block to have a smaller serialization size
+    ///
+    /// See [Self::if_then_else_compressed_e2e_parallelized] for usage
+    pub fn switch_modulus_and_compress_boolean_block(
+        &self,
+        ct: &BooleanBlock,
+    ) -> CompressedModulusSwitchedCiphertext {
+        self.key.switch_modulus_and_compress(&ct.0)
+    }
+
+    /// Decompresses a compressed boolean block
+    /// This operation costs a PBS
+    ///
+    /// See [Self::if_then_else_compressed_e2e_parallelized] for usage
+    pub fn decompress_boolean_block(
+        &self,
+        compressed_ct: &CompressedModulusSwitchedCiphertext,
+    ) -> BooleanBlock {
+        let lut = self.key.generate_lookup_table(|x| x & 1);
+        BooleanBlock::new_unchecked(
+            self.key
+                .decompress_and_apply_lookup_table(compressed_ct, &lut),
+        )
+    }
+
+    /// Selects between two compressed ciphertexts, depending on a compressed condition,
+    /// and returns the compressed result
+    ///
+    /// - If condition == 1, the result will encrypt the same value as `true_ct`
+    /// - If condition == 0, the result will encrypt the same value as `false_ct`
+    ///
+    /// The condition must be compressed with [Self::switch_modulus_and_compress_boolean_block]
+    /// and the branches with [Self::switch_modulus_and_compress_parallelized].
+    ///
+    /// # Memory
+    ///
+    /// The branches are never fully decompressed: each compressed pair of blocks is decompressed,
+    /// selected and compressed back independently. So, on top of the decompressed condition
+    /// block, the peak memory is a few uncompressed blocks per rayon thread (the pair of each
+    /// branch and the selected pair), whatever the number of blocks of the inputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the branches do not have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clear_true = 27u8;
+    /// let clear_false = 128u8;
+    ///
+    /// let true_ct = sks.switch_modulus_and_compress_parallelized(&cks.encrypt(clear_true));
+    /// let false_ct = sks.switch_modulus_and_compress_parallelized(&cks.encrypt(clear_false));
+    /// let condition = sks.switch_modulus_and_compress_boolean_block(&cks.encrypt_bool(true));
+    ///
+    /// let compressed_result =
+    ///     sks.if_then_else_compressed_e2e_parallelized(&condition, &true_ct, &false_ct);
+    ///
+    /// // Decrypt:
+    /// let result = sks.decompress_parallelized(&compressed_result);
+    /// let dec: u8 = cks.decrypt(&result);
+    /// assert_eq!(dec, clear_true);
+    /// ```
+    pub fn if_then_else_compressed_e2e_parallelized(
+        &self,
+        condition: &CompressedModulusSwitchedCiphertext,
+        true_ct: &CompressedModulusSwitchedRadixCiphertext,
+        false_ct: &CompressedModulusSwitchedRadixCiphertext,
+    ) -> CompressedModulusSwitchedRadixCiphertext {
+        let (true_ct, false_ct) = (&true_ct.0, &false_ct.0);
+        assert!(
+            true_ct.paired_blocks.len() == false_ct.paired_blocks.len()
+                && true_ct.last_block.is_some() == false_ct.last_block.is_some(),
+            "Both branches must have the same number of blocks"
+        );
+
+        let condition = self.decompress_boolean_block(condition);
+        let [message_extract, carry_extract] = self.decompression_lookup_tables();
+
+        let select_and_compress = |true_blocks: Vec<Ciphertext>, false_blocks: Vec<Ciphertext>| {
+            let result = self.if_then_else_parallelized(
+                &condition,
+                &RadixCiphertext::from(true_blocks),
+                &RadixCiphertext::from(false_blocks),
+            );
+            self.switch_modulus_and_compress_generic_parallelized(&result.blocks)
+        };
+
+        let paired_blocks = true_ct
+            .paired_blocks
+            .par_iter()
+            .zip(false_ct.paired_blocks.par_iter())
+            .map(|(true_pair, false_pair)| {
+                let (true_blocks, false_blocks) = rayon::join(
+                    || self.decompress_block_pair(true_pair, &message_extract, &carry_extract),
+                    || self.decompress_block_pair(false_pair, &message_extract, &carry_extract),
+                );
+                select_and_compress(true_blocks.to_vec(), false_blocks.to_vec())
+                    .paired_blocks
+                    .pop()
+                    .unwrap()
+            })
+            .collect();
+
+        let last_block = true_ct
+            .last_block
+            .as_ref()
+            .zip(false_ct.last_block.as_ref())
+            .map(|(true_block, false_block)| {
+                let (true_block, false_block) = rayon::join(
+                    || {
+                        self.key
+                            .decompress_and_apply_lookup_table(true_block, &message_extract)
+                    },
+                    || {
+                        self.key
+                            .decompress_and_apply_lookup_table(false_block, &message_extract)
+                    },
+                );
+                select_and_compress(vec![true_block], vec![false_block])
+                    .last_block
+                    .unwrap()
+            });
+
+        CompressedModulusSwitchedRadixCiphertext(CompressedModulusSwitchedRadixCiphertextGeneric {
+            paired_blocks,
+            last_block,
+        })
+    }
+
     #[allow(clippy::int_plus_one)]
     fn switch_modulus_and_compress_generic_parallelized(
         &self,
@@ -111,25 +251,12 @@ impl ServerKey {
         &self,
         compressed_ct: &CompressedModulusSwitchedRadixCiphertextGeneric,
     ) -> Vec<Ciphertext> {
-        let message_extract = self
-            .key
-            .generate_lookup_table(|x| x % self.message_modulus().0);
-
-        let carry_extract = self
-            .key
-            .generate_lookup_table(|x| x / self.message_modulus().0);
+        let [message_extract, carry_extract] = self.decompression_lookup_tables();
 
         let mut blocks: Vec<Ciphertext> = compressed_ct
             .paired_blocks
             .par_iter()
-            .flat_map(|a| {
-                [
-                    self.key
-                        .decompress_and_apply_lookup_table(a, &message_extract),
-                    self.key
-                        .decompress_and_apply_lookup_table(a, &carry_extract),
-                ]
-            })
+            .flat_map(|a| self.decompress_block_pair(a, &message_extract, &carry_extract))
             .collect();
 
         if let Some(last_block) = compressed_ct.last_block.as_ref() {
@@ -141,4 +268,34 @@ impl ServerKey {
 
         blocks
     }
+
+    /// Returns the lookup tables that extract, from a decompressed block that packs two blocks,
+    /// the first block (`[message_extract, _]`) and the second block (`[_, carry_extract]`)
+    ///
+    /// A block that is not packed is decompressed with `message_extract`.
+    fn decompression_lookup_tables(&self) -> [LookupTableOwned; 2] {
+        let message_modulus = self.message_modulus().0;
+
+        [
+            self.key.generate_lookup_table(|x| x % message_modulus),
+            self.key.generate_lookup_table(|x| x / message_modulus),
+        ]
+    }
+
+    /// Decompresses a block that packs two blocks, returning them in order
+    ///
+    /// The lookup tables are the ones returned by [Self::decompression_lookup_tables]
+    fn decompress_block_pair(
+        &self,
+        compressed_pair: &CompressedModulusSwitchedCiphertext,
+        message_extract: &LookupTableOwned,
+        carry_extract: &LookupTableOwned,
+    ) -> [Ciphertext; 2] {
+        [
+            self.key
+                .decompress_and_apply_lookup_table(compressed_pair, message_extract),
+            self.key
+                .decompress_and_apply_lookup_table(compressed_pair, carry_extract),
+        ]
+    }
 }
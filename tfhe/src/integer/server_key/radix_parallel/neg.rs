@@ -1,5 +1,6 @@
 use crate::integer::ciphertext::IntegerRadixCiphertext;
-use crate::integer::ServerKey;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey, SignedRadixCiphertext};
+use rayon::prelude::*;
 
 impl ServerKey {
     /// Homomorphically computes the opposite of a ciphertext encrypting an integer message.
@@ -92,4 +93,151 @@ impl ServerKey {
         self.full_propagate_parallelized(&mut ct);
         ct
     }
+
+    /// Converts a sign-magnitude value to two's complement
+    ///
+    /// Returns a signed ciphertext encrypting `-magnitude` if `sign` encrypts true,
+    /// `magnitude` otherwise, with the same number of blocks as `magnitude`.
+    ///
+    /// The negation is conditional: each block of `magnitude` goes through a LUT that flips
+    /// its bits only if `sign` is true, and `sign` is then added as the `+1` of the two's
+    /// complement before propagating the carries.
+    ///
+    /// # Overflow
+    ///
+    /// The result is computed modulo `2^n` (`n` being the number of bits of `magnitude`) and
+    /// reinterpreted as signed, so it is only correct when the signed value fits:
+    ///
+    /// - if `sign` is false, `magnitude` must be at most `2^(n-1) - 1`
+    /// - if `sign` is true, `magnitude` must be at most `2^(n-1)`
+    ///
+    /// Larger magnitudes wrap around, e.g. on 8 bits, a positive magnitude of 200 gives -56 and
+    /// a negative magnitude of 255 gives 1.
+    ///
+    /// To ensure correct results, sign must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let magnitude = cks.encrypt(100u8);
+    ///
+    /// for (sign, expected) in [(false, 100i8), (true, -100i8)] {
+    ///     let ct_sign = cks.encrypt_bool(sign);
+    ///     let ct_res = sks.apply_sign_parallelized(&magnitude, &ct_sign);
+    ///
+    ///     // Decrypt
+    ///     let dec: i8 = cks.decrypt_signed(&ct_res);
+    ///     assert_eq!(dec, expected);
+    /// }
+    /// ```
+    pub fn apply_sign_parallelized(
+        &self,
+        magnitude: &RadixCiphertext,
+        sign: &BooleanBlock,
+    ) -> SignedRadixCiphertext {
+        let mut tmp_magnitude;
+
+        let magnitude = if magnitude.block_carries_are_empty() {
+            magnitude
+        } else {
+            tmp_magnitude = magnitude.clone();
+            self.full_propagate_parallelized(&mut tmp_magnitude);
+            &tmp_magnitude
+        };
+
+        let message_modulus = self.message_modulus().0;
+        let lut = self.key.generate_lookup_table_bivariate(|block, sign| {
+            if sign == 1 {
+                message_modulus - 1 - block
+            } else {
+                block
+            }
+        });
+
+        // Trivial zero blocks are not skipped, they become all ones when sign is true
+        let mut blocks = magnitude
+            .blocks
+            .par_iter()
+            .map(|block| {
+                self.key
+                    .unchecked_apply_lookup_table_bivariate(block, &sign.0, &lut)
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(first_block) = blocks.first_mut() {
+            self.key.unchecked_add_assign(first_block, &sign.0);
+        }
+
+        let mut result = SignedRadixCiphertext::from(blocks);
+        self.full_propagate_parallelized(&mut result);
+        result
+    }
+
+    /// Conditionally conjugates the complex number `re + i * im`
+    ///
+    /// - If condition == 1, `im` is negated in place, giving the complex conjugate.
+    /// - If condition == 0, `im` keeps its value.
+    ///
+    /// The real part is never modified, it is only taken to check that the two parts have the
+    /// same number of blocks. The negation is conditional, using the same lookup tables as
+    /// [Self::apply_sign_parallelized].
+    ///
+    /// # Overflow
+    ///
+    /// The negation is computed in two's complement, so negating the minimum value of the
+    /// imaginary part (e.g. -128 on 8 bits) overflows and gives that same minimum value back.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// - if `re` and `im` do not have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let re = cks.encrypt_signed(3i8);
+    /// let mut im = cks.encrypt_signed(-4i8);
+    /// let condition = cks.encrypt_bool(true);
+    ///
+    /// sks.conditional_conjugate_parallelized(&re, &mut im, &condition);
+    ///
+    /// // Decrypt
+    /// let dec_re: i8 = cks.decrypt_signed(&re);
+    /// let dec_im: i8 = cks.decrypt_signed(&im);
+    /// assert_eq!((dec_re, dec_im), (3, 4));
+    /// ```
+    pub fn conditional_conjugate_parallelized(
+        &self,
+        re: &SignedRadixCiphertext,
+        im: &mut SignedRadixCiphertext,
+        condition: &BooleanBlock,
+    ) {
+        assert_eq!(
+            re.blocks.len(),
+            im.blocks.len(),
+            "re and im must have the same number of blocks"
+        );
+
+        // Negating the two's complement bits of im is the same as applying the sign to them
+        // seen as an unsigned magnitude
+        let bits = RadixCiphertext::from(std::mem::take(&mut im.blocks));
+        *im = self.apply_sign_parallelized(&bits, condition);
+    }
 }
@@ -0,0 +1,221 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::ServerKey;
+use rayon::prelude::*;
+
+/// The operation applied at each node of a reduction tree
+///
+/// See [ServerKey::reduce_with_refresh_parallelized] and
+/// [ServerKey::reduce_select_chunked_parallelized]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ReduceOp {
+    /// Wrapping sum of the values
+    Sum,
+    /// Minimum of the values
+    Min,
+    /// Maximum of the values
+    Max,
+}
+
+impl ServerKey {
+    /// Reduces `values` with `op` in a balanced tree, refreshing the intermediate results
+    /// every `refresh_every` layers
+    ///
+    /// Each layer of the tree combines the values two by two, so there are
+    /// `ceil(log2(values.len()))` layers. A refresh is a carry propagation of all the values of
+    /// the layer: it cleans their carries and brings their noise back to the nominal level.
+    /// Blocks that already have empty carries and nominal noise are left as they are, they do
+    /// not go through a PBS.
+    ///
+    /// - For [ReduceOp::Sum], the nodes are additions without carry propagation, so the noise and
+    ///   the degree of the blocks grow with each layer. A larger `refresh_every` means fewer
+    ///   refreshes, at the cost of consuming more of the carry and noise budget. Whatever the value
+    ///   of `refresh_every`, a refresh is also done before a layer whose additions would exceed
+    ///   what the parameters support.
+    /// - For [ReduceOp::Min] and [ReduceOp::Max], the nodes are comparisons followed by selections,
+    ///   whose outputs are already freshly bootstrapped, so refreshes have nothing to do.
+    ///
+    /// A `refresh_every` of 0 is the default cadence: intermediate results are only refreshed
+    /// when required by the parameters, that is as late as possible.
+    ///
+    /// The returned ciphertext has clean blocks.
+    ///
+    /// # Panics
+    ///
+    /// - if `values` is empty
+    /// - if the values do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::server_key::ReduceOp;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clears = [13u8, 200, 7, 42, 99];
+    /// let values = clears.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    ///
+    /// let sum = sks.reduce_with_refresh_parallelized(&values, ReduceOp::Sum, 1);
+    /// let max = sks.reduce_with_refresh_parallelized(&values, ReduceOp::Max, 0);
+    ///
+    /// // Decrypt:
+    /// let dec_sum: u8 = cks.decrypt(&sum);
+    /// let dec_max: u8 = cks.decrypt(&max);
+    /// assert_eq!(
+    ///     dec_sum,
+    ///     clears.iter().fold(0u8, |acc, v| acc.wrapping_add(*v))
+    /// );
+    /// assert_eq!(dec_max, 200);
+    /// ```
+    pub fn reduce_with_refresh_parallelized<T>(
+        &self,
+        values: &[T],
+        op: ReduceOp,
+        refresh_every: usize,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!values.is_empty(), "Cannot reduce an empty slice");
+        let num_blocks = values[0].blocks().len();
+        assert!(
+            values.iter().all(|ct| ct.blocks().len() == num_blocks),
+            "All values must have the same number of blocks"
+        );
+
+        let mut layer = values
+            .par_iter()
+            .map(|ct| {
+                let mut ct = ct.clone();
+                if !ct.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut ct);
+                }
+                ct
+            })
+            .collect::<Vec<_>>();
+
+        let mut layers_since_refresh = 0;
+        while layer.len() > 1 {
+            let cadence_reached = refresh_every != 0 && layers_since_refresh == refresh_every;
+            let add_possible = || {
+                layer.chunks_exact(2).all(|pair| {
+                    pair[0]
+                        .blocks()
+                        .iter()
+                        .zip(pair[1].blocks())
+                        .all(|(lhs, rhs)| {
+                            self.key
+                                .is_add_possible(lhs.noise_degree(), rhs.noise_degree())
+                                .is_ok()
+                        })
+                })
+            };
+            if layers_since_refresh != 0 && (cadence_reached || !add_possible()) {
+                layer
+                    .par_iter_mut()
+                    .for_each(|ct| self.full_propagate_parallelized(ct));
+                layers_since_refresh = 0;
+            }
+
+            layer = layer
+                .par_chunks(2)
+                .map(|chunk| match chunk {
+                    [lhs, rhs] => match op {
+                        ReduceOp::Sum => self.unchecked_add(lhs, rhs),
+                        ReduceOp::Min => self.unchecked_min_parallelized(lhs, rhs),
+                        ReduceOp::Max => self.unchecked_max_parallelized(lhs, rhs),
+                    },
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers_since_refresh += 1;
+        }
+
+        let mut result = layer.pop().unwrap();
+        self.full_propagate_parallelized(&mut result);
+        result
+    }
+
+    /// Reduces `values` with `op`, one chunk of at most `chunk` values at a time
+    ///
+    /// The chunks are processed sequentially: each chunk is reduced with a balanced tree, like
+    /// [Self::reduce_with_refresh_parallelized], which is parallelized within the chunk, and the
+    /// result is then combined into an accumulator.
+    ///
+    /// # Memory profile
+    ///
+    /// Reducing all the values in a single tree makes copies of all of them, and rayon may keep
+    /// many intermediate results alive at the same time. Here, at most about `chunk` values
+    /// (and the intermediate results of their tree) plus the accumulator are alive at any time,
+    /// whatever the number of values. A smaller `chunk` bounds the memory more tightly, at the
+    /// cost of less parallelism: `chunk` is the knob between memory usage and parallelism.
+    ///
+    /// The returned ciphertext has clean blocks.
+    ///
+    /// # Panics
+    ///
+    /// - if `values` is empty
+    /// - if `chunk` is 0
+    /// - if the values do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::server_key::ReduceOp;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clears = [13u8, 200, 7, 42, 99];
+    /// let values = clears.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    ///
+    /// let sum = sks.reduce_select_chunked_parallelized(&values, 2, ReduceOp::Sum);
+    /// let min = sks.reduce_select_chunked_parallelized(&values, 2, ReduceOp::Min);
+    ///
+    /// // Decrypt:
+    /// let dec_sum: u8 = cks.decrypt(&sum);
+    /// let dec_min: u8 = cks.decrypt(&min);
+    /// assert_eq!(
+    ///     dec_sum,
+    ///     clears.iter().fold(0u8, |acc, v| acc.wrapping_add(*v))
+    /// );
+    /// assert_eq!(dec_min, 7);
+    /// ```
+    pub fn reduce_select_chunked_parallelized<T>(
+        &self,
+        values: &[T],
+        chunk: usize,
+        op: ReduceOp,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!values.is_empty(), "Cannot reduce an empty slice");
+        assert_ne!(chunk, 0, "The chunk size must not be 0");
+        let num_blocks = values[0].blocks().len();
+        assert!(
+            values.iter().all(|ct| ct.blocks().len() == num_blocks),
+            "All values must have the same number of blocks"
+        );
+
+        let mut chunks = values.chunks(chunk);
+        let first_chunk = chunks.next().unwrap();
+        let mut accumulator = self.reduce_with_refresh_parallelized(first_chunk, op, 0);
+        for chunk in chunks {
+            let partial = self.reduce_with_refresh_parallelized(chunk, op, 0);
+            accumulator = match op {
+                ReduceOp::Sum => self.add_parallelized(&accumulator, &partial),
+                ReduceOp::Min => self.min_parallelized(&accumulator, &partial),
+                ReduceOp::Max => self.max_parallelized(&accumulator, &partial),
+            };
+        }
+        accumulator
+    }
+}
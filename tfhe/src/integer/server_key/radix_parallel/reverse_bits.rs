@@ -1,6 +1,9 @@
 use super::ServerKey;
 use crate::integer::ciphertext::IntegerRadixCiphertext;
-use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use crate::integer::BooleanBlock;
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator,
+};
 
 impl ServerKey {
     /// Reverse the bits of the integer
@@ -56,6 +59,230 @@ impl ServerKey {
 
         T::from_blocks(blocks)
     }
+
+    /// Reverse the bits of the integer if `condition` encrypts true
+    ///
+    /// - If condition == 1, the result encrypts the bit reversed value of `ct`
+    /// - If condition == 0, the result encrypts the same value as `ct`
+    ///
+    /// The reversal always spans the whole width of `ct`, that is
+    /// `ct.blocks().len() * log2(message_modulus)` bits. For a value that only uses its `w`
+    /// lower bits, the reversed value is thus shifted to the left by the number of unused bits,
+    /// a right shift by `total_bits - w` gives the reversal on `w` bits.
+    ///
+    /// Rather than reversing and then selecting, the condition is applied in the same PBS as the
+    /// per-block bit reversal: the blocks in reversed order go through a LUT that reverses them or
+    /// zeroes them depending on the condition, the original blocks go through a LUT that zeroes
+    /// them when the condition is true, and the two are added together.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// let msg = 0b10110100_u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// for cond in [false, true] {
+    ///     let ct_cond = cks.encrypt_bool(cond);
+    ///     let ct_res = sks.conditional_bit_reverse_parallelized(&ct, &ct_cond);
+    ///
+    ///     // Decrypt:
+    ///     let res: u8 = cks.decrypt(&ct_res);
+    ///     assert_eq!(if cond { msg.reverse_bits() } else { msg }, res);
+    /// }
+    /// ```
+    pub fn conditional_bit_reverse_parallelized<T>(&self, ct: &T, condition: &BooleanBlock) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let message_modulus = self.message_modulus().0;
+
+        let mut clean_ct;
+
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            clean_ct = ct.clone();
+            self.full_propagate_parallelized(&mut clean_ct);
+            &clean_ct
+        };
+
+        let (reversed, kept) = rayon::join(
+            || {
+                let lut = self
+                    .key
+                    .generate_lookup_table_bivariate(|block, condition| {
+                        if condition == 1 {
+                            (block % message_modulus).reverse_bits()
+                                >> (64 - message_modulus.ilog2())
+                        } else {
+                            0
+                        }
+                    });
+                let mut reversed = T::from_blocks(ct.blocks().iter().rev().cloned().collect());
+                self.zero_out_with_lut(&mut reversed, &condition.0, &lut);
+                reversed
+            },
+            || {
+                let mut kept = ct.clone();
+                self.zero_out_if(&mut kept, &condition.0, |x| x == 1);
+                kept
+            },
+        );
+
+        // One of the two blocks is zero, the sum is the selected block
+        let mut result = kept;
+        result
+            .blocks_mut()
+            .par_iter_mut()
+            .zip(reversed.blocks().par_iter())
+            .for_each(|(lhs_block, rhs_block)| {
+                self.key.unchecked_add_assign(lhs_block, rhs_block);
+                self.key.message_extract_assign(lhs_block);
+            });
+
+        result
+    }
+
+    /// Permutes the blocks of the integer if `condition` encrypts true
+    ///
+    /// - If condition == 1, the block at position `i` of the result is the block at position
+    ///   `perm[i]` of `ct`
+    /// - If condition == 0, the result encrypts the same value as `ct`
+    ///
+    /// The permuted and the original blocks share the condition: the permuted block is zeroed
+    /// when the condition is false, the original block is zeroed when the condition is true, and
+    /// the two are added together. Positions that the permutation leaves in place cost no PBS.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `perm` is not a permutation of `0..ct.blocks().len()`
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, num_blocks);
+    ///
+    /// // Blocks from LSB to MSB: 0b00, 0b01, 0b10, 0b11
+    /// let msg = 0b11_10_01_00_u8;
+    /// // Swaps the two lower blocks and the two upper blocks
+    /// let perm = [1, 0, 3, 2];
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// for cond in [false, true] {
+    ///     let ct_cond = cks.encrypt_bool(cond);
+    ///     let ct_res = sks.conditional_permute_blocks_parallelized(&ct, &perm, &ct_cond);
+    ///
+    ///     // Decrypt:
+    ///     let res: u8 = cks.decrypt(&ct_res);
+    ///     assert_eq!(if cond { 0b10_11_00_01 } else { msg }, res);
+    /// }
+    /// ```
+    pub fn conditional_permute_blocks_parallelized<T>(
+        &self,
+        ct: &T,
+        perm: &[usize],
+        condition: &BooleanBlock,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = ct.blocks().len();
+        assert_eq!(
+            perm.len(),
+            num_blocks,
+            "The permutation must have one index per block"
+        );
+        let mut seen = vec![false; num_blocks];
+        for &index in perm {
+            assert!(
+                index < num_blocks && !seen[index],
+                "{perm:?} is not a permutation of the {num_blocks} block indices"
+            );
+            seen[index] = true;
+        }
+
+        let mut clean_ct;
+
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            clean_ct = ct.clone();
+            self.full_propagate_parallelized(&mut clean_ct);
+            &clean_ct
+        };
+
+        // Positions left in place by the permutation are trivial zeros in both
+        // operands, so that no PBS is done on them
+        let moved_blocks = |permuted: bool| {
+            let blocks = perm
+                .iter()
+                .enumerate()
+                .map(|(i, &src)| {
+                    if src == i {
+                        self.key.create_trivial(0)
+                    } else if permuted {
+                        ct.blocks()[src].clone()
+                    } else {
+                        ct.blocks()[i].clone()
+                    }
+                })
+                .collect();
+            T::from_blocks(blocks)
+        };
+
+        let (kept, permuted) = rayon::join(
+            || {
+                let mut kept = moved_blocks(false);
+                self.zero_out_if(&mut kept, &condition.0, |x| x == 1);
+                kept
+            },
+            || {
+                let mut permuted = moved_blocks(true);
+                self.zero_out_if(&mut permuted, &condition.0, |x| x == 0);
+                permuted
+            },
+        );
+
+        let blocks = kept
+            .blocks()
+            .par_iter()
+            .zip(permuted.blocks().par_iter())
+            .enumerate()
+            .map(|(i, (kept_block, permuted_block))| {
+                if perm[i] == i {
+                    return ct.blocks()[i].clone();
+                }
+                // One of the two blocks is zero, the sum is the selected block
+                let mut block = self.key.unchecked_add(kept_block, permuted_block);
+                self.key.message_extract_assign(&mut block);
+                block
+            })
+            .collect();
+
+        T::from_blocks(blocks)
+    }
 }
 
 #[cfg(test)]
@@ -66,7 +293,7 @@ mod tests {
     use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
     use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
     use crate::integer::tests::create_parameterized_test;
-    use crate::integer::{IntegerKeyKind, RadixClientKey};
+    use crate::integer::{BooleanBlock, IntegerKeyKind, RadixClientKey};
     #[cfg(tarpaulin)]
     use crate::shortint::parameters::coverage_parameters::*;
     use crate::shortint::parameters::test_params::*;
@@ -123,4 +350,177 @@ mod tests {
     }
 
     create_parameterized_test!(integer_reverse_bits);
+
+    pub(crate) fn conditional_bit_reverse_test<P, T>(param: P, mut executor: T)
+    where
+        P: Into<PBSParameters>,
+        T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a BooleanBlock), RadixCiphertext>,
+    {
+        let param = param.into();
+        let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+        let sks = Arc::new(sks);
+
+        let nb_blocks = 4;
+
+        let cks = RadixClientKey::from((cks, nb_blocks));
+
+        executor.setup(&cks, sks.clone());
+
+        let log_modulus = nb_blocks * param.message_modulus().0.ilog2() as usize;
+        let modulus = 1 << log_modulus;
+
+        let nb_tests = 10;
+
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..nb_tests {
+            let clear = rng.gen::<u64>() % modulus;
+            let clear_cond = rng.gen_bool(0.5);
+
+            let mut ct = cks.encrypt(clear);
+            let cond = cks.encrypt_bool(clear_cond);
+
+            let result = executor.execute((&ct, &cond));
+            assert!(result.block_carries_are_empty());
+            let decrypted_result: u64 = cks.decrypt(&result);
+
+            let expected_result = if clear_cond {
+                clear.reverse_bits() >> (64 - log_modulus)
+            } else {
+                clear
+            };
+
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid conditional_bit_reverse result, gave clear = {clear}, \
+            condition = {clear_cond}, expected {expected_result}, got {decrypted_result}"
+            );
+
+            // Add to have non empty carries
+            let clear_2 = rng.gen::<u64>() % modulus;
+            sks.unchecked_scalar_add_assign(&mut ct, clear_2);
+            let clear = (clear + clear_2) % modulus;
+
+            let result = executor.execute((&ct, &cond));
+            assert!(result.block_carries_are_empty());
+            let decrypted_result: u64 = cks.decrypt(&result);
+
+            let expected_result = if clear_cond {
+                clear.reverse_bits() >> (64 - log_modulus)
+            } else {
+                clear
+            };
+
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid conditional_bit_reverse result, gave clear = {clear}, \
+            condition = {clear_cond}, expected {expected_result}, got {decrypted_result}"
+            );
+        }
+    }
+
+    fn integer_conditional_bit_reverse<P>(param: P)
+    where
+        P: Into<PBSParameters>,
+    {
+        let executor = CpuFunctionExecutor::new(&ServerKey::conditional_bit_reverse_parallelized);
+        conditional_bit_reverse_test(param, executor);
+    }
+
+    create_parameterized_test!(integer_conditional_bit_reverse);
+
+    pub(crate) fn conditional_permute_blocks_test<P, T>(param: P, mut executor: T)
+    where
+        P: Into<PBSParameters>,
+        T: for<'a> FunctionExecutor<
+            (&'a RadixCiphertext, &'a [usize], &'a BooleanBlock),
+            RadixCiphertext,
+        >,
+    {
+        let param = param.into();
+        let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+        let sks = Arc::new(sks);
+
+        let nb_blocks = 4;
+
+        let cks = RadixClientKey::from((cks, nb_blocks));
+
+        executor.setup(&cks, sks.clone());
+
+        let bits_per_block = param.message_modulus().0.ilog2() as usize;
+        let block_mask = param.message_modulus().0 - 1;
+        let log_modulus = nb_blocks * bits_per_block;
+        let modulus = 1 << log_modulus;
+
+        let nb_tests = 10;
+
+        let mut rng = rand::thread_rng();
+
+        let permute = |clear: u64, perm: &[usize]| {
+            perm.iter().enumerate().fold(0, |acc, (i, &src)| {
+                let block = (clear >> (src * bits_per_block)) & block_mask;
+                acc | (block << (i * bits_per_block))
+            })
+        };
+
+        for _ in 0..nb_tests {
+            let clear = rng.gen::<u64>() % modulus;
+            let clear_cond = rng.gen_bool(0.5);
+            let mut perm = (0..nb_blocks).collect::<Vec<_>>();
+            perm.shuffle(&mut rng);
+
+            let mut ct = cks.encrypt(clear);
+            let cond = cks.encrypt_bool(clear_cond);
+
+            let result = executor.execute((&ct, &perm, &cond));
+            assert!(result.block_carries_are_empty());
+            let decrypted_result: u64 = cks.decrypt(&result);
+
+            let expected_result = if clear_cond {
+                permute(clear, &perm)
+            } else {
+                clear
+            };
+
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid conditional_permute_blocks result, gave clear = {clear}, \
+            perm = {perm:?}, condition = {clear_cond}, expected {expected_result}, \
+            got {decrypted_result}"
+            );
+
+            // Add to have non empty carries
+            let clear_2 = rng.gen::<u64>() % modulus;
+            sks.unchecked_scalar_add_assign(&mut ct, clear_2);
+            let clear = (clear + clear_2) % modulus;
+
+            let result = executor.execute((&ct, &perm, &cond));
+            assert!(result.block_carries_are_empty());
+            let decrypted_result: u64 = cks.decrypt(&result);
+
+            let expected_result = if clear_cond {
+                permute(clear, &perm)
+            } else {
+                clear
+            };
+
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid conditional_permute_blocks result, gave clear = {clear}, \
+            perm = {perm:?}, condition = {clear_cond}, expected {expected_result}, \
+            got {decrypted_result}"
+            );
+        }
+    }
+
+    fn integer_conditional_permute_blocks<P>(param: P)
+    where
+        P: Into<PBSParameters>,
+    {
+        let executor =
+            CpuFunctionExecutor::new(&ServerKey::conditional_permute_blocks_parallelized);
+        conditional_permute_blocks_test(param, executor);
+    }
+
+    create_parameterized_test!(integer_conditional_permute_blocks);
 }
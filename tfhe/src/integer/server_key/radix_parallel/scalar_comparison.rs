@@ -29,6 +29,33 @@ impl ServerKey {
         T: IntegerRadixCiphertext,
         Scalar: DecomposableInto<u64>,
     {
+        self.is_scalar_out_of_bounds_for_num_blocks::<T, Scalar>(ct.blocks().len(), scalar)
+    }
+
+    /// Returns whether the clear scalar is outside of the
+    /// value range a ciphertext of type `T` with `num_blocks` blocks can hold.
+    ///
+    /// See [Self::is_scalar_out_of_bounds]
+    pub(crate) fn is_scalar_out_of_bounds_for_num_blocks<T, Scalar>(
+        &self,
+        num_blocks: usize,
+        scalar: Scalar,
+    ) -> std::cmp::Ordering
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: DecomposableInto<u64>,
+    {
+        if num_blocks == 0 {
+            // A ciphertext without blocks can only represent 0
+            return if scalar < Scalar::ZERO {
+                std::cmp::Ordering::Less
+            } else if scalar > Scalar::ZERO {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            };
+        }
+
         let scalar_blocks =
             BlockDecomposer::with_early_stop_at_zero(scalar, self.key.message_modulus.0.ilog2())
                 .iter_as::<u64>()
@@ -37,12 +64,12 @@ impl ServerKey {
         if T::IS_SIGNED {
             let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
             let sign_bit_is_set = scalar_blocks
-                .get(ct.blocks().len() - 1)
+                .get(num_blocks - 1)
                 .is_some_and(|block| (block >> sign_bit_pos) == 1);
 
             if scalar > Scalar::ZERO
-                && (scalar_blocks.len() > ct.blocks().len()
-                    || (scalar_blocks.len() == ct.blocks().len() && sign_bit_is_set))
+                && (scalar_blocks.len() > num_blocks
+                    || (scalar_blocks.len() == num_blocks && sign_bit_is_set))
             {
                 // If scalar is positive and that any bits above the ct's n-1 bits is set
                 // it means scalar is bigger.
@@ -56,19 +83,19 @@ impl ServerKey {
                 // If scalar is negative, and that any bits above the ct's n-1 bits is not set
                 // it means scalar is smaller.
 
-                if ct.blocks().len() > scalar_blocks.len() {
+                if num_blocks > scalar_blocks.len() {
                     // Ciphertext has more blocks, the scalar may be in range
                     return std::cmp::Ordering::Equal;
                 }
 
                 // (returns false for empty iter)
-                let at_least_one_block_is_not_full_of_1s = scalar_blocks[ct.blocks().len()..]
+                let at_least_one_block_is_not_full_of_1s = scalar_blocks[num_blocks..]
                     .iter()
                     .any(|&scalar_block| scalar_block != (self.key.message_modulus.0 - 1));
 
                 let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
                 let sign_bit_is_unset = scalar_blocks
-                    .get(ct.blocks().len() - 1)
+                    .get(num_blocks - 1)
                     .is_some_and(|block| (block >> sign_bit_pos) == 0);
 
                 if at_least_one_block_is_not_full_of_1s || sign_bit_is_unset {
@@ -84,9 +111,8 @@ impl ServerKey {
             } else if scalar > Scalar::ZERO {
                 // scalar is obviously bigger if it has non-zero
                 // blocks  after lhs's last block
-                let is_scalar_obviously_bigger = scalar_blocks
-                    .get(ct.blocks().len()..)
-                    .is_some_and(|sub_slice| {
+                let is_scalar_obviously_bigger =
+                    scalar_blocks.get(num_blocks..).is_some_and(|sub_slice| {
                         sub_slice.iter().any(|&scalar_block| scalar_block != 0)
                     });
                 if is_scalar_obviously_bigger {
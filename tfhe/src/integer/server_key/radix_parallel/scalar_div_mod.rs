@@ -14,8 +14,9 @@ use crate::core_crypto::prelude::{CastFrom, CastInto, Numeric, SignedNumeric, Un
 use crate::integer::bigint::{StaticUnsignedBigInt, I1024, I2048, I4096, U1024, U2048, U4096};
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::ciphertext::{RadixCiphertext, SignedRadixCiphertext};
+use crate::integer::prelude::ServerKeyDefaultCMux;
 use crate::integer::server_key::radix::scalar_mul::ScalarMultiplier;
-use crate::integer::{IntegerCiphertext, ServerKey, I256, I512, U256, U512};
+use crate::integer::{BooleanBlock, IntegerCiphertext, ServerKey, I256, I512, U256, U512};
 use std::ops::{Add, AddAssign, BitAnd, Div, Mul, Neg, Shl, Shr, Sub};
 
 #[inline(always)]
@@ -1118,6 +1119,156 @@ impl ServerKey {
             self.unchecked_scalar_div_rem_parallelized(&cloned_numerator, divisor)
         }
     }
+
+    /// Computes homomorphically `numerator / divisor` if `condition` is true,
+    /// and returns `numerator` unchanged otherwise.
+    ///
+    /// The division truncates towards zero, like [Self::scalar_div_parallelized].
+    ///
+    /// # Note
+    ///
+    /// The division is always computed and then selected against the numerator,
+    /// so this pays the full cost of the scalar division regardless of the
+    /// condition's value, which keeps the execution time independent of it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if divisor is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 230u8;
+    /// let scalar = 12u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let condition = sks.create_trivial_boolean_block(true);
+    ///
+    /// let ct_res = sks.conditional_scalar_div_parallelized(&ct, scalar as u64, &condition);
+    ///
+    /// // Decrypt:
+    /// let decrypted: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg / scalar, decrypted);
+    /// ```
+    pub fn conditional_scalar_div_parallelized(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor: u64,
+        condition: &BooleanBlock,
+    ) -> RadixCiphertext {
+        let mut tmp_numerator;
+        let numerator = if numerator.block_carries_are_empty() {
+            numerator
+        } else {
+            tmp_numerator = numerator.clone();
+            self.full_propagate_parallelized(&mut tmp_numerator);
+            &tmp_numerator
+        };
+
+        let quotient = self.unchecked_scalar_div_parallelized(numerator, divisor);
+        self.if_then_else_parallelized(condition, &quotient, numerator)
+    }
+
+    /// Computes homomorphically `numerator / divisor`, rounded up if `round_up` is true,
+    /// and truncated otherwise.
+    ///
+    /// The rounding mode is selected under encryption: `divisor - 1` is added to the numerator
+    /// only when `round_up` is true (the clear constant is zeroed otherwise), then a single
+    /// scalar division is done. So the cost does not depend on the value of `round_up`.
+    ///
+    /// The addition is done on a numerator extended with enough blocks so that it cannot
+    /// overflow, the result always fits in the number of blocks of `numerator`.
+    ///
+    /// # Exact divisions
+    ///
+    /// When `divisor` divides `numerator`, both rounding modes give the same (exact) quotient.
+    ///
+    /// To ensure correct results, `round_up` must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Panics
+    ///
+    /// Panics if divisor is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2, size);
+    ///
+    /// let msg = 230u8;
+    /// let scalar = 12u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// for round_up in [false, true] {
+    ///     let ct_round_up = cks.encrypt_bool(round_up);
+    ///     let ct_res = sks.div_scalar_rounded_parallelized(&ct, scalar as u64, &ct_round_up);
+    ///
+    ///     // Decrypt:
+    ///     let decrypted: u8 = cks.decrypt(&ct_res);
+    ///     if round_up {
+    ///         assert_eq!(msg.div_ceil(scalar), decrypted);
+    ///     } else {
+    ///         assert_eq!(msg / scalar, decrypted);
+    ///     }
+    /// }
+    /// ```
+    pub fn div_scalar_rounded_parallelized(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor: u64,
+        round_up: &BooleanBlock,
+    ) -> RadixCiphertext {
+        assert_ne!(divisor, 0, "attempt to divide by 0");
+
+        let mut tmp_numerator;
+        let numerator = if numerator.block_carries_are_empty() {
+            numerator
+        } else {
+            tmp_numerator = numerator.clone();
+            self.full_propagate_parallelized(&mut tmp_numerator);
+            &tmp_numerator
+        };
+
+        // numerator + divisor - 1 needs one more bit than the biggest of the two operands
+        let bits_per_block = self.message_modulus().0.ilog2();
+        let num_blocks = numerator.blocks.len();
+        let numerator_bits = bits_per_block * num_blocks as u32;
+        let rounding_bits = u64::BITS - (divisor - 1).leading_zeros();
+        let extended_num_blocks =
+            (numerator_bits.max(rounding_bits) + 1).div_ceil(bits_per_block) as usize;
+        let num_extra_blocks = extended_num_blocks - num_blocks;
+        let mut extended =
+            self.extend_radix_with_trivial_zero_blocks_msb(numerator, num_extra_blocks);
+
+        // Conditional scalar add: the constant is zeroed when the rounding is truncation
+        let mut rounding: RadixCiphertext =
+            self.create_trivial_radix(divisor - 1, extended_num_blocks);
+        self.zero_out_if(&mut rounding, &round_up.0, |x| x == 0);
+        self.add_assign_parallelized(&mut extended, &rounding);
+
+        let mut quotient = if bits_per_block * extended_num_blocks as u32 <= u64::BITS {
+            self.unchecked_scalar_div_parallelized(&extended, divisor)
+        } else {
+            self.unchecked_scalar_div_parallelized(&extended, u128::from(divisor))
+        };
+
+        // The quotient is at most the numerator, the extra blocks are zeros
+        self.trim_radix_blocks_msb_assign(&mut quotient, num_extra_blocks);
+        quotient
+    }
 }
 
 #[cfg(test)]
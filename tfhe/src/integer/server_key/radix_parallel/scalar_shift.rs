@@ -1,9 +1,23 @@
 use crate::core_crypto::commons::utils::izip;
 use crate::core_crypto::prelude::CastFrom;
 use crate::integer::ciphertext::IntegerRadixCiphertext;
-use crate::integer::ServerKey;
+use crate::integer::prelude::ServerKeyDefaultCMux;
+use crate::integer::{BooleanBlock, ServerKey};
 use rayon::prelude::*;
 
+/// Direction of the shift of a stage of a barrel shifter
+///
+/// See [ServerKey::conditional_shift_stage_parallelized]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ShiftDir {
+    /// Shift to the left, the vacated low bits are filled with zeros
+    Left,
+    /// Shift to the right, the vacated high bits are filled with zeros
+    LogicalRight,
+    /// Shift to the right, the vacated high bits are filled with the most significant bit
+    ArithmeticRight,
+}
+
 impl ServerKey {
     //======================================================================
     //                Shift Right
@@ -722,4 +736,90 @@ impl ServerKey {
 
         self.unchecked_scalar_left_shift_assign_parallelized(ct, shift);
     }
+
+    /// Computes one stage of a barrel shifter
+    ///
+    /// Returns `ct` shifted by the clear `shift_amount` in the given `direction` if `shift_bit`
+    /// encrypts `true`, otherwise returns `ct` unchanged.
+    ///
+    /// The fill of the vacated bits is given by `direction`, see [ShiftDir]. As for the other
+    /// shifts, `shift_amount` is taken modulo the number of bits of `ct`.
+    ///
+    /// The returned ciphertext has clean blocks.
+    ///
+    /// # Composing stages
+    ///
+    /// To shift by an encrypted amount `s` of `k` bits `s_0, ..., s_(k-1)` (LSB first), chain
+    /// `k` stages, the stage `i` shifting by `2^i` when `s_i` is `true`. As shifting by `a` then
+    /// by `b` is the same as shifting by `a + b`, the result is `ct` shifted by `s`. With
+    /// `k = log2(number of bits of ct)`, every amount up to the width of `ct` can be reached.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::server_key::ShiftDir;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let msg = 13u8;
+    /// let shift = 5u32;
+    ///
+    /// // 8 bits, so 3 stages are enough to shift by any amount in [0, 8[
+    /// let shift_bits = (0..3)
+    ///     .map(|i| cks.encrypt_bool((shift >> i) & 1 == 1))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let mut ct = cks.encrypt(msg);
+    /// for (i, shift_bit) in shift_bits.iter().enumerate() {
+    ///     ct = sks.conditional_shift_stage_parallelized(&ct, 1 << i, shift_bit, ShiftDir::Left);
+    /// }
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct);
+    /// assert_eq!(dec, msg << shift);
+    /// ```
+    pub fn conditional_shift_stage_parallelized<T>(
+        &self,
+        ct: &T,
+        shift_amount: u32,
+        shift_bit: &BooleanBlock,
+        direction: ShiftDir,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        let mut shifted = ct.clone();
+        match direction {
+            ShiftDir::Left => {
+                self.unchecked_scalar_left_shift_assign_parallelized(&mut shifted, shift_amount);
+            }
+            ShiftDir::LogicalRight => {
+                self.unchecked_scalar_right_shift_logical_assign_parallelized(
+                    &mut shifted,
+                    shift_amount,
+                );
+            }
+            ShiftDir::ArithmeticRight => {
+                self.unchecked_scalar_right_shift_arithmetic_assign_parallelized(
+                    &mut shifted,
+                    shift_amount,
+                );
+            }
+        }
+
+        self.if_then_else_parallelized(shift_bit, &shifted, ct)
+    }
 }
@@ -0,0 +1,146 @@
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+/// Records a chain of conditional assignments, to compute them all at once
+///
+/// Created with [ServerKey::select_builder].
+///
+/// Replaying the chain `value = if cond_i { value_i } else { value }` with one
+/// [ServerKey::if_then_else_parallelized] per assignment requires to clean all the blocks of the
+/// intermediate result at each step. Instead, the builder computes, for each recorded value, a
+/// condition telling if it is the one that ends up being assigned, masks the values with these
+/// conditions in parallel, and then sums the masked values. As at most one of the masked values is
+/// non-zero, the blocks are only cleaned when the noise budget of the sum is exhausted.
+pub struct SelectBuilder<'a, T> {
+    server_key: &'a ServerKey,
+    initial_value: &'a T,
+    assignments: Vec<(&'a BooleanBlock, &'a T)>,
+}
+
+impl<'a, T> SelectBuilder<'a, T>
+where
+    T: IntegerRadixCiphertext,
+{
+    /// Records the assignment of `value` if `condition` is true
+    ///
+    /// Assignments recorded later take precedence over the ones recorded before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` does not have the same number of blocks as the initial value
+    pub fn assign_if(mut self, condition: &'a BooleanBlock, value: &'a T) -> Self {
+        assert_eq!(
+            value.blocks().len(),
+            self.initial_value.blocks().len(),
+            "All values of a SelectBuilder must have the same number of blocks"
+        );
+        self.assignments.push((condition, value));
+        self
+    }
+
+    /// Computes the result of the recorded chain of assignments
+    ///
+    /// The returned ciphertext has clean blocks
+    pub fn build(self) -> T {
+        let sks = self.server_key;
+
+        if self.assignments.is_empty() {
+            let mut result = self.initial_value.clone();
+            if !result.block_carries_are_empty() {
+                sks.full_propagate_parallelized(&mut result);
+            }
+            return result;
+        }
+
+        // Going from the last assignment to the first one,
+        // a value is selected if its condition is true and no later condition was true
+        let mut selectors = Vec::with_capacity(self.assignments.len() + 1);
+        let mut any_later_is_true: Option<BooleanBlock> = None;
+        for (condition, _) in self.assignments.iter().rev() {
+            let (selector, any_is_true) = any_later_is_true.as_ref().map_or_else(
+                || ((*condition).clone(), (*condition).clone()),
+                |any_later_is_true| {
+                    rayon::join(
+                        || {
+                            let none_later_is_true = sks.boolean_bitnot(any_later_is_true);
+                            sks.boolean_bitand(condition, &none_later_is_true)
+                        },
+                        || sks.boolean_bitor(condition, any_later_is_true),
+                    )
+                },
+            );
+            selectors.push(selector);
+            any_later_is_true = Some(any_is_true);
+        }
+        // The initial value is kept only if no condition is true
+        selectors.push(sks.boolean_bitnot(&any_later_is_true.unwrap()));
+        selectors.reverse();
+
+        let values = std::iter::once(self.initial_value)
+            .chain(self.assignments.iter().map(|(_, value)| *value))
+            .collect::<Vec<_>>();
+
+        let masked_values = values
+            .into_par_iter()
+            .zip(selectors.par_iter())
+            .map(|(value, selector)| sks.mask_with_selector(value, selector))
+            .collect::<Vec<_>>();
+
+        sks.sum_one_hot_vector(masked_values)
+    }
+}
+
+impl ServerKey {
+    /// Creates a [SelectBuilder], to compute a chain of conditional assignments starting from
+    /// `initial_value`
+    ///
+    /// The assignments are recorded with [SelectBuilder::assign_if], and computed when calling
+    /// [SelectBuilder::build]. The result is the same as doing
+    /// `value = sks.if_then_else_parallelized(&condition, &new_value, &value)` for each recorded
+    /// assignment in order, but with less carry cleaning.
+    ///
+    /// To ensure correct results, conditions must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clear_x = 27u8;
+    /// let x = cks.encrypt(clear_x);
+    ///
+    /// // A 4 level mux, classifying x
+    /// let thresholds = [10u8, 20, 30, 40];
+    /// let conditions = thresholds.map(|t| sks.scalar_ge_parallelized(&x, t));
+    /// let values = [1u8, 2, 3, 4].map(|v| cks.encrypt(v));
+    /// let initial_value = cks.encrypt(0u8);
+    ///
+    /// let result = sks
+    ///     .select_builder(&initial_value)
+    ///     .assign_if(&conditions[0], &values[0])
+    ///     .assign_if(&conditions[1], &values[1])
+    ///     .assign_if(&conditions[2], &values[2])
+    ///     .assign_if(&conditions[3], &values[3])
+    ///     .build();
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&result);
+    /// assert_eq!(dec, 2);
+    /// ```
+    pub fn select_builder<'a, T>(&'a self, initial_value: &'a T) -> SelectBuilder<'a, T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        SelectBuilder {
+            server_key: self,
+            initial_value,
+            assignments: Vec::new(),
+        }
+    }
+}
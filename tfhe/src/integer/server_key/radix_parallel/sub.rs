@@ -793,4 +793,168 @@ impl ServerKey {
 
         self.unchecked_signed_overflowing_sub_parallelized_with_choice(lhs, rhs, algorithm)
     }
+
+    /// Subtracts the clear `p` from `ct` in place, if and only if `ct >= p`
+    ///
+    /// This is the final correction step of many modular reduction algorithms (e.g. after a
+    /// modular addition, or at the end of a Montgomery or Barrett reduction): the result is
+    /// `ct mod p` as long as `ct < 2 * p`.
+    ///
+    /// `ct` is compared to `p`, and the resulting boolean masks `p` before it is subtracted, so
+    /// that the subtraction is always done.
+    ///
+    /// The result has clean blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let p = 97u64;
+    /// let mut ct = cks.encrypt(150u64);
+    ///
+    /// sks.conditional_subtract_if_ge_scalar_parallelized(&mut ct, p);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct);
+    /// assert_eq!(dec, 150 - p);
+    /// ```
+    pub fn conditional_subtract_if_ge_scalar_parallelized(&self, ct: &mut RadixCiphertext, p: u64) {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        if self.num_blocks_to_represent_unsigned_value(p) > ct.blocks.len() {
+            // ct < p is always true, there is nothing to subtract
+            return;
+        }
+
+        let is_ge = self.unchecked_scalar_ge_parallelized(ct, p);
+
+        let mut masked_p: RadixCiphertext = self.create_trivial_radix(p, ct.blocks.len());
+        self.zero_out_if_condition_is_false(&mut masked_p, &is_ge.0);
+
+        self.sub_assign_parallelized(ct, &masked_p);
+    }
+
+    /// Subtracts `p` from `ct` in place, if and only if `ct >= p`
+    ///
+    /// This is the same correction as
+    /// [Self::conditional_subtract_if_ge_scalar_parallelized], for an encrypted `p`. The result
+    /// is `ct mod p` as long as `ct < 2 * p`.
+    ///
+    /// The result has clean blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ct` and `p` do not have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let mut ct = cks.encrypt(60u64);
+    /// let p = cks.encrypt(97u64);
+    ///
+    /// sks.conditional_subtract_if_ge_parallelized(&mut ct, &p);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct);
+    /// assert_eq!(dec, 60);
+    /// ```
+    pub fn conditional_subtract_if_ge_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        p: &RadixCiphertext,
+    ) {
+        assert_eq!(
+            ct.blocks.len(),
+            p.blocks.len(),
+            "ct and p must have the same number of blocks"
+        );
+
+        let mut masked_p = p.clone();
+        rayon::join(
+            || {
+                if !ct.block_carries_are_empty() {
+                    self.full_propagate_parallelized(ct);
+                }
+            },
+            || {
+                if !masked_p.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut masked_p);
+                }
+            },
+        );
+
+        let is_ge = self.unchecked_ge_parallelized(ct, &masked_p);
+        self.zero_out_if_condition_is_false(&mut masked_p, &is_ge.0);
+
+        self.sub_assign_parallelized(ct, &masked_p);
+    }
+
+    /// Decrements `ct` by one if `condition` is true and `ct` is not 0
+    ///
+    /// - If condition == 1 and ct > 0, ct is replaced by ct - 1
+    /// - Otherwise (condition == 0, or ct == 0), ct keeps its value
+    ///
+    /// This is the step of a counter clamped at 0 (e.g. a token bucket): decrementing an empty
+    /// counter leaves it at 0 instead of wrapping around.
+    ///
+    /// The condition and the result of the check `ct != 0` are combined into a single boolean,
+    /// which is then subtracted from `ct`.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// The result has clean blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let mut ct = cks.encrypt(1u64);
+    /// let condition = cks.encrypt_bool(true);
+    ///
+    /// sks.saturating_conditional_decrement_parallelized(&mut ct, &condition);
+    /// let dec: u64 = cks.decrypt(&ct);
+    /// assert_eq!(dec, 0);
+    ///
+    /// // The counter stays at 0
+    /// sks.saturating_conditional_decrement_parallelized(&mut ct, &condition);
+    /// let dec: u64 = cks.decrypt(&ct);
+    /// assert_eq!(dec, 0);
+    /// ```
+    pub fn saturating_conditional_decrement_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        condition: &BooleanBlock,
+    ) {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        let is_non_zero = self.unchecked_scalar_ne_parallelized(ct, 0u64);
+        let should_decrement = self.boolean_bitand(&is_non_zero, condition);
+
+        let decrement: RadixCiphertext = should_decrement.into_radix(ct.blocks.len(), self);
+        self.sub_assign_parallelized(ct, &decrement);
+    }
 }
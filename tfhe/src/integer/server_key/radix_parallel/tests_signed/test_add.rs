@@ -46,6 +46,7 @@ create_parameterized_test!(integer_signed_smart_add);
 create_parameterized_test!(integer_signed_default_add);
 create_parameterized_test!(integer_extensive_trivial_signed_default_add);
 create_parameterized_test!(integer_signed_default_overflowing_add);
+create_parameterized_test!(integer_signed_default_add_then_select_on_overflow);
 create_parameterized_test!(integer_extensive_trivial_signed_overflowing_add);
 create_parameterized_test!(
     integer_extensive_trivial_signed_advanced_overflowing_add_assign_with_carry_sequential
@@ -104,6 +105,14 @@ where
     signed_default_overflowing_add_test(param, executor);
 }
 
+fn integer_signed_default_add_then_select_on_overflow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::add_then_select_on_overflow_parallelized);
+    signed_default_add_then_select_on_overflow_test(param, executor);
+}
+
 fn integer_extensive_trivial_signed_overflowing_add<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -689,3 +698,58 @@ where
         }
     }
 }
+
+pub(crate) fn signed_default_add_then_select_on_overflow_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a SignedRadixCiphertext,
+            &'a SignedRadixCiphertext,
+            &'a SignedRadixCiphertext,
+            &'a SignedRadixCiphertext,
+        ),
+        SignedRadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for _ in 0..nb_tests_smaller {
+        let clear_a = rng.gen_range(-modulus..modulus);
+        let clear_b = rng.gen_range(-modulus..modulus);
+        let clear_on_overflow = rng.gen_range(-modulus..modulus);
+        let clear_on_ok = rng.gen_range(-modulus..modulus);
+
+        let ctxt_a = cks.encrypt_signed(clear_a);
+        let ctxt_b = cks.encrypt_signed(clear_b);
+        let ctxt_on_overflow = cks.encrypt_signed(clear_on_overflow);
+        let ctxt_on_ok = cks.encrypt_signed(clear_on_ok);
+
+        let ct_res = executor.execute((&ctxt_a, &ctxt_b, &ctxt_on_overflow, &ctxt_on_ok));
+        assert!(ct_res.block_carries_are_empty());
+
+        let (sum, overflowed) = signed_overflowing_add_under_modulus(clear_a, clear_b, modulus);
+        let fixup = if overflowed {
+            clear_on_overflow
+        } else {
+            clear_on_ok
+        };
+        let expected = signed_add_under_modulus(sum, fixup, modulus);
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(
+            dec, expected,
+            "Invalid result for {clear_a} + {clear_b}, on_overflow: {clear_on_overflow}, \
+            on_ok: {clear_on_ok}"
+        );
+    }
+}
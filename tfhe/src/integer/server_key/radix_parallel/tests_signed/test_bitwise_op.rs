@@ -8,7 +8,9 @@ use crate::integer::server_key::radix_parallel::tests_unsigned::{
     nb_tests_for_params, nb_unchecked_tests_for_params, CpuFunctionExecutor,
 };
 use crate::integer::tests::create_parameterized_test;
-use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey, SignedRadixCiphertext};
+use crate::integer::{
+    BooleanBlock, IntegerKeyKind, RadixClientKey, ServerKey, SignedRadixCiphertext,
+};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::test_params::*;
@@ -23,6 +25,7 @@ create_parameterized_test!(integer_signed_default_bitnot);
 create_parameterized_test!(integer_signed_default_bitand);
 create_parameterized_test!(integer_signed_default_bitor);
 create_parameterized_test!(integer_signed_default_bitxor);
+create_parameterized_test!(integer_signed_default_conditional_bitnot);
 
 fn integer_signed_unchecked_bitand<P>(param: P)
 where
@@ -79,6 +82,14 @@ where
     let executor = CpuFunctionExecutor::new(&ServerKey::bitxor_parallelized);
     signed_default_bitxor_test(param, executor);
 }
+fn integer_signed_default_conditional_bitnot<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_bitnot_parallelized);
+    signed_default_conditional_bitnot_test(param, executor);
+}
+
 pub(crate) fn signed_unchecked_bitand_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
@@ -381,3 +392,40 @@ where
         assert_eq!(dec_res, expected_result);
     }
 }
+
+pub(crate) fn signed_default_conditional_bitnot_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a SignedRadixCiphertext, &'a BooleanBlock),
+        SignedRadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<i64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_condition));
+        assert!(ct_res.block_carries_are_empty());
+
+        // The bit pattern is kept, so the sign is flipped by the bitnot
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        let clear_res = if clear_condition { !clear_0 } else { clear_0 };
+        assert_eq!(clear_res, dec_res);
+    }
+}
@@ -19,6 +19,8 @@ use std::sync::Arc;
 create_parameterized_test!(integer_signed_unchecked_if_then_else);
 create_parameterized_test!(integer_signed_default_if_then_else);
 create_parameterized_test!(integer_signed_default_scalar_if_then_else);
+create_parameterized_test!(integer_signed_default_try_scalar_if_then_else);
+create_parameterized_test!(integer_signed_default_saturating_cast);
 
 fn integer_signed_unchecked_if_then_else<P>(param: P)
 where
@@ -52,6 +54,25 @@ where
     signed_default_scalar_if_then_else_test(param, executor);
 }
 
+fn integer_signed_default_try_scalar_if_then_else<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey, cond: &BooleanBlock, lhs: i64, rhs: i64, n_blocks: usize| {
+        sks.try_scalar_if_then_else_parallelized(cond, lhs, rhs, n_blocks)
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    signed_default_try_scalar_if_then_else_test(param, executor);
+}
+
+fn integer_signed_default_saturating_cast<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::saturating_cast_parallelized);
+    signed_default_saturating_cast_test(param, executor);
+}
+
 pub(crate) fn signed_default_if_then_else_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
@@ -246,3 +267,111 @@ where
         assert_eq!(dec_res, if clear_condition { clear_0 } else { clear_1 });
     }
 }
+
+pub(crate) fn signed_default_try_scalar_if_then_else_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a BooleanBlock, i64, i64, usize),
+        crate::Result<SignedRadixCiphertext>,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as i64 / 2;
+
+    executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen_range(-modulus..modulus);
+        let clear_1 = rng.gen_range(-modulus..modulus);
+        let clear_condition = rng.gen_bool(0.5);
+
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        let ct_res = executor
+            .execute((&ctxt_condition, clear_0, clear_1, NB_CTXT))
+            .unwrap();
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(dec_res, if clear_condition { clear_0 } else { clear_1 });
+
+        // Values that do not fit in NB_CTXT blocks
+        let too_large = rng.gen_range(modulus..=i64::MAX);
+        let too_small = rng.gen_range(i64::MIN..-modulus);
+        for invalid in [too_large, too_small] {
+            assert!(executor
+                .execute((&ctxt_condition, invalid, clear_1, NB_CTXT))
+                .is_err());
+            assert!(executor
+                .execute((&ctxt_condition, clear_0, invalid, NB_CTXT))
+                .is_err());
+        }
+    }
+}
+
+pub(crate) fn signed_default_saturating_cast_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a SignedRadixCiphertext, usize), SignedRadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = cks.parameters().message_modulus().0 as i64;
+    // message_modulus^vec_length
+    let modulus = message_modulus.pow(NB_CTXT as u32) / 2;
+
+    executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests {
+        for target_blocks in [1, NB_CTXT / 2, NB_CTXT, NB_CTXT + 1] {
+            let target_modulus = message_modulus.pow(target_blocks as u32) / 2;
+            let (target_min, target_max) = (-target_modulus, target_modulus - 1);
+
+            // Values at the boundaries of the target type, and a random one
+            let clears = [
+                0,
+                -1,
+                target_max,
+                target_max + 1,
+                target_min,
+                target_min - 1,
+                modulus - 1,
+                -modulus,
+                rng.gen::<i64>() % modulus,
+            ];
+            for clear in clears
+                .into_iter()
+                .filter(|clear| (-modulus..modulus).contains(clear))
+            {
+                let ctxt = cks.encrypt_signed(clear);
+
+                let ct_res = executor.execute((&ctxt, target_blocks));
+                assert_eq!(ct_res.blocks.len(), target_blocks);
+                assert!(ct_res.block_carries_are_empty());
+
+                let dec_res: i64 = cks.decrypt_signed(&ct_res);
+                assert_eq!(
+                    dec_res,
+                    clear.clamp(target_min, target_max),
+                    "Invalid saturating cast of {clear} to {target_blocks} blocks"
+                );
+            }
+        }
+    }
+}
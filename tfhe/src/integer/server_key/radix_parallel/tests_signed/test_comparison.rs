@@ -3,9 +3,11 @@ use crate::integer::block_decomposition::{DecomposableInto, RecomposableSignedIn
 use crate::integer::ciphertext::SignedRadixCiphertext;
 use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
-use crate::integer::server_key::radix_parallel::tests_unsigned::{CpuFunctionExecutor, NB_CTXT};
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor, NB_CTXT,
+};
 use crate::integer::tests::create_parameterized_test;
-use crate::integer::{BooleanBlock, IntegerKeyKind, RadixClientKey, ServerKey};
+use crate::integer::{BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::test_params::*;
@@ -976,3 +978,70 @@ pub(crate) fn extensive_trivial_signed_default_comparisons_test<P, E1, E2, E3, E
         }
     }
 }
+
+create_parameterized_test!(integer_signed_default_max_and_absdiff);
+
+fn integer_signed_default_max_and_absdiff<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey, lhs: &SignedRadixCiphertext, rhs: &SignedRadixCiphertext| {
+        sks.max_and_absdiff_parallelized(lhs, rhs)
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    signed_default_max_and_absdiff_test(param, executor);
+}
+
+pub(crate) fn signed_default_max_and_absdiff_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a SignedRadixCiphertext, &'a SignedRadixCiphertext),
+        (SignedRadixCiphertext, SignedRadixCiphertext),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    // The extreme values make the difference overflow the signed range,
+    // so they are always tested
+    let mut pairs = vec![(modulus - 1, -modulus), (-modulus, modulus - 1)];
+    pairs.extend((0..nb_tests).map(|_| {
+        (
+            rng.gen_range(-modulus..modulus),
+            rng.gen_range(-modulus..modulus),
+        )
+    }));
+
+    for (clear_a, clear_b) in pairs {
+        let ct_a = cks.encrypt_signed(clear_a);
+        let ct_b = cks.encrypt_signed(clear_b);
+
+        let (ct_max, ct_diff) = executor.execute((&ct_a, &ct_b));
+
+        let dec_max: i64 = cks.decrypt_signed(&ct_max);
+        assert_eq!(
+            dec_max,
+            clear_a.max(clear_b),
+            "Invalid max of {clear_a} and {clear_b}"
+        );
+
+        // The difference is only meaningful when read as unsigned
+        let ct_diff = RadixCiphertext::from(ct_diff.blocks);
+        let dec_diff: u64 = cks.decrypt(&ct_diff);
+        assert_eq!(
+            dec_diff,
+            clear_a.abs_diff(clear_b),
+            "Invalid absolute difference of {clear_a} and {clear_b}"
+        );
+    }
+}
@@ -7,7 +7,9 @@ use crate::integer::server_key::radix_parallel::tests_unsigned::{
     nb_tests_smaller_for_params, nb_unchecked_tests_for_params, CpuFunctionExecutor,
 };
 use crate::integer::tests::create_parameterized_test;
-use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey, SignedRadixCiphertext};
+use crate::integer::{
+    BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey, SignedRadixCiphertext,
+};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::test_params::*;
@@ -18,6 +20,8 @@ use std::sync::Arc;
 create_parameterized_test!(integer_signed_unchecked_neg);
 create_parameterized_test!(integer_signed_smart_neg);
 create_parameterized_test!(integer_signed_default_neg);
+create_parameterized_test!(integer_signed_default_apply_sign);
+create_parameterized_test!(integer_signed_default_conditional_conjugate);
 
 fn integer_signed_unchecked_neg<P>(param: P)
 where
@@ -43,6 +47,22 @@ where
     signed_default_neg_test(param, executor);
 }
 
+fn integer_signed_default_apply_sign<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::apply_sign_parallelized);
+    signed_default_apply_sign_test(param, executor);
+}
+
+fn integer_signed_default_conditional_conjugate<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_conjugate_parallelized);
+    signed_default_conditional_conjugate_test(param, executor);
+}
+
 pub(crate) fn signed_unchecked_neg_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
@@ -186,3 +206,128 @@ where
         assert_eq!(clear_result, dec);
     }
 }
+
+pub(crate) fn signed_default_apply_sign_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a BooleanBlock), SignedRadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    executor.setup(&cks, sks.clone());
+
+    // Wrapping of the signed value, as if computed in two's complement
+    let wrap = |value: i64| (value + modulus).rem_euclid(2 * modulus) - modulus;
+
+    // The largest representable magnitudes, and the ones that overflow
+    let edge_cases = [
+        (modulus - 1, false),
+        (modulus, true),
+        (modulus, false),
+        (2 * modulus - 1, true),
+        (0, true),
+    ];
+    let random_cases = (0..nb_tests_smaller)
+        .map(|_| (rng.gen_range(0..2 * modulus), rng.gen_bool(0.5)))
+        .collect::<Vec<_>>();
+
+    for (clear_magnitude, clear_sign) in edge_cases.into_iter().chain(random_cases) {
+        let mut magnitude = cks.encrypt(clear_magnitude as u64);
+        let sign = cks.encrypt_bool(clear_sign);
+
+        let expected = if clear_sign {
+            wrap(-clear_magnitude)
+        } else {
+            wrap(clear_magnitude)
+        };
+
+        let ct_res = executor.execute((&magnitude, &sign));
+        assert!(ct_res.block_carries_are_empty());
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(
+            dec, expected,
+            "Invalid apply_sign result for magnitude {clear_magnitude} and sign {clear_sign}"
+        );
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen_range(0..2 * modulus);
+        sks.unchecked_scalar_add_assign(&mut magnitude, clear_2 as u64);
+        let clear_magnitude = (clear_magnitude + clear_2) % (2 * modulus);
+
+        let expected = if clear_sign {
+            wrap(-clear_magnitude)
+        } else {
+            wrap(clear_magnitude)
+        };
+
+        let ct_res = executor.execute((&magnitude, &sign));
+        assert!(ct_res.block_carries_are_empty());
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(
+            dec, expected,
+            "Invalid apply_sign result for magnitude {clear_magnitude} and sign {clear_sign}"
+        );
+    }
+}
+
+pub(crate) fn signed_default_conditional_conjugate_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a SignedRadixCiphertext,
+            &'a mut SignedRadixCiphertext,
+            &'a BooleanBlock,
+        ),
+        (),
+    >,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    executor.setup(&cks, sks);
+
+    // The minimum value, whose negation overflows
+    let edge_cases = [(-modulus, true), (-modulus, false), (0, true)];
+    let random_cases = (0..nb_tests_smaller)
+        .map(|_| (rng.gen_range(-modulus..modulus), rng.gen_bool(0.5)))
+        .collect::<Vec<_>>();
+
+    for (clear_im, clear_condition) in edge_cases.into_iter().chain(random_cases) {
+        let clear_re = rng.gen_range(-modulus..modulus);
+        let re = cks.encrypt_signed(clear_re);
+        let mut im = cks.encrypt_signed(clear_im);
+        let condition = cks.encrypt_bool(clear_condition);
+
+        executor.execute((&re, &mut im, &condition));
+        assert!(im.block_carries_are_empty());
+
+        let expected_im = if clear_condition {
+            signed_neg_under_modulus(clear_im, modulus)
+        } else {
+            clear_im
+        };
+        let dec_re: i64 = cks.decrypt_signed(&re);
+        let dec_im: i64 = cks.decrypt_signed(&im);
+        assert_eq!(
+            (dec_re, dec_im),
+            (clear_re, expected_im),
+            "Invalid conjugate of {clear_re} + i * {clear_im}, condition: {clear_condition}"
+        );
+    }
+}
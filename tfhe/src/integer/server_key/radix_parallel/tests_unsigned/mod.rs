@@ -7,6 +7,7 @@ pub(crate) mod test_cmux;
 pub(crate) mod test_comparison;
 mod test_count_zeros_ones;
 pub(crate) mod test_div_mod;
+pub(crate) mod test_gray_code;
 pub(crate) mod test_ilog2;
 pub(crate) mod test_mul;
 pub(crate) mod test_neg;
@@ -20,12 +21,14 @@ pub(crate) mod test_scalar_mul;
 pub(crate) mod test_scalar_rotate;
 pub(crate) mod test_scalar_shift;
 pub(crate) mod test_scalar_sub;
+pub(crate) mod test_select_builder;
 pub(crate) mod test_shift;
 pub(crate) mod test_slice;
 pub(crate) mod test_sub;
 pub(crate) mod test_sum;
 pub(crate) mod test_vector_comparisons;
 pub(crate) mod test_vector_find;
+pub(crate) mod test_vector_select;
 
 use super::tests_cases_unsigned::*;
 use crate::core_crypto::prelude::UnsignedInteger;
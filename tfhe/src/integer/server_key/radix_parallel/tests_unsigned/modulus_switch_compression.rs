@@ -9,6 +9,7 @@ use rand::Rng;
 const NB_TESTS: usize = 10;
 
 create_parameterized_test!(modulus_switch_compression);
+create_parameterized_test!(modulus_switch_compression_if_then_else);
 
 fn modulus_switch_compression<P>(param: P)
 where
@@ -35,3 +36,45 @@ where
         assert_eq!(clear, dec);
     }
 }
+
+fn modulus_switch_compression_if_then_else<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let mut rng = rand::thread_rng();
+
+    // Use an odd number of blocks too, to have a block that is not paired
+    for size in [3, 4] {
+        let (cks, sks) = gen_keys_radix(param, size);
+
+        let bound = sks.message_modulus().0.pow(size as u32);
+
+        for _ in 0..NB_TESTS {
+            let clear_true: u64 = rng.gen_range(0..bound);
+            let clear_false: u64 = rng.gen_range(0..bound);
+
+            let true_ct = sks.switch_modulus_and_compress_parallelized(&cks.encrypt(clear_true));
+            let false_ct = sks.switch_modulus_and_compress_parallelized(&cks.encrypt(clear_false));
+
+            for clear_condition in [false, true] {
+                let condition = sks
+                    .switch_modulus_and_compress_boolean_block(&cks.encrypt_bool(clear_condition));
+
+                let compressed_result =
+                    sks.if_then_else_compressed_e2e_parallelized(&condition, &true_ct, &false_ct);
+
+                let result = sks.decompress_parallelized(&compressed_result);
+                assert_eq!(result.blocks.len(), size);
+
+                let dec: u64 = cks.decrypt(&result);
+                let expected = if clear_condition {
+                    clear_true
+                } else {
+                    clear_false
+                };
+                assert_eq!(dec, expected);
+            }
+        }
+    }
+}
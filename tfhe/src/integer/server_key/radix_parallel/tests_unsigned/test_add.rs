@@ -23,6 +23,10 @@ create_parameterized_test!(integer_smart_add);
 create_parameterized_test!(integer_default_add);
 create_parameterized_test!(integer_extensive_trivial_default_add);
 create_parameterized_test!(integer_default_overflowing_add);
+create_parameterized_test!(integer_default_conditional_digit_carry);
+create_parameterized_test!(integer_default_add_mod_scalar);
+create_parameterized_test!(integer_default_add_then_select_on_overflow);
+create_parameterized_test!(integer_default_add_with_carry_in);
 create_parameterized_test!(integer_extensive_trivial_default_overflowing_add);
 create_parameterized_test!(integer_advanced_overflowing_add_assign_with_carry_at_least_4_bits {
     coverage => {
@@ -237,6 +241,38 @@ where
     default_overflowing_add_test(param, executor);
 }
 
+fn integer_default_conditional_digit_carry<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_digit_carry_parallelized);
+    default_conditional_digit_carry_test(param, executor);
+}
+
+fn integer_default_add_mod_scalar<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::add_mod_scalar_parallelized);
+    default_add_mod_scalar_test(param, executor);
+}
+
+fn integer_default_add_then_select_on_overflow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::add_then_select_on_overflow_parallelized);
+    default_add_then_select_on_overflow_test(param, executor);
+}
+
+fn integer_default_add_with_carry_in<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::add_with_carry_in_parallelized);
+    default_add_with_carry_in_test(param, executor);
+}
+
 fn integer_extensive_trivial_default_overflowing_add<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -730,3 +766,225 @@ where
         }
     }
 }
+
+pub(crate) fn default_conditional_digit_carry_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a mut RadixCiphertext, &'a BooleanBlock, u64), BooleanBlock>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = cks.parameters().message_modulus().0;
+
+    for _ in 0..nb_tests {
+        let base = rng.gen_range(1..=message_modulus);
+        let clear_digit = rng.gen_range(0..base);
+        let clear_carry_in = rng.gen_bool(0.5);
+
+        let mut digit: RadixCiphertext = cks.as_ref().encrypt_radix(clear_digit, 1);
+        let carry_in = cks.encrypt_bool(clear_carry_in);
+
+        let carry_out = executor.execute((&mut digit, &carry_in, base));
+        assert!(digit.block_carries_are_empty());
+        assert!(carry_out.0.degree.get() <= 1);
+
+        let sum = clear_digit + u64::from(clear_carry_in);
+        let dec_digit: u64 = cks.decrypt(&digit);
+        let dec_carry_out = cks.decrypt_bool(&carry_out);
+        assert_eq!(
+            (dec_digit, dec_carry_out),
+            (sum % base, sum >= base),
+            "Invalid result for {clear_digit} + {clear_carry_in} in base {base}"
+        );
+    }
+}
+
+pub(crate) fn default_add_mod_scalar_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext, u64), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    // The largest modulus needs an extra block for the sum, the smallest one does not
+    for p in [modulus, modulus - 1, modulus / 2 + 1, modulus / 2, 3] {
+        for _ in 0..nb_tests {
+            let clear_a = rng.gen_range(0..p);
+            let clear_b = rng.gen_range(0..p);
+
+            let mut ctxt_a = cks.encrypt(clear_a);
+            let ctxt_b = cks.encrypt(clear_b);
+
+            let ct_res = executor.execute((&ctxt_a, &ctxt_b, p));
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(ct_res.blocks.len(), NB_CTXT);
+
+            let dec: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec,
+                (clear_a + clear_b) % p,
+                "Invalid result for ({clear_a} + {clear_b}) mod {p}"
+            );
+
+            // Add to have non empty carries, while keeping a < p
+            let clear_2 = rng.gen_range(0..p - clear_a);
+            let ctxt_2 = cks.encrypt(clear_2);
+            sks.unchecked_add_assign(&mut ctxt_a, &ctxt_2);
+            let clear_a = clear_a + clear_2;
+
+            let ct_res = executor.execute((&ctxt_a, &ctxt_b, p));
+            assert!(ct_res.block_carries_are_empty());
+
+            let dec: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec,
+                (clear_a + clear_b) % p,
+                "Invalid result for ({clear_a} + {clear_b}) mod {p}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_add_then_select_on_overflow_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let clear_a = rng.gen::<u64>() % modulus;
+        // Half of the time, b is chosen so that the addition overflows
+        let clear_b = if rng.gen_bool(0.5) && clear_a != 0 {
+            rng.gen_range(modulus - clear_a..modulus)
+        } else {
+            rng.gen_range(0..modulus - clear_a)
+        };
+        let clear_on_overflow = rng.gen::<u64>() % modulus;
+        let clear_on_ok = rng.gen::<u64>() % modulus;
+
+        let mut ctxt_a = cks.encrypt(clear_a / 2);
+        // Non empty carries, so that the propagation is also tested
+        sks.unchecked_add_assign(&mut ctxt_a, &cks.encrypt(clear_a - clear_a / 2));
+        let ctxt_b = cks.encrypt(clear_b);
+        let ctxt_on_overflow = cks.encrypt(clear_on_overflow);
+        let ctxt_on_ok = cks.encrypt(clear_on_ok);
+
+        let ct_res = executor.execute((&ctxt_a, &ctxt_b, &ctxt_on_overflow, &ctxt_on_ok));
+        assert!(ct_res.block_carries_are_empty());
+
+        let (sum, overflowed) = overflowing_add_under_modulus(clear_a, clear_b, modulus);
+        let fixup = if overflowed {
+            clear_on_overflow
+        } else {
+            clear_on_ok
+        };
+        let expected = (sum + fixup) % modulus;
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec, expected,
+            "Invalid result for {clear_a} + {clear_b}, on_overflow: {clear_on_overflow}, \
+            on_ok: {clear_on_ok}"
+        );
+    }
+}
+
+pub(crate) fn default_add_with_carry_in_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, &'a RadixCiphertext, &'a BooleanBlock),
+        (RadixCiphertext, BooleanBlock),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let clear_a = rng.gen::<u64>() % modulus;
+        let clear_b = rng.gen::<u64>() % modulus;
+        let clear_carry_in = rng.gen_bool(0.5);
+
+        let mut ctxt_a = cks.encrypt(clear_a / 2);
+        // Non empty carries, so that the propagation is also tested
+        sks.unchecked_add_assign(&mut ctxt_a, &cks.encrypt(clear_a - clear_a / 2));
+        let ctxt_b = cks.encrypt(clear_b);
+        let ctxt_carry_in = cks.encrypt_bool(clear_carry_in);
+
+        let (ct_res, carry_out) = executor.execute((&ctxt_a, &ctxt_b, &ctxt_carry_in));
+        assert!(ct_res.block_carries_are_empty());
+
+        let exact_sum = clear_a + clear_b + u64::from(clear_carry_in);
+        let dec: u64 = cks.decrypt(&ct_res);
+        let dec_carry_out = cks.decrypt_bool(&carry_out);
+        assert_eq!(
+            (dec, dec_carry_out),
+            (exact_sum % modulus, exact_sum >= modulus),
+            "Invalid result for {clear_a} + {clear_b} + {clear_carry_in}"
+        );
+
+        // Chain a second limb, to add integers of 2 * NB_CTXT blocks
+        let clear_a_high = rng.gen::<u64>() % modulus;
+        let clear_b_high = rng.gen::<u64>() % modulus;
+        let ctxt_a_high = cks.encrypt(clear_a_high);
+        let ctxt_b_high = cks.encrypt(clear_b_high);
+
+        let (ct_res_high, carry_out) = executor.execute((&ctxt_a_high, &ctxt_b_high, &carry_out));
+        assert!(ct_res_high.block_carries_are_empty());
+
+        let exact_sum_high = clear_a_high + clear_b_high + u64::from(dec_carry_out);
+        let dec_high: u64 = cks.decrypt(&ct_res_high);
+        let dec_carry_out = cks.decrypt_bool(&carry_out);
+        assert_eq!(
+            (dec_high, dec_carry_out),
+            (exact_sum_high % modulus, exact_sum_high >= modulus),
+            "Invalid high limb for {clear_a_high} + {clear_b_high} with carry of the low limb"
+        );
+    }
+}
@@ -1,15 +1,20 @@
+use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_bitand_test, default_bitnot_test, default_bitor_test, default_bitxor_test,
     smart_bitand_test, smart_bitor_test, smart_bitxor_test, unchecked_bitand_test,
-    unchecked_bitnot_test, unchecked_bitor_test, unchecked_bitxor_test,
+    unchecked_bitnot_test, unchecked_bitor_test, unchecked_bitxor_test, FunctionExecutor,
+};
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_for_params, unsigned_modulus, CpuFunctionExecutor, NB_CTXT,
 };
-use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
 use crate::integer::tests::create_parameterized_test;
-use crate::integer::ServerKey;
+use crate::integer::{BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::test_params::*;
 use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
 
 create_parameterized_test!(integer_smart_bitand);
 create_parameterized_test!(integer_smart_bitor);
@@ -18,6 +23,8 @@ create_parameterized_test!(integer_default_bitand);
 create_parameterized_test!(integer_default_bitor);
 create_parameterized_test!(integer_default_bitnot);
 create_parameterized_test!(integer_default_bitxor);
+create_parameterized_test!(integer_default_conditional_bitnot);
+create_parameterized_test!(integer_default_conditional_xor);
 create_parameterized_test!(integer_unchecked_bitand);
 create_parameterized_test!(integer_unchecked_bitor);
 create_parameterized_test!(integer_unchecked_bitnot);
@@ -79,6 +86,22 @@ where
     default_bitnot_test(param, executor);
 }
 
+fn integer_default_conditional_bitnot<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_bitnot_parallelized);
+    default_conditional_bitnot_test(param, executor);
+}
+
+fn integer_default_conditional_xor<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_xor_parallelized);
+    default_conditional_xor_test(param, executor);
+}
+
 fn integer_unchecked_bitand<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -110,3 +133,145 @@ where
     let executor = CpuFunctionExecutor::new(&ServerKey::bitnot);
     unchecked_bitnot_test(param, executor);
 }
+
+pub(crate) fn default_conditional_bitnot_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a BooleanBlock), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_condition));
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = if clear_condition {
+            !clear_0 % modulus
+        } else {
+            clear_0
+        };
+        assert_eq!(dec_res, expected);
+
+        // Add to have non empty carries
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let ctxt_1 = cks.encrypt(clear_1);
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_1);
+        assert!(!ctxt_0.block_carries_are_empty());
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_condition));
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let clear_0 = (clear_0 + clear_1) % modulus;
+        let expected = if clear_condition {
+            !clear_0 % modulus
+        } else {
+            clear_0
+        };
+        assert_eq!(dec_res, expected);
+    }
+
+    // Trivial conditions
+    for clear_condition in [false, true] {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let ctxt_0 = cks.encrypt(clear_0);
+        let condition = sks.create_trivial_boolean_block(clear_condition);
+
+        let ct_res = executor.execute((&ctxt_0, &condition));
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = if clear_condition {
+            !clear_0 % modulus
+        } else {
+            clear_0
+        };
+        assert_eq!(dec_res, expected);
+    }
+}
+
+pub(crate) fn default_conditional_xor_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, &'a RadixCiphertext, &'a BooleanBlock),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let mut ctxt_1 = cks.encrypt(clear_1);
+
+        for clear_condition in [false, true] {
+            let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+            let ct_res = executor.execute((&ctxt_0, &ctxt_1, &ctxt_condition));
+            assert!(ct_res.block_carries_are_empty());
+
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            let expected = if clear_condition {
+                clear_0 ^ clear_1
+            } else {
+                clear_0
+            };
+            assert_eq!(dec_res, expected);
+        }
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let clear_3 = rng.gen::<u64>() % modulus;
+        let ctxt_2 = cks.encrypt(clear_2);
+        let ctxt_3 = cks.encrypt(clear_3);
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+        sks.unchecked_add_assign(&mut ctxt_1, &ctxt_3);
+        assert!(!ctxt_0.block_carries_are_empty());
+        assert!(!ctxt_1.block_carries_are_empty());
+        let clear_0 = (clear_0 + clear_2) % modulus;
+        let clear_1 = (clear_1 + clear_3) % modulus;
+
+        for clear_condition in [false, true] {
+            let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+            let ct_res = executor.execute((&ctxt_0, &ctxt_1, &ctxt_condition));
+            assert!(ct_res.block_carries_are_empty());
+
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            let expected = if clear_condition {
+                clear_0 ^ clear_1
+            } else {
+                clear_0
+            };
+            assert_eq!(dec_res, expected);
+        }
+    }
+}
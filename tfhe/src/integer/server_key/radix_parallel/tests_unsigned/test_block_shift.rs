@@ -17,6 +17,8 @@ create_parameterized_test!(integer_block_shift_right);
 
 create_parameterized_test!(integer_block_shift_left);
 
+create_parameterized_test!(integer_extract_field);
+
 fn integer_block_shift_right<P>(param: P)
 where
     P: Into<PBSParameters> + Copy,
@@ -33,6 +35,14 @@ where
     default_block_shift_left_test(param, executor);
 }
 
+fn integer_extract_field<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::extract_field_parallelized);
+    default_extract_field_test(param, executor);
+}
+
 pub(crate) fn default_block_shift_left_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
@@ -216,3 +226,82 @@ where
         }
     }
 }
+
+pub(crate) fn default_extract_field_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext, usize), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    sks.set_deterministic_pbs_execution(true);
+
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    executor.setup(&cks, sks.clone());
+
+    let cks: ClientKey = cks.into();
+    let message_modulus = cks.parameters().message_modulus().0;
+    let bits_per_blocks = message_modulus.ilog2();
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    let expected_field = |clear: u64, offset: u64, width: usize| {
+        if offset >= NB_CTXT as u64 {
+            0
+        } else {
+            (clear >> (offset as u32 * bits_per_blocks)) % message_modulus.pow(width as u32)
+        }
+    };
+
+    for width in [1, NB_CTXT.div_ceil(2), NB_CTXT, NB_CTXT + 1] {
+        for _ in 0..nb_tests {
+            let clear = rng.gen::<u64>() % modulus;
+            let ct = cks.encrypt_radix(clear, NB_CTXT);
+
+            // Offset on a single block, which may not be enough to address
+            // all the blocks of the padded input
+            {
+                let clear_offset = rng.gen::<u64>() % message_modulus;
+                let offset = cks.encrypt_radix(clear_offset, 1);
+                let encrypted_result = executor.execute((&ct, &offset, width));
+                assert_eq!(encrypted_result.blocks.len(), width);
+                assert!(
+                    encrypted_result.block_carries_are_empty(),
+                    "Expected all blocks to have no carries"
+                );
+                let decrypted_result: u64 = cks.decrypt_radix(&encrypted_result);
+                assert_eq!(
+                    decrypted_result,
+                    expected_field(clear, clear_offset, width),
+                    "Invalid extract_field result for {clear} at offset {clear_offset} \
+                    with width {width}"
+                );
+
+                let encrypted_result2 = executor.execute((&ct, &offset, width));
+                assert_eq!(
+                    encrypted_result, encrypted_result2,
+                    "Failed determinism check"
+                );
+            }
+
+            // Offset that may be past the end of the input
+            {
+                let clear_offset = rng.gen_range(0..modulus);
+                let offset = sks.create_trivial_radix(clear_offset, NB_CTXT);
+                let encrypted_result = executor.execute((&ct, &offset, width));
+                assert_eq!(encrypted_result.blocks.len(), width);
+                let decrypted_result: u64 = cks.decrypt_radix(&encrypted_result);
+                assert_eq!(
+                    decrypted_result,
+                    expected_field(clear, clear_offset, width),
+                    "Invalid extract_field result for {clear} at offset {clear_offset} \
+                    with width {width}"
+                );
+            }
+        }
+    }
+}
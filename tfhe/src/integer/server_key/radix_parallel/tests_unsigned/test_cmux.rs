@@ -2,7 +2,7 @@ use crate::integer::keycache::KEY_CACHE;
 use crate::integer::prelude::*;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{FunctionExecutor, NB_CTXT};
 use crate::integer::server_key::radix_parallel::tests_unsigned::{
-    nb_tests_for_params, CpuFunctionExecutor,
+    nb_tests_for_params, nb_tests_smaller_for_params, CpuFunctionExecutor,
 };
 use crate::integer::tests::create_parameterized_test;
 use crate::integer::{BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
@@ -16,7 +16,76 @@ use std::sync::Arc;
 create_parameterized_test!(integer_unchecked_left_scalar_if_then_else);
 create_parameterized_test!(integer_smart_if_then_else);
 create_parameterized_test!(integer_default_if_then_else);
+create_parameterized_test!(integer_default_if_then_else_reproducible);
+create_parameterized_test!(integer_default_lean_if_then_else);
+create_parameterized_test!(integer_default_blockwise_select);
 create_parameterized_test!(integer_default_scalar_if_then_else);
+create_parameterized_test!(integer_default_select_zero_pair_with_degrees);
+create_parameterized_test!(integer_default_try_scalar_if_then_else);
+create_parameterized_test!(integer_default_scalar_boolean_if_then_else);
+create_parameterized_test!(integer_default_conditional_move);
+create_parameterized_test!(integer_default_conditional_broadcast);
+create_parameterized_test!(integer_default_broadcast_condition_to_blocks);
+create_parameterized_test!(integer_default_apply_mask_matrix);
+create_parameterized_test!(integer_default_if_then_else_pair);
+create_parameterized_test!(integer_default_select_packed_bit);
+create_parameterized_test!(integer_default_conditional_unpack_field);
+create_parameterized_test!(integer_default_arithmetic_select_with_plan);
+create_parameterized_test!(integer_default_if_then_else_keep_loser);
+create_parameterized_test!(integer_default_partition);
+create_parameterized_test!(integer_default_conditional_swap);
+create_parameterized_test!(integer_default_select_struct);
+create_parameterized_test!(integer_default_right_scalar_if_then_else);
+create_parameterized_test!(integer_default_constant_shape_if_then_else);
+create_parameterized_test!(integer_default_programmable_select_with_plan);
+create_parameterized_test!(integer_default_programmable_if_then_else);
+create_parameterized_test!(integer_default_if_then_else_with_checksum);
+create_parameterized_test!(integer_default_if_then_else_with_branch_tag);
+create_parameterized_test!(integer_default_if_then_else_with_degree_report);
+create_parameterized_test!(integer_default_saturating_cast);
+create_parameterized_test!(integer_default_conditional_widen);
+create_parameterized_test!(integer_default_if_then_else_opt);
+create_parameterized_test!(integer_default_select_valid);
+create_parameterized_test!(integer_default_conditional_ema_step);
+create_parameterized_test!(integer_default_conditional_affine);
+create_parameterized_test!(integer_default_conditional_xor_scalar);
+create_parameterized_test!(integer_default_conditional_crc_step);
+create_parameterized_test!(integer_default_conditional_fma_scalar);
+create_parameterized_test!(integer_default_conditional_fma);
+create_parameterized_test!(integer_default_if_then_else_assign);
+create_parameterized_test!(integer_default_if_then_else_carry_save);
+create_parameterized_test!(integer_default_if_then_else_dontcare {
+    coverage => {
+        COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        COVERAGE_PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+    },
+    no_coverage => {
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        TEST_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+        // 2M128 is too slow for 4_4, it is estimated to be 2x slower
+        TEST_PARAM_MESSAGE_4_CARRY_4_KS_PBS_GAUSSIAN_2M64,
+        TEST_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+        TEST_PARAM_MULTI_BIT_GROUP_2_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64,
+        TEST_PARAM_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+        TEST_PARAM_MULTI_BIT_GROUP_3_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64
+    }
+});
+create_parameterized_test!(integer_default_if_then_else_heterogeneous {
+    coverage => {
+        COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        COVERAGE_PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+    },
+    no_coverage => {
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        TEST_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+        // 2M128 is too slow for 4_4, it is estimated to be 2x slower
+        TEST_PARAM_MESSAGE_4_CARRY_4_KS_PBS_GAUSSIAN_2M64,
+        TEST_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+        TEST_PARAM_MULTI_BIT_GROUP_2_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64,
+        TEST_PARAM_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+        TEST_PARAM_MULTI_BIT_GROUP_3_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64
+    }
+});
 
 fn integer_unchecked_left_scalar_if_then_else<P>(param: P)
 where
@@ -54,6 +123,43 @@ where
     default_if_then_else_test(param, executor);
 }
 
+fn integer_default_if_then_else_reproducible<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func =
+        |sks: &ServerKey, cond: &BooleanBlock, lhs: &RadixCiphertext, rhs: &RadixCiphertext| {
+            sks.if_then_else_parallelized(cond, lhs, rhs)
+        };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_if_then_else_reproducible_test(param, executor);
+}
+
+fn integer_default_lean_if_then_else<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func =
+        |sks: &ServerKey, cond: &BooleanBlock, lhs: &RadixCiphertext, rhs: &RadixCiphertext| {
+            sks.lean_if_then_else_parallelized(cond, lhs, rhs)
+        };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_lean_if_then_else_test(param, executor);
+}
+
+fn integer_default_blockwise_select<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func =
+        |sks: &ServerKey,
+         conditions: &[BooleanBlock],
+         lhs: &RadixCiphertext,
+         rhs: &RadixCiphertext| { sks.blockwise_select_parallelized(conditions, lhs, rhs) };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_blockwise_select_test(param, executor);
+}
+
 fn integer_default_scalar_if_then_else<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -65,6 +171,380 @@ where
     default_scalar_if_then_else_test(param, executor);
 }
 
+fn integer_default_select_zero_pair_with_degrees<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func =
+        |sks: &ServerKey, cond: &BooleanBlock, lhs: &RadixCiphertext, rhs: &RadixCiphertext| {
+            sks.select_zero_pair_with_degrees_parallelized(cond, lhs, rhs)
+        };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_select_zero_pair_with_degrees_test(param, executor);
+}
+
+fn integer_default_try_scalar_if_then_else<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey, cond: &BooleanBlock, lhs: u64, rhs: u64, num_blocks: usize| {
+        sks.try_scalar_if_then_else_parallelized(cond, lhs, rhs, num_blocks)
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_try_scalar_if_then_else_test(param, executor);
+}
+
+fn integer_default_scalar_boolean_if_then_else<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::scalar_boolean_if_then_else_parallelized);
+    default_scalar_boolean_if_then_else_test(param, executor);
+}
+
+fn integer_default_conditional_move<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_move_parallelized);
+    default_conditional_move_test(param, executor);
+}
+
+fn integer_default_conditional_broadcast<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_broadcast_parallelized);
+    default_conditional_broadcast_test(param, executor);
+}
+
+fn integer_default_broadcast_condition_to_blocks<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::broadcast_condition_to_blocks_parallelized);
+    default_broadcast_condition_to_blocks_test(param, executor);
+}
+
+fn integer_default_apply_mask_matrix<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::apply_mask_matrix_parallelized);
+    default_apply_mask_matrix_test(param, executor);
+}
+
+fn integer_default_if_then_else_pair<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::if_then_else_pair_parallelized);
+    default_if_then_else_pair_test(param, executor);
+}
+
+fn integer_default_select_packed_bit<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::select_packed_bit_parallelized);
+    default_select_packed_bit_test(param, executor);
+}
+
+fn integer_default_conditional_unpack_field<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_unpack_field_parallelized);
+    default_conditional_unpack_field_test(param, executor);
+}
+
+fn integer_default_arithmetic_select_with_plan<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey,
+                conditions: &[BooleanBlock],
+                lhs: &RadixCiphertext,
+                rhs: &RadixCiphertext| {
+        let plan = sks.prepare_arithmetic_select(lhs, rhs);
+        conditions
+            .iter()
+            .map(|condition| sks.arithmetic_select_with_plan(condition, &plan))
+            .collect::<Vec<_>>()
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_arithmetic_select_with_plan_test(param, executor);
+}
+
+fn integer_default_if_then_else_keep_loser<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::if_then_else_keep_loser_parallelized);
+    default_if_then_else_keep_loser_test(param, executor);
+}
+
+fn integer_default_partition<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    // Same semantic as if_then_else_keep_loser, so the same test is used
+    let executor = CpuFunctionExecutor::new(&ServerKey::partition_parallelized);
+    default_if_then_else_keep_loser_test(param, executor);
+}
+
+fn integer_default_conditional_swap<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::conditional_swap_parallelized::<RadixCiphertext>);
+    default_conditional_swap_test(param, executor);
+}
+
+fn integer_default_select_struct<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey,
+                dst: &mut Vec<RadixCiphertext>,
+                src: &[RadixCiphertext],
+                condition: &BooleanBlock| {
+        let mut dst_refs = dst.iter_mut().collect::<Vec<_>>();
+        let src_refs = src.iter().collect::<Vec<_>>();
+        sks.select_struct_parallelized(&mut dst_refs, &src_refs, condition);
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_select_struct_test(param, executor);
+}
+
+fn integer_default_right_scalar_if_then_else<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey, cond: &BooleanBlock, lhs: &RadixCiphertext, rhs: u64| {
+        sks.if_then_else_parallelized(cond, lhs, rhs)
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_right_scalar_if_then_else_test(param, executor);
+}
+
+fn integer_default_constant_shape_if_then_else<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::constant_shape_if_then_else_parallelized);
+    default_constant_shape_if_then_else_test(param, executor);
+}
+
+fn integer_default_programmable_select_with_plan<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey,
+                conditions: &[crate::shortint::Ciphertext],
+                lhs: &RadixCiphertext,
+                rhs: &RadixCiphertext| {
+        let plan = sks.prepare_programmable_select(|x| x % 2 == 1);
+        conditions
+            .iter()
+            .map(|condition| sks.programmable_select_with_plan(condition, lhs, rhs, &plan))
+            .collect::<Vec<_>>()
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_programmable_select_with_plan_test(param, executor);
+}
+
+fn integer_default_programmable_if_then_else<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey,
+                condition: &RadixCiphertext,
+                lhs: &RadixCiphertext,
+                rhs: &RadixCiphertext| {
+        sks.programmable_if_then_else_parallelized(condition, lhs, rhs, |x| x == 2)
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_programmable_if_then_else_test(param, executor);
+}
+
+fn integer_default_if_then_else_dontcare<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func =
+        |sks: &ServerKey,
+         cond: &crate::shortint::Ciphertext,
+         lhs: &RadixCiphertext,
+         rhs: &RadixCiphertext| { sks.if_then_else_dontcare_parallelized(cond, lhs, rhs) };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_if_then_else_dontcare_test(param, executor);
+}
+
+fn integer_default_if_then_else_with_checksum<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey,
+                conditions: &[BooleanBlock],
+                lhs: &RadixCiphertext,
+                rhs: &RadixCiphertext,
+                checksum: &RadixCiphertext| {
+        let mut checksum = checksum.clone();
+        let results = conditions
+            .iter()
+            .map(|condition| {
+                sks.if_then_else_with_checksum_parallelized(condition, lhs, rhs, &mut checksum)
+            })
+            .collect::<Vec<_>>();
+        (results, checksum)
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_if_then_else_with_checksum_test(param, executor);
+}
+
+fn integer_default_if_then_else_with_branch_tag<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func =
+        |sks: &ServerKey, cond: &BooleanBlock, lhs: &RadixCiphertext, rhs: &RadixCiphertext| {
+            sks.if_then_else_with_branch_tag_parallelized(cond, lhs, rhs)
+        };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_if_then_else_with_branch_tag_test(param, executor);
+}
+
+fn integer_default_if_then_else_with_degree_report<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func =
+        |sks: &ServerKey, cond: &BooleanBlock, lhs: &RadixCiphertext, rhs: &RadixCiphertext| {
+            sks.if_then_else_with_degree_report_parallelized(cond, lhs, rhs)
+        };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_if_then_else_with_degree_report_test(param, executor);
+}
+
+fn integer_default_if_then_else_heterogeneous<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func =
+        |sks: &ServerKey, cond: &BooleanBlock, lhs: &RadixCiphertext, rhs: &RadixCiphertext| {
+            sks.if_then_else_heterogeneous_parallelized(cond, lhs, rhs)
+        };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_if_then_else_heterogeneous_test(param, executor);
+}
+
+fn integer_default_saturating_cast<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::saturating_cast_parallelized);
+    default_saturating_cast_test(param, executor);
+}
+
+fn integer_default_if_then_else_opt<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::if_then_else_opt_parallelized);
+    default_if_then_else_opt_test(param, executor);
+}
+
+fn integer_default_select_valid<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::select_valid_parallelized);
+    default_select_valid_test(param, executor);
+}
+
+fn integer_default_conditional_ema_step<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_ema_step_parallelized);
+    default_conditional_ema_step_test(param, executor);
+}
+
+fn integer_default_conditional_affine<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_affine_parallelized);
+    default_conditional_affine_test(param, executor);
+}
+
+fn integer_default_conditional_xor_scalar<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_xor_scalar_parallelized);
+    default_conditional_xor_scalar_test(param, executor);
+}
+
+fn integer_default_conditional_crc_step<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_crc_step_parallelized);
+    default_conditional_crc_step_test(param, executor);
+}
+
+fn integer_default_conditional_fma_scalar<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_fma_scalar_parallelized);
+    default_conditional_fma_scalar_test(param, executor);
+}
+
+fn integer_default_conditional_fma<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_fma_parallelized);
+    default_conditional_fma_test(param, executor);
+}
+
+fn integer_default_if_then_else_assign<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::if_then_else_assign_parallelized::<RadixCiphertext>);
+    default_if_then_else_assign_test(param, executor);
+}
+
+fn integer_default_if_then_else_carry_save<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey,
+                conditions: &[BooleanBlock],
+                true_cts: &[RadixCiphertext],
+                false_cts: &[RadixCiphertext]| {
+        let mut selected = conditions
+            .iter()
+            .zip(true_cts.iter().zip(false_cts.iter()))
+            .map(|(condition, (true_ct, false_ct))| {
+                sks.if_then_else_carry_save_parallelized(condition, true_ct, false_ct)
+            });
+        let mut sum = selected.next().unwrap();
+        for value in selected {
+            sks.carry_save_add_assign_parallelized(&mut sum, &value);
+        }
+        sks.resolve_carry_save(sum)
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_if_then_else_carry_save_test(param, executor);
+}
+
 pub(crate) fn smart_if_then_else_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
@@ -244,13 +724,16 @@ where
     }
 }
 
-pub(crate) fn default_scalar_if_then_else_test<P, T>(param: P, mut executor: T)
+pub(crate) fn default_if_then_else_reproducible_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
-    T: for<'a> FunctionExecutor<(&'a BooleanBlock, u64, u64, usize), RadixCiphertext>,
+    T: for<'a> FunctionExecutor<
+        (&'a BooleanBlock, &'a RadixCiphertext, &'a RadixCiphertext),
+        RadixCiphertext,
+    >,
 {
     let param = param.into();
-    let nb_tests = nb_tests_for_params(param);
+    let nb_tests = nb_tests_smaller_for_params(param);
     let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
     let cks = RadixClientKey::from((cks, NB_CTXT));
 
@@ -262,18 +745,212 @@ where
     // message_modulus^vec_length
     let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
 
-    executor.setup(&cks, sks);
+    executor.setup(&cks, sks.clone());
 
     for _ in 0..nb_tests {
         let clear_0 = rng.gen::<u64>() % modulus;
         let clear_1 = rng.gen::<u64>() % modulus;
         let clear_condition = rng.gen_bool(0.5);
 
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
         let ctxt_condition = cks.encrypt_bool(clear_condition);
 
-        let ct_res = executor.execute((&ctxt_condition, clear_0, clear_1, NB_CTXT));
-        assert_eq!(ct_res.blocks.len(), NB_CTXT);
-        assert!(ct_res.block_carries_are_empty());
+        // Non empty carries on one of the inputs, so that carry propagation is done
+        // concurrently with the other input
+        if rng.gen_bool(0.5) {
+            let zero = cks.encrypt(0u64);
+            sks.unchecked_add_assign(&mut ctxt_0, &zero);
+        }
+
+        let serialized_results = (0..3)
+            .map(|_| {
+                let ct_res = executor.execute((&ctxt_condition, &ctxt_0, &ctxt_1));
+                let dec_res: u64 = cks.decrypt(&ct_res);
+                assert_eq!(dec_res, if clear_condition { clear_0 } else { clear_1 });
+                bincode::serialize(&ct_res).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert!(
+            serialized_results.windows(2).all(|w| w[0] == w[1]),
+            "Serialized outputs of if_then_else differ from one run to another"
+        );
+    }
+}
+
+pub(crate) fn default_lean_if_then_else_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a BooleanBlock, &'a RadixCiphertext, &'a RadixCiphertext),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        // Adding 0 gives non empty carries (according to the degrees)
+        // while the carries actually encrypt 0
+        sks.unchecked_add_assign(&mut ctxt_0, &cks.encrypt(0u64));
+        assert!(!ctxt_0.block_carries_are_empty());
+
+        for (true_ct, false_ct, clear_true, clear_false) in [
+            (&ctxt_0, &ctxt_1, clear_0, clear_1),
+            (&ctxt_1, &ctxt_0, clear_1, clear_0),
+        ] {
+            #[cfg(feature = "pbs-stats")]
+            crate::reset_pbs_count();
+            let ct_res = executor.execute((&ctxt_condition, true_ct, false_ct));
+            #[cfg(feature = "pbs-stats")]
+            let lean_pbs_count = crate::get_pbs_count();
+
+            #[cfg(feature = "pbs-stats")]
+            crate::reset_pbs_count();
+            let expected_ct = sks.if_then_else_parallelized(&ctxt_condition, true_ct, false_ct);
+            #[cfg(feature = "pbs-stats")]
+            let standard_pbs_count = crate::get_pbs_count();
+
+            assert!(ct_res.block_carries_are_empty());
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            let expected = if clear_condition {
+                clear_true
+            } else {
+                clear_false
+            };
+            assert_eq!(dec_res, expected);
+            let dec_expected: u64 = cks.decrypt(&expected_ct);
+            assert_eq!(dec_res, dec_expected);
+
+            #[cfg(feature = "pbs-stats")]
+            assert!(
+                lean_pbs_count < standard_pbs_count,
+                "The lean selection ({lean_pbs_count} PBS) should be cheaper than \
+                the standard one ({standard_pbs_count} PBS)"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_blockwise_select_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a [BooleanBlock], &'a RadixCiphertext, &'a RadixCiphertext),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = cks.parameters().message_modulus().0;
+    // message_modulus^vec_length
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_conditions = (0..NB_CTXT).map(|_| rng.gen_bool(0.5)).collect::<Vec<_>>();
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let ctxt_conditions = clear_conditions
+            .iter()
+            .map(|condition| cks.encrypt_bool(*condition))
+            .collect::<Vec<_>>();
+
+        let ct_res = executor.execute((&ctxt_conditions, &ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+
+        let expected = clear_conditions
+            .iter()
+            .enumerate()
+            .map(|(i, condition)| {
+                let shift = message_modulus.pow(i as u32);
+                let source = if *condition { clear_0 } else { clear_1 };
+                ((source / shift) % message_modulus) * shift
+            })
+            .sum::<u64>();
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res, expected,
+            "Invalid blockwise selection of {clear_0} and {clear_1} \
+            with conditions {clear_conditions:?}"
+        );
+
+        // Dirty inputs
+        let mut ctxt_0 = ctxt_0;
+        let clear_2 = rng.gen::<u64>() % modulus;
+        sks.unchecked_add_assign(&mut ctxt_0, &cks.encrypt(clear_2));
+        let clear_0 = (clear_0 + clear_2) % modulus;
+        assert!(!ctxt_0.block_carries_are_empty());
+
+        let all_true = vec![cks.encrypt_bool(true); NB_CTXT];
+        let ct_res = executor.execute((&all_true, &ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0);
+    }
+}
+
+pub(crate) fn default_scalar_if_then_else_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a BooleanBlock, u64, u64, usize), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        let ct_res = executor.execute((&ctxt_condition, clear_0, clear_1, NB_CTXT));
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        assert!(ct_res.block_carries_are_empty());
         assert!(ct_res
             .blocks
             .iter()
@@ -331,3 +1008,2442 @@ where
         );
     }
 }
+
+pub(crate) fn default_select_zero_pair_with_degrees_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a BooleanBlock, &'a RadixCiphertext, &'a RadixCiphertext),
+        (RadixCiphertext, RadixCiphertext, Vec<usize>, Vec<usize>),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let ctxt_2 = cks.encrypt(clear_2);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        // Add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+        assert!(!ctxt_0.block_carries_are_empty());
+        let clear_0 = (clear_0 + clear_2) % modulus;
+
+        let (masked_0, masked_1, degrees_0, degrees_1) =
+            executor.execute((&ctxt_condition, &ctxt_0, &ctxt_1));
+
+        for (masked, degrees) in [(&masked_0, &degrees_0), (&masked_1, &degrees_1)] {
+            assert_eq!(degrees.len(), NB_CTXT);
+            assert!(masked
+                .blocks
+                .iter()
+                .zip(degrees.iter())
+                .all(|(block, degree)| block.degree.get() as usize == *degree));
+        }
+
+        let dec_0: u64 = cks.decrypt(&masked_0);
+        let dec_1: u64 = cks.decrypt(&masked_1);
+        if clear_condition {
+            assert_eq!(dec_0, clear_0);
+            assert_eq!(dec_1, 0);
+        } else {
+            assert_eq!(dec_0, 0);
+            assert_eq!(dec_1, clear_1);
+        }
+
+        let mut combined = sks.unchecked_add_parallelized(&masked_0, &masked_1);
+        sks.full_propagate_parallelized(&mut combined);
+        let dec_res: u64 = cks.decrypt(&combined);
+        assert_eq!(dec_res, if clear_condition { clear_0 } else { clear_1 });
+    }
+}
+
+pub(crate) fn default_try_scalar_if_then_else_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a BooleanBlock, u64, u64, usize),
+        crate::Result<RadixCiphertext>,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        let ct_res = executor
+            .execute((&ctxt_condition, clear_0, clear_1, NB_CTXT))
+            .unwrap();
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, if clear_condition { clear_0 } else { clear_1 });
+
+        // Values that do not fit in NB_CTXT blocks
+        let too_large = rng.gen_range(modulus..=u64::MAX);
+        assert!(executor
+            .execute((&ctxt_condition, too_large, clear_1, NB_CTXT))
+            .is_err());
+        assert!(executor
+            .execute((&ctxt_condition, clear_0, too_large, NB_CTXT))
+            .is_err());
+    }
+}
+
+pub(crate) fn default_scalar_boolean_if_then_else_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a BooleanBlock, bool, bool), BooleanBlock>,
+{
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks);
+
+    for clear_condition in [false, true] {
+        let condition = cks.encrypt_bool(clear_condition);
+        let noise_level = condition.0.noise_level();
+
+        for (true_value, false_value) in
+            [(false, false), (false, true), (true, false), (true, true)]
+        {
+            let result = executor.execute((&condition, true_value, false_value));
+
+            // No PBS, the result is either trivial or a linear function of the condition
+            if true_value == false_value {
+                assert!(result.0.is_trivial());
+            } else {
+                assert_eq!(result.0.noise_level(), noise_level);
+            }
+            assert!(result.0.degree.get() <= 1);
+
+            let expected = if clear_condition {
+                true_value
+            } else {
+                false_value
+            };
+            let dec_result = cks.decrypt_bool(&result);
+            assert_eq!(
+                dec_result, expected,
+                "Invalid result for if {clear_condition} then {true_value} else {false_value}, \
+                expected {expected}, got {dec_result}"
+            );
+        }
+    }
+}
+pub(crate) fn default_conditional_move_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a mut RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a BooleanBlock,
+        ),
+        (),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_dst = rng.gen::<u64>() % modulus;
+        let clear_src = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut ctxt_dst = cks.encrypt(clear_dst);
+        let mut ctxt_src = cks.encrypt(clear_src);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        executor.execute((&mut ctxt_dst, &ctxt_src, &ctxt_condition));
+        assert!(ctxt_dst.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ctxt_dst);
+        let clear_dst = if clear_condition {
+            clear_src
+        } else {
+            clear_dst
+        };
+        assert_eq!(dec_res, clear_dst);
+
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let clear_3 = rng.gen::<u64>() % modulus;
+
+        let ctxt_2 = cks.encrypt(clear_2);
+        let ctxt_3 = cks.encrypt(clear_3);
+
+        // Add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_dst, &ctxt_2);
+        sks.unchecked_add_assign(&mut ctxt_src, &ctxt_3);
+        assert!(!ctxt_dst.block_carries_are_empty());
+        assert!(!ctxt_src.block_carries_are_empty());
+
+        executor.execute((&mut ctxt_dst, &ctxt_src, &ctxt_condition));
+        assert!(ctxt_dst.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ctxt_dst);
+        assert_eq!(
+            dec_res,
+            if clear_condition {
+                (clear_src + clear_3) % modulus
+            } else {
+                (clear_dst + clear_2) % modulus
+            }
+        );
+    }
+
+    // Some test with trivial ciphertext as input
+    let one = sks.create_trivial_radix(1, NB_CTXT);
+    for (clear_condition, expected) in [(false, 2u64), (true, 1u64)] {
+        let condition = sks.create_trivial_boolean_block(clear_condition);
+
+        let mut dst = sks.create_trivial_radix(2, NB_CTXT);
+        executor.execute((&mut dst, &one, &condition));
+        assert!(dst.block_carries_are_empty());
+        assert_eq!(cks.decrypt::<u64>(&dst), expected);
+    }
+}
+
+pub(crate) fn default_conditional_broadcast_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a mut RadixCiphertext,
+            &'a crate::shortint::Ciphertext,
+            &'a BooleanBlock,
+        ),
+        (),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = cks.parameters().message_modulus().0;
+    // message_modulus^vec_length
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    let broadcast = |fill: u64| {
+        (0..NB_CTXT as u32)
+            .map(|i| fill * message_modulus.pow(i))
+            .sum::<u64>()
+    };
+
+    for _ in 0..nb_tests {
+        let clear_ct = rng.gen::<u64>() % modulus;
+        let clear_fill = rng.gen::<u64>() % message_modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut ctxt = cks.encrypt(clear_ct);
+        let mut ctxt_fill = cks.encrypt_one_block(clear_fill);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        executor.execute((&mut ctxt, &ctxt_fill, &ctxt_condition));
+        assert!(ctxt.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ctxt);
+        let expected = if clear_condition {
+            broadcast(clear_fill)
+        } else {
+            clear_ct
+        };
+        assert_eq!(
+            dec_res, expected,
+            "Invalid broadcast of {clear_fill} into {clear_ct}, condition: {clear_condition}"
+        );
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let clear_3 = rng.gen::<u64>() % message_modulus;
+        sks.unchecked_add_assign(&mut ctxt, &cks.encrypt(clear_2));
+        sks.key
+            .unchecked_add_assign(&mut ctxt_fill, &cks.encrypt_one_block(clear_3));
+
+        executor.execute((&mut ctxt, &ctxt_fill, &ctxt_condition));
+        assert!(ctxt.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ctxt);
+        let expected = if clear_condition {
+            broadcast((clear_fill + clear_3) % message_modulus)
+        } else {
+            (expected + clear_2) % modulus
+        };
+        assert_eq!(
+            dec_res, expected,
+            "Invalid broadcast of dirty inputs, condition: {clear_condition}"
+        );
+    }
+}
+
+pub(crate) fn default_if_then_else_pair_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a BooleanBlock,
+            (&'a RadixCiphertext, &'a RadixCiphertext),
+            (&'a RadixCiphertext, &'a RadixCiphertext),
+        ),
+        (RadixCiphertext, RadixCiphertext),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        // Select between the (quotient, remainder) pairs of two divisions
+        let clear_pairs: [(u64, u64); 2] = std::array::from_fn(|_| {
+            let numerator = rng.gen::<u64>() % modulus;
+            let divisor = rng.gen_range(1..modulus);
+            (numerator / divisor, numerator % divisor)
+        });
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut pairs = clear_pairs.map(|(q, r)| (cks.encrypt(q), cks.encrypt(r)));
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        let expected = if clear_condition {
+            clear_pairs[0]
+        } else {
+            clear_pairs[1]
+        };
+
+        let (ct_q, ct_r) = executor.execute((
+            &ctxt_condition,
+            (&pairs[0].0, &pairs[0].1),
+            (&pairs[1].0, &pairs[1].1),
+        ));
+        assert!(ct_q.block_carries_are_empty());
+        assert!(ct_r.block_carries_are_empty());
+
+        let dec_q: u64 = cks.decrypt(&ct_q);
+        let dec_r: u64 = cks.decrypt(&ct_r);
+        assert_eq!((dec_q, dec_r), expected);
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let ctxt_2 = cks.encrypt(clear_2);
+        for (q, r) in pairs.iter_mut() {
+            sks.unchecked_add_assign(q, &ctxt_2);
+            sks.unchecked_add_assign(r, &ctxt_2);
+            assert!(!q.block_carries_are_empty());
+            assert!(!r.block_carries_are_empty());
+        }
+
+        let (ct_q, ct_r) = executor.execute((
+            &ctxt_condition,
+            (&pairs[0].0, &pairs[0].1),
+            (&pairs[1].0, &pairs[1].1),
+        ));
+        assert!(ct_q.block_carries_are_empty());
+        assert!(ct_r.block_carries_are_empty());
+
+        let dec_q: u64 = cks.decrypt(&ct_q);
+        let dec_r: u64 = cks.decrypt(&ct_r);
+        assert_eq!(
+            (dec_q, dec_r),
+            (
+                (expected.0 + clear_2) % modulus,
+                (expected.1 + clear_2) % modulus
+            )
+        );
+    }
+}
+
+pub(crate) fn default_select_packed_bit_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a crate::shortint::Ciphertext,
+            u32,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let block_modulus = cks.parameters().message_modulus().0;
+    let modulus = block_modulus.pow(NB_CTXT as u32);
+    let num_bits_in_block = block_modulus.ilog2();
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_flags = rng.gen::<u64>() % block_modulus;
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let mut ctxt_1 = cks.encrypt(clear_1);
+        let packed = cks.encrypt_one_block(clear_flags);
+
+        for bit in 0..num_bits_in_block {
+            let flag = (clear_flags >> bit) & 1 == 1;
+
+            let ct_res = executor.execute((&packed, bit, &ctxt_0, &ctxt_1));
+            assert!(ct_res.block_carries_are_empty());
+
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec_res,
+                if flag { clear_0 } else { clear_1 },
+                "Invalid result for select_packed_bit, flags: {clear_flags:#b}, bit: {bit}"
+            );
+        }
+
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let clear_3 = rng.gen::<u64>() % modulus;
+
+        let ctxt_2 = cks.encrypt(clear_2);
+        let ctxt_3 = cks.encrypt(clear_3);
+
+        // Add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+        sks.unchecked_add_assign(&mut ctxt_1, &ctxt_3);
+        assert!(!ctxt_0.block_carries_are_empty());
+        assert!(!ctxt_1.block_carries_are_empty());
+
+        let bit = rng.gen_range(0..num_bits_in_block);
+        let flag = (clear_flags >> bit) & 1 == 1;
+
+        let ct_res = executor.execute((&packed, bit, &ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            if flag {
+                (clear_0 + clear_2) % modulus
+            } else {
+                (clear_1 + clear_3) % modulus
+            }
+        );
+    }
+}
+
+pub(crate) fn default_arithmetic_select_with_plan_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a [BooleanBlock], &'a RadixCiphertext, &'a RadixCiphertext),
+        Vec<RadixCiphertext>,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_conditions = (0..4).map(|_| rng.gen_bool(0.5)).collect::<Vec<_>>();
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let mut ctxt_1 = cks.encrypt(clear_1);
+        let conditions = clear_conditions
+            .iter()
+            .map(|c| cks.encrypt_bool(*c))
+            .collect::<Vec<_>>();
+
+        let results = executor.execute((&conditions, &ctxt_0, &ctxt_1));
+        assert_eq!(results.len(), clear_conditions.len());
+        for (ct_res, clear_condition) in results.iter().zip(clear_conditions.iter()) {
+            assert!(ct_res.block_carries_are_empty());
+            let dec_res: u64 = cks.decrypt(ct_res);
+            assert_eq!(dec_res, if *clear_condition { clear_0 } else { clear_1 });
+        }
+
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let clear_3 = rng.gen::<u64>() % modulus;
+
+        let ctxt_2 = cks.encrypt(clear_2);
+        let ctxt_3 = cks.encrypt(clear_3);
+
+        // Add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+        sks.unchecked_add_assign(&mut ctxt_1, &ctxt_3);
+        assert!(!ctxt_0.block_carries_are_empty());
+        assert!(!ctxt_1.block_carries_are_empty());
+
+        let results = executor.execute((&conditions, &ctxt_0, &ctxt_1));
+        for (ct_res, clear_condition) in results.iter().zip(clear_conditions.iter()) {
+            assert!(ct_res.block_carries_are_empty());
+            let dec_res: u64 = cks.decrypt(ct_res);
+            assert_eq!(
+                dec_res,
+                if *clear_condition {
+                    (clear_0 + clear_2) % modulus
+                } else {
+                    (clear_1 + clear_3) % modulus
+                }
+            );
+        }
+    }
+}
+
+pub(crate) fn default_if_then_else_keep_loser_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a BooleanBlock, &'a RadixCiphertext, &'a RadixCiphertext),
+        (RadixCiphertext, RadixCiphertext),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let mut ctxt_1 = cks.encrypt(clear_1);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        let (winner, loser) = executor.execute((&ctxt_condition, &ctxt_0, &ctxt_1));
+        assert!(winner.block_carries_are_empty());
+        assert!(loser.block_carries_are_empty());
+
+        let dec_winner: u64 = cks.decrypt(&winner);
+        let dec_loser: u64 = cks.decrypt(&loser);
+        let expected = if clear_condition {
+            (clear_0, clear_1)
+        } else {
+            (clear_1, clear_0)
+        };
+        assert_eq!((dec_winner, dec_loser), expected);
+
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let clear_3 = rng.gen::<u64>() % modulus;
+
+        let ctxt_2 = cks.encrypt(clear_2);
+        let ctxt_3 = cks.encrypt(clear_3);
+
+        // Add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+        sks.unchecked_add_assign(&mut ctxt_1, &ctxt_3);
+        assert!(!ctxt_0.block_carries_are_empty());
+        assert!(!ctxt_1.block_carries_are_empty());
+
+        let (winner, loser) = executor.execute((&ctxt_condition, &ctxt_0, &ctxt_1));
+        assert!(winner.block_carries_are_empty());
+        assert!(loser.block_carries_are_empty());
+
+        let dec_winner: u64 = cks.decrypt(&winner);
+        let dec_loser: u64 = cks.decrypt(&loser);
+        let (clear_0, clear_1) = ((clear_0 + clear_2) % modulus, (clear_1 + clear_3) % modulus);
+        let expected = if clear_condition {
+            (clear_0, clear_1)
+        } else {
+            (clear_1, clear_0)
+        };
+        assert_eq!((dec_winner, dec_loser), expected);
+    }
+}
+
+pub(crate) fn default_conditional_swap_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a BooleanBlock, &'a RadixCiphertext, &'a RadixCiphertext),
+        (RadixCiphertext, RadixCiphertext),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let mut ctxt_1 = cks.encrypt(clear_1);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        let (first, second) = executor.execute((&ctxt_condition, &ctxt_0, &ctxt_1));
+        assert!(first.block_carries_are_empty());
+        assert!(second.block_carries_are_empty());
+
+        let dec_first: u64 = cks.decrypt(&first);
+        let dec_second: u64 = cks.decrypt(&second);
+        let expected = if clear_condition {
+            (clear_1, clear_0)
+        } else {
+            (clear_0, clear_1)
+        };
+        assert_eq!((dec_first, dec_second), expected);
+
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let clear_3 = rng.gen::<u64>() % modulus;
+
+        let ctxt_2 = cks.encrypt(clear_2);
+        let ctxt_3 = cks.encrypt(clear_3);
+
+        // Add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+        sks.unchecked_add_assign(&mut ctxt_1, &ctxt_3);
+        assert!(!ctxt_0.block_carries_are_empty());
+        assert!(!ctxt_1.block_carries_are_empty());
+
+        let (first, second) = executor.execute((&ctxt_condition, &ctxt_0, &ctxt_1));
+        assert!(first.block_carries_are_empty());
+        assert!(second.block_carries_are_empty());
+
+        let dec_first: u64 = cks.decrypt(&first);
+        let dec_second: u64 = cks.decrypt(&second);
+        let (clear_0, clear_1) = ((clear_0 + clear_2) % modulus, (clear_1 + clear_3) % modulus);
+        let expected = if clear_condition {
+            (clear_1, clear_0)
+        } else {
+            (clear_0, clear_1)
+        };
+        assert_eq!((dec_first, dec_second), expected);
+    }
+
+    // Some test with trivial ciphertexts as input, which have trivial zero blocks
+    let clear_0 = rng.gen::<u64>() % modulus;
+    let ctxt_0 = cks.encrypt(clear_0);
+    let zero: RadixCiphertext = sks.create_trivial_zero_radix(NB_CTXT);
+    for clear_condition in [false, true] {
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        let (first, second) = executor.execute((&ctxt_condition, &ctxt_0, &zero));
+        assert!(first.block_carries_are_empty());
+        assert!(second.block_carries_are_empty());
+
+        let dec_first: u64 = cks.decrypt(&first);
+        let dec_second: u64 = cks.decrypt(&second);
+        let expected = if clear_condition {
+            (0, clear_0)
+        } else {
+            (clear_0, 0)
+        };
+        assert_eq!((dec_first, dec_second), expected);
+    }
+}
+
+pub(crate) fn default_select_struct_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a mut Vec<RadixCiphertext>,
+            &'a [RadixCiphertext],
+            &'a BooleanBlock,
+        ),
+        (),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests {
+        let num_fields = rng.gen_range(0..=4usize);
+        let clear_dst = (0..num_fields)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let clear_src = (0..num_fields)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut dst = clear_dst
+            .iter()
+            .map(|v| cks.encrypt(*v))
+            .collect::<Vec<RadixCiphertext>>();
+        let src = clear_src
+            .iter()
+            .map(|v| cks.encrypt(*v))
+            .collect::<Vec<RadixCiphertext>>();
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        executor.execute((&mut dst, &src, &ctxt_condition));
+
+        let expected = if clear_condition {
+            &clear_src
+        } else {
+            &clear_dst
+        };
+        assert_eq!(dst.len(), num_fields);
+        for (ct, expected) in dst.iter().zip(expected.iter()) {
+            assert!(ct.block_carries_are_empty());
+            let dec: u64 = cks.decrypt(ct);
+            assert_eq!(dec, *expected);
+        }
+    }
+}
+
+pub(crate) fn default_right_scalar_if_then_else_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a BooleanBlock, &'a RadixCiphertext, u64), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+    let max_noise_level = sks.key.max_noise_level.get();
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        let ct_res = executor.execute((&ctxt_condition, &ctxt_0, clear_1));
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, if clear_condition { clear_0 } else { clear_1 });
+
+        // Add as many times as the noise allows, to have blocks with a high degree
+        let mut clear_sum = clear_0;
+        for _ in 1..max_noise_level {
+            let clear_2 = rng.gen::<u64>() % modulus;
+            let ctxt_2 = cks.encrypt(clear_2);
+            sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+            clear_sum = (clear_sum + clear_2) % modulus;
+        }
+
+        let ct_res = executor.execute((&ctxt_condition, &ctxt_0, clear_1));
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, if clear_condition { clear_sum } else { clear_1 });
+    }
+}
+
+pub(crate) fn default_constant_shape_if_then_else_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a BooleanBlock, &'a RadixCiphertext, &'a RadixCiphertext),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = cks.parameters().message_modulus().0;
+    let small_num_blocks = NB_CTXT / 2;
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+    let small_modulus = message_modulus.pow(small_num_blocks as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_large = rng.gen::<u64>() % modulus;
+        let clear_small = rng.gen::<u64>() % small_modulus;
+
+        let large = cks.encrypt(clear_large);
+        let small = cks.as_ref().encrypt_radix(clear_small, small_num_blocks);
+        let trivial_small: RadixCiphertext =
+            sks.create_trivial_radix(clear_small, small_num_blocks);
+
+        for clear_condition in [false, true] {
+            let condition = cks.encrypt_bool(clear_condition);
+
+            for (small, small_kind) in [(&small, "encrypted"), (&trivial_small, "trivial")] {
+                for (true_ct, false_ct, clear_true, clear_false) in [
+                    (&large, small, clear_large, clear_small),
+                    (small, &large, clear_small, clear_large),
+                ] {
+                    #[cfg(feature = "pbs-stats")]
+                    crate::reset_pbs_count();
+
+                    let result = executor.execute((&condition, true_ct, false_ct));
+
+                    // The work done must not depend on the condition,
+                    // nor on the content of the branches
+                    #[cfg(feature = "pbs-stats")]
+                    assert_eq!(
+                        crate::get_pbs_count(),
+                        3 * NB_CTXT as u64,
+                        "Invalid PBS Count"
+                    );
+
+                    assert_eq!(result.blocks.len(), NB_CTXT);
+                    assert!(result.block_carries_are_empty());
+
+                    let dec: u64 = cks.decrypt(&result);
+                    let expected = if clear_condition {
+                        clear_true
+                    } else {
+                        clear_false
+                    };
+                    assert_eq!(
+                        dec, expected,
+                        "Invalid constant_shape_if_then_else result with a {small_kind} small branch, \
+                        expected {expected}, got {dec}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn default_programmable_select_with_plan_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a [crate::shortint::Ciphertext],
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        Vec<RadixCiphertext>,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let block_modulus = cks.parameters().message_modulus().0;
+    let modulus = block_modulus.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    // The executor selects lhs when the condition block is odd
+    let expected = |condition: u64, lhs: u64, rhs: u64| if condition % 2 == 1 { lhs } else { rhs };
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let mut clear_conditions = (0..4)
+            .map(|_| rng.gen::<u64>() % block_modulus)
+            .collect::<Vec<_>>();
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let mut ctxt_1 = cks.encrypt(clear_1);
+        let mut conditions = clear_conditions
+            .iter()
+            .map(|c| cks.encrypt_one_block(*c))
+            .collect::<Vec<_>>();
+        // A trivial condition block takes a shortcut
+        clear_conditions.push(0);
+        conditions.push(sks.create_trivial_boolean_block(false).into_raw_parts());
+
+        let results = executor.execute((&conditions, &ctxt_0, &ctxt_1));
+        assert_eq!(results.len(), clear_conditions.len());
+        for (ct_res, clear_condition) in results.iter().zip(clear_conditions.iter()) {
+            assert!(ct_res.block_carries_are_empty());
+            let dec_res: u64 = cks.decrypt(ct_res);
+            assert_eq!(
+                dec_res,
+                expected(*clear_condition, clear_0, clear_1),
+                "Invalid result for programmable_select_with_plan, condition: {clear_condition}"
+            );
+        }
+
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let clear_3 = rng.gen::<u64>() % modulus;
+
+        let ctxt_2 = cks.encrypt(clear_2);
+        let ctxt_3 = cks.encrypt(clear_3);
+
+        // Add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+        sks.unchecked_add_assign(&mut ctxt_1, &ctxt_3);
+        assert!(!ctxt_0.block_carries_are_empty());
+        assert!(!ctxt_1.block_carries_are_empty());
+
+        let results = executor.execute((&conditions, &ctxt_0, &ctxt_1));
+        for (ct_res, clear_condition) in results.iter().zip(clear_conditions.iter()) {
+            assert!(ct_res.block_carries_are_empty());
+            let dec_res: u64 = cks.decrypt(ct_res);
+            assert_eq!(
+                dec_res,
+                expected(
+                    *clear_condition,
+                    (clear_0 + clear_2) % modulus,
+                    (clear_1 + clear_3) % modulus
+                ),
+                "Invalid result for programmable_select_with_plan, condition: {clear_condition}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_programmable_if_then_else_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let block_modulus = cks.parameters().message_modulus().0;
+    let modulus = block_modulus.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    // The executor selects lhs when the condition block equals 2
+    let expected = |condition: u64, lhs: u64, rhs: u64| if condition == 2 { lhs } else { rhs };
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let mut ctxt_1 = cks.encrypt(clear_1);
+
+        for clear_condition in 0..block_modulus {
+            let condition = cks.as_ref().encrypt_radix(clear_condition, 1);
+
+            let ct_res = executor.execute((&condition, &ctxt_0, &ctxt_1));
+            assert!(ct_res.block_carries_are_empty());
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec_res,
+                expected(clear_condition, clear_0, clear_1),
+                "Invalid result for programmable_if_then_else, condition: {clear_condition}"
+            );
+        }
+
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let clear_3 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen::<u64>() % block_modulus;
+        let clear_condition_2 = rng.gen::<u64>() % block_modulus;
+
+        let ctxt_2 = cks.encrypt(clear_2);
+        let ctxt_3 = cks.encrypt(clear_3);
+        let mut condition = cks.as_ref().encrypt_radix(clear_condition, 1);
+        let condition_2 = cks.as_ref().encrypt_radix(clear_condition_2, 1);
+
+        // Add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+        sks.unchecked_add_assign(&mut ctxt_1, &ctxt_3);
+        sks.unchecked_add_assign(&mut condition, &condition_2);
+        assert!(!ctxt_0.block_carries_are_empty());
+        assert!(!ctxt_1.block_carries_are_empty());
+
+        let ct_res = executor.execute((&condition, &ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let clear_condition = (clear_condition + clear_condition_2) % block_modulus;
+        assert_eq!(
+            dec_res,
+            expected(
+                clear_condition,
+                (clear_0 + clear_2) % modulus,
+                (clear_1 + clear_3) % modulus
+            ),
+            "Invalid result for programmable_if_then_else, condition: {clear_condition}"
+        );
+    }
+}
+
+pub(crate) fn default_if_then_else_dontcare_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a crate::shortint::Ciphertext,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_flag_0 = rng.gen_bool(0.5);
+        let clear_flag_1 = rng.gen_bool(0.5);
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let mut ctxt_1 = cks.encrypt(clear_1);
+
+        // The sum of two booleans gives a condition in {0, 1, 2} with a degree of 2
+        let mut condition = cks.encrypt_bool(clear_flag_0).into_raw_parts();
+        sks.key
+            .unchecked_add_assign(&mut condition, cks.encrypt_bool(clear_flag_1).as_ref());
+        let clear_condition = u64::from(clear_flag_0) + u64::from(clear_flag_1);
+
+        let check = |ct_res: &RadixCiphertext, clear_true: u64, clear_false: u64| {
+            assert!(ct_res.block_carries_are_empty());
+            let dec_res: u64 = cks.decrypt(ct_res);
+            match clear_condition {
+                0 => assert_eq!(dec_res, clear_false),
+                1 => assert_eq!(dec_res, clear_true),
+                _ => assert!(
+                    dec_res == clear_true || dec_res == clear_false,
+                    "Invalid result for don't care condition: \
+                    got {dec_res}, expected {clear_true} or {clear_false}"
+                ),
+            }
+        };
+
+        let ct_res = executor.execute((&condition, &ctxt_0, &ctxt_1));
+        check(&ct_res, clear_0, clear_1);
+
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let clear_3 = rng.gen::<u64>() % modulus;
+
+        let ctxt_2 = cks.encrypt(clear_2);
+        let ctxt_3 = cks.encrypt(clear_3);
+
+        // Add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+        sks.unchecked_add_assign(&mut ctxt_1, &ctxt_3);
+        assert!(!ctxt_0.block_carries_are_empty());
+        assert!(!ctxt_1.block_carries_are_empty());
+
+        let ct_res = executor.execute((&condition, &ctxt_0, &ctxt_1));
+        check(
+            &ct_res,
+            (clear_0 + clear_2) % modulus,
+            (clear_1 + clear_3) % modulus,
+        );
+    }
+}
+
+pub(crate) fn default_if_then_else_with_branch_tag_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a BooleanBlock, &'a RadixCiphertext, &'a RadixCiphertext),
+        (RadixCiphertext, RadixCiphertext),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let ctxt_2 = cks.encrypt(clear_2);
+
+        // Add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+        assert!(!ctxt_0.block_carries_are_empty());
+        let clear_0 = (clear_0 + clear_2) % modulus;
+
+        for clear_condition in [false, true] {
+            let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+            let (ct_res, tag) = executor.execute((&ctxt_condition, &ctxt_0, &ctxt_1));
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(tag.blocks.len(), ct_res.blocks.len());
+
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            let dec_tag: u64 = cks.decrypt(&tag);
+            assert_eq!(dec_res, if clear_condition { clear_0 } else { clear_1 });
+            assert_eq!(dec_tag, u64::from(clear_condition));
+        }
+    }
+}
+
+pub(crate) fn default_if_then_else_with_degree_report_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a BooleanBlock, &'a RadixCiphertext, &'a RadixCiphertext),
+        (RadixCiphertext, Vec<usize>),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = cks.parameters().message_modulus().0;
+    // message_modulus^vec_length
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let ctxt_2 = cks.encrypt(clear_2);
+
+        // Add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+        assert!(!ctxt_0.block_carries_are_empty());
+        let clear_0 = (clear_0 + clear_2) % modulus;
+
+        for clear_condition in [false, true] {
+            let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+            let (ct_res, degrees) = executor.execute((&ctxt_condition, &ctxt_0, &ctxt_1));
+            assert_eq!(degrees.len(), ct_res.blocks.len());
+            for (block, degree) in ct_res.blocks.iter().zip(degrees.iter()) {
+                assert_eq!(block.degree.get() as usize, *degree);
+                assert!(*degree < message_modulus as usize);
+            }
+
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(dec_res, if clear_condition { clear_0 } else { clear_1 });
+        }
+    }
+}
+
+pub(crate) fn default_if_then_else_with_checksum_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a [BooleanBlock],
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        (Vec<RadixCiphertext>, RadixCiphertext),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_checksum = rng.gen::<u64>() % modulus;
+        let clear_conditions = (0..4).map(|_| rng.gen_bool(0.5)).collect::<Vec<_>>();
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let mut ctxt_1 = cks.encrypt(clear_1);
+        let mut checksum = cks.encrypt(clear_checksum);
+        let conditions = clear_conditions
+            .iter()
+            .map(|c| cks.encrypt_bool(*c))
+            .collect::<Vec<_>>();
+
+        let (results, ct_checksum) = executor.execute((&conditions, &ctxt_0, &ctxt_1, &checksum));
+        assert_eq!(results.len(), clear_conditions.len());
+        let mut expected_checksum = clear_checksum;
+        for (ct_res, clear_condition) in results.iter().zip(clear_conditions.iter()) {
+            assert!(ct_res.block_carries_are_empty());
+            let dec_res: u64 = cks.decrypt(ct_res);
+            assert_eq!(dec_res, if *clear_condition { clear_0 } else { clear_1 });
+            expected_checksum ^= dec_res;
+        }
+        assert!(ct_checksum.block_carries_are_empty());
+        let dec_checksum: u64 = cks.decrypt(&ct_checksum);
+        assert_eq!(
+            dec_checksum, expected_checksum,
+            "Invalid checksum, expected {expected_checksum}, got {dec_checksum}"
+        );
+
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let clear_3 = rng.gen::<u64>() % modulus;
+        let clear_4 = rng.gen::<u64>() % modulus;
+
+        let ctxt_2 = cks.encrypt(clear_2);
+        let ctxt_3 = cks.encrypt(clear_3);
+        let ctxt_4 = cks.encrypt(clear_4);
+
+        // Add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+        sks.unchecked_add_assign(&mut ctxt_1, &ctxt_3);
+        sks.unchecked_add_assign(&mut checksum, &ctxt_4);
+        assert!(!ctxt_0.block_carries_are_empty());
+        assert!(!ctxt_1.block_carries_are_empty());
+        assert!(!checksum.block_carries_are_empty());
+
+        let (results, ct_checksum) = executor.execute((&conditions, &ctxt_0, &ctxt_1, &checksum));
+        let mut expected_checksum = (clear_checksum + clear_4) % modulus;
+        for (ct_res, clear_condition) in results.iter().zip(clear_conditions.iter()) {
+            assert!(ct_res.block_carries_are_empty());
+            let dec_res: u64 = cks.decrypt(ct_res);
+            assert_eq!(
+                dec_res,
+                if *clear_condition {
+                    (clear_0 + clear_2) % modulus
+                } else {
+                    (clear_1 + clear_3) % modulus
+                }
+            );
+            expected_checksum ^= dec_res;
+        }
+        assert!(ct_checksum.block_carries_are_empty());
+        let dec_checksum: u64 = cks.decrypt(&ct_checksum);
+        assert_eq!(
+            dec_checksum, expected_checksum,
+            "Invalid checksum, expected {expected_checksum}, got {dec_checksum}"
+        );
+    }
+}
+
+pub(crate) fn default_if_then_else_heterogeneous_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a BooleanBlock, &'a RadixCiphertext, &'a RadixCiphertext),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // Blocks with non power of two moduli, mixed with a block using the moduli of the key
+    let basis = vec![2, 3, 5, 7, cks.parameters().message_modulus().0];
+    let modulus = basis.iter().product::<u64>();
+
+    executor.setup(&cks, sks.clone());
+
+    let encrypt =
+        |clear: u64| RadixCiphertext::from(cks.as_ref().encrypt_crt(clear, basis.clone()).blocks);
+    let decrypt_residues = |ct: &RadixCiphertext| {
+        ct.blocks
+            .iter()
+            .map(|block| cks.decrypt_one_block(block))
+            .collect::<Vec<_>>()
+    };
+    let residues = |clear: u64| basis.iter().map(|m| clear % m).collect::<Vec<_>>();
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let ctxt_0 = encrypt(clear_0);
+        let ctxt_1 = encrypt(clear_1);
+        let condition = cks.encrypt_bool(clear_condition);
+
+        let ct_res = executor.execute((&condition, &ctxt_0, &ctxt_1));
+        for (block, ct_block) in ct_res.blocks.iter().zip(ctxt_0.blocks.iter()) {
+            assert_eq!(block.message_modulus, ct_block.message_modulus);
+            assert!(block.carry_is_empty());
+        }
+        assert_eq!(
+            decrypt_residues(&ct_res),
+            residues(if clear_condition { clear_0 } else { clear_1 }),
+            "Invalid result for condition {clear_condition}, {clear_0} and {clear_1}"
+        );
+
+        // Trivial condition
+        let condition = sks.create_trivial_boolean_block(clear_condition);
+        let ct_res = executor.execute((&condition, &ctxt_0, &ctxt_1));
+        assert_eq!(
+            decrypt_residues(&ct_res),
+            residues(if clear_condition { clear_0 } else { clear_1 }),
+            "Invalid result for trivial condition {clear_condition}, {clear_0} and {clear_1}"
+        );
+    }
+}
+
+fn integer_default_conditional_widen<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_widen_parallelized);
+    default_conditional_widen_test(param, executor);
+}
+
+pub(crate) fn default_saturating_cast_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, usize), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = cks.parameters().message_modulus().0;
+    // message_modulus^vec_length
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests {
+        for target_blocks in [1, NB_CTXT / 2, NB_CTXT, NB_CTXT + 1] {
+            let target_max = message_modulus
+                .checked_pow(target_blocks as u32)
+                .map_or(u64::MAX, |m| m - 1);
+
+            // Values at the boundary of the target type, and a random one
+            let clears = [
+                0,
+                target_max.saturating_sub(1),
+                target_max,
+                target_max.saturating_add(1),
+                modulus - 1,
+                rng.gen::<u64>() % modulus,
+            ];
+            for clear in clears.into_iter().filter(|clear| *clear < modulus) {
+                let ctxt = cks.encrypt(clear);
+
+                let ct_res = executor.execute((&ctxt, target_blocks));
+                assert_eq!(ct_res.blocks.len(), target_blocks);
+                assert!(ct_res.block_carries_are_empty());
+
+                let dec_res: u64 = cks.decrypt(&ct_res);
+                assert_eq!(
+                    dec_res,
+                    clear.min(target_max),
+                    "Invalid saturating cast of {clear} to {target_blocks} blocks"
+                );
+            }
+        }
+    }
+}
+
+pub(crate) fn default_conditional_widen_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, usize, &'a BooleanBlock), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let clear = rng.gen::<u64>() % modulus;
+        let extra_blocks = rng.gen_range(0..=NB_CTXT);
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut ctxt = cks.encrypt(clear);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        let ct_res = executor.execute((&ctxt, extra_blocks, &ctxt_condition));
+        assert_eq!(ct_res.blocks.len(), NB_CTXT + extra_blocks);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res, clear,
+            "Invalid widening of {clear} by {extra_blocks} blocks with condition {clear_condition}"
+        );
+
+        // Add to have non empty carries, the carry out of the original width must be
+        // discarded, not moved into the padding blocks
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let ctxt_2 = cks.encrypt(clear_2);
+        sks.unchecked_add_assign(&mut ctxt, &ctxt_2);
+        let clear = (clear + clear_2) % modulus;
+
+        let ct_res = executor.execute((&ctxt, extra_blocks, &ctxt_condition));
+        assert_eq!(ct_res.blocks.len(), NB_CTXT + extra_blocks);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res, clear,
+            "Invalid widening of {clear} by {extra_blocks} blocks with condition {clear_condition}"
+        );
+    }
+}
+
+pub(crate) fn default_if_then_else_opt_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a BooleanBlock,
+            Option<&'a RadixCiphertext>,
+            Option<&'a RadixCiphertext>,
+            usize,
+        ),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        // Dirty branch, so that the propagation is also tested
+        let mut ctxt_1 = cks.encrypt(clear_1 / 2);
+        sks.unchecked_add_assign(&mut ctxt_1, &cks.encrypt(clear_1 - clear_1 / 2));
+        let condition = cks.encrypt_bool(clear_condition);
+
+        for (true_ct, false_ct) in [
+            (Some(&ctxt_0), Some(&ctxt_1)),
+            (Some(&ctxt_0), None),
+            (None, Some(&ctxt_1)),
+            (None, None),
+        ] {
+            let ct_res = executor.execute((&condition, true_ct, false_ct, NB_CTXT));
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(ct_res.blocks.len(), NB_CTXT);
+
+            let expected = if clear_condition {
+                true_ct.map_or(0, |_| clear_0)
+            } else {
+                false_ct.map_or(0, |_| clear_1)
+            };
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec_res,
+                expected,
+                "Invalid if_then_else_opt result for condition {clear_condition}, \
+                true branch present: {}, false branch present: {}",
+                true_ct.is_some(),
+                false_ct.is_some()
+            );
+        }
+    }
+}
+
+pub(crate) fn default_conditional_ema_step_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a mut RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a BooleanBlock,
+            u32,
+        ),
+        (),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+    let num_bits = modulus.ilog2();
+
+    executor.setup(&cks, sks.clone());
+
+    let ema_step =
+        |acc: u64, sample: u64, shift: u32| (acc - (acc >> shift) + (sample >> shift)) % modulus;
+
+    for _ in 0..nb_tests {
+        let clear_acc = rng.gen::<u64>() % modulus;
+        let clear_sample = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+        let shift = rng.gen_range(0..num_bits);
+
+        let mut ctxt_acc = cks.encrypt(clear_acc);
+        let ctxt_sample = cks.encrypt(clear_sample);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        executor.execute((&mut ctxt_acc, &ctxt_sample, &ctxt_condition, shift));
+        assert!(ctxt_acc.block_carries_are_empty());
+
+        let clear_acc = if clear_condition {
+            ema_step(clear_acc, clear_sample, shift)
+        } else {
+            clear_acc
+        };
+        let dec_res: u64 = cks.decrypt(&ctxt_acc);
+        assert_eq!(
+            dec_res, clear_acc,
+            "Invalid EMA step result for condition {clear_condition}, sample {clear_sample}, \
+            shift {shift}"
+        );
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let ctxt_2 = cks.encrypt(clear_2);
+        sks.unchecked_add_assign(&mut ctxt_acc, &ctxt_2);
+        assert!(!ctxt_acc.block_carries_are_empty());
+        let clear_acc = (clear_acc + clear_2) % modulus;
+
+        executor.execute((&mut ctxt_acc, &ctxt_sample, &ctxt_condition, shift));
+        assert!(ctxt_acc.block_carries_are_empty());
+
+        let expected = if clear_condition {
+            ema_step(clear_acc, clear_sample, shift)
+        } else {
+            clear_acc
+        };
+        let dec_res: u64 = cks.decrypt(&ctxt_acc);
+        assert_eq!(dec_res, expected);
+    }
+}
+
+pub(crate) fn default_conditional_affine_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, u64, u64, &'a BooleanBlock), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let clear = rng.gen::<u64>() % modulus;
+        // Scales larger than the modulus make the affine map wrap around
+        let scale = rng.gen::<u64>() % (2 * modulus);
+        let bias = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut ctxt = cks.encrypt(clear);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        let expected = |clear: u64| {
+            if clear_condition {
+                clear.wrapping_mul(scale).wrapping_add(bias) % modulus
+            } else {
+                clear
+            }
+        };
+
+        let ct_res = executor.execute((&ctxt, scale, bias, &ctxt_condition));
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            expected(clear),
+            "Invalid result for {clear} * {scale} + {bias} with condition {clear_condition}"
+        );
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let ctxt_2 = cks.encrypt(clear_2);
+        sks.unchecked_add_assign(&mut ctxt, &ctxt_2);
+        let clear = (clear + clear_2) % modulus;
+
+        let ct_res = executor.execute((&ctxt, scale, bias, &ctxt_condition));
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            expected(clear),
+            "Invalid result for {clear} * {scale} + {bias} with condition {clear_condition}"
+        );
+    }
+}
+
+pub(crate) fn default_conditional_xor_scalar_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a mut RadixCiphertext, u64, &'a BooleanBlock), ()>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let clear = rng.gen::<u64>() % modulus;
+        // Keystream words wider than the ciphertext have their high bits ignored,
+        // and small ones leave the high blocks untouched
+        let keystream = match rng.gen_range(0..3) {
+            0 => rng.gen::<u64>(),
+            1 => rng.gen::<u64>() % cks.parameters().message_modulus().0,
+            _ => rng.gen::<u64>() % modulus,
+        };
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut ctxt = cks.encrypt(clear);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen::<u64>() % modulus;
+        sks.unchecked_scalar_add_assign(&mut ctxt, clear_2);
+        let clear = (clear + clear_2) % modulus;
+
+        executor.execute((&mut ctxt, keystream, &ctxt_condition));
+        assert!(ctxt.block_carries_are_empty());
+
+        let expected = if clear_condition {
+            (clear ^ keystream) % modulus
+        } else {
+            clear
+        };
+        let dec_res: u64 = cks.decrypt(&ctxt);
+        assert_eq!(
+            dec_res, expected,
+            "Invalid result for {clear} ^ {keystream} with condition {clear_condition}"
+        );
+    }
+}
+
+pub(crate) fn default_conditional_crc_step_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a mut RadixCiphertext,
+            &'a crate::shortint::Ciphertext,
+            u64,
+            &'a BooleanBlock,
+        ),
+        (),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = cks.parameters().message_modulus().0;
+    let bits_in_block = message_modulus.ilog2();
+    let width = bits_in_block * NB_CTXT as u32;
+    // message_modulus^vec_length
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    // Bitwise CRC, one data bit at a time, most significant first
+    let clear_crc_step = |mut crc: u64, data: u64, poly: u64| {
+        for i in (0..bits_in_block).rev() {
+            let top_bit = ((crc >> (width - 1)) ^ (data >> i)) & 1;
+            crc = (crc << 1) % modulus;
+            if top_bit == 1 {
+                crc ^= poly;
+            }
+        }
+        crc
+    };
+
+    for _ in 0..nb_tests {
+        let poly = rng.gen::<u64>() % modulus;
+        let mut clear_crc = rng.gen::<u64>() % modulus;
+        let mut ctxt_crc = cks.encrypt(clear_crc);
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen::<u64>() % modulus;
+        sks.unchecked_scalar_add_assign(&mut ctxt_crc, clear_2);
+        clear_crc = (clear_crc + clear_2) % modulus;
+
+        for _ in 0..3 {
+            let clear_data = rng.gen::<u64>() % message_modulus;
+            let clear_condition = rng.gen_bool(0.5);
+
+            let ctxt_data = cks.encrypt_one_block(clear_data);
+            let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+            executor.execute((&mut ctxt_crc, &ctxt_data, poly, &ctxt_condition));
+            assert!(ctxt_crc.block_carries_are_empty());
+
+            let previous_crc = clear_crc;
+            if clear_condition {
+                clear_crc = clear_crc_step(clear_crc, clear_data, poly);
+            }
+            let dec_res: u64 = cks.decrypt(&ctxt_crc);
+            assert_eq!(
+                dec_res, clear_crc,
+                "Invalid CRC step of {previous_crc:#x} with data {clear_data}, \
+                poly {poly:#x} and condition {clear_condition}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_conditional_fma_scalar_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a mut RadixCiphertext,
+            &'a RadixCiphertext,
+            u64,
+            &'a BooleanBlock,
+        ),
+        (),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let mut clear_acc = rng.gen::<u64>() % modulus;
+        let mut acc = cks.encrypt(clear_acc);
+
+        // Chain enough steps so that the carry space of acc gets exhausted
+        let chain_len = rng.gen_range(1..=8);
+        for _ in 0..chain_len {
+            let clear_x = rng.gen::<u64>() % modulus;
+            let a = rng.gen::<u64>() % modulus;
+            let clear_condition = rng.gen_bool(0.5);
+
+            let mut ctxt_x = cks.encrypt(clear_x);
+            let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+            // Add to have non empty carries
+            let clear_2 = rng.gen::<u64>() % modulus;
+            sks.unchecked_scalar_add_assign(&mut ctxt_x, clear_2);
+            let clear_x = (clear_x + clear_2) % modulus;
+
+            executor.execute((&mut acc, &ctxt_x, a, &ctxt_condition));
+
+            if clear_condition {
+                clear_acc = clear_acc.wrapping_add(a.wrapping_mul(clear_x)) % modulus;
+            }
+
+            let mut propagated = acc.clone();
+            sks.full_propagate_parallelized(&mut propagated);
+            let dec_acc: u64 = cks.decrypt(&propagated);
+            assert_eq!(
+                dec_acc, clear_acc,
+                "Invalid result for acc + {a} * {clear_x} with condition {clear_condition}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_conditional_fma_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a mut RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a BooleanBlock,
+        ),
+        (),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let mut clear_acc = rng.gen::<u64>() % modulus;
+        let mut acc = cks.encrypt(clear_acc);
+
+        // Chain enough steps so that the carry space of acc gets exhausted
+        let chain_len = rng.gen_range(1..=8);
+        for _ in 0..chain_len {
+            let clear_x = rng.gen::<u64>() % modulus;
+            let clear_a = rng.gen::<u64>() % modulus;
+            let clear_condition = rng.gen_bool(0.5);
+
+            let ctxt_x = cks.encrypt(clear_x);
+            let mut ctxt_a = cks.encrypt(clear_a);
+            let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+            // Add to have non empty carries
+            let clear_2 = rng.gen::<u64>() % modulus;
+            sks.unchecked_scalar_add_assign(&mut ctxt_a, clear_2);
+            let clear_a = (clear_a + clear_2) % modulus;
+
+            executor.execute((&mut acc, &ctxt_x, &ctxt_a, &ctxt_condition));
+
+            if clear_condition {
+                clear_acc = clear_acc.wrapping_add(clear_a.wrapping_mul(clear_x)) % modulus;
+            }
+
+            let mut propagated = acc.clone();
+            sks.full_propagate_parallelized(&mut propagated);
+            let dec_acc: u64 = cks.decrypt(&propagated);
+            assert_eq!(
+                dec_acc, clear_acc,
+                "Invalid result for acc + {clear_a} * {clear_x} with condition {clear_condition}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_if_then_else_assign_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a mut RadixCiphertext,
+            &'a BooleanBlock,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        (),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    let mut ctxt_dst = cks.encrypt(rng.gen::<u64>() % modulus);
+
+    for _ in 0..nb_tests {
+        let clear_true = rng.gen::<u64>() % modulus;
+        let clear_false = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut ctxt_true = cks.encrypt(clear_true);
+        let mut ctxt_false = cks.encrypt(clear_false);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        // dst is reused from the previous iteration
+        executor.execute((&mut ctxt_dst, &ctxt_condition, &ctxt_true, &ctxt_false));
+        assert!(ctxt_dst.block_carries_are_empty());
+        assert_eq!(ctxt_dst.blocks.len(), NB_CTXT);
+
+        let dec_res: u64 = cks.decrypt(&ctxt_dst);
+        let expected = if clear_condition {
+            clear_true
+        } else {
+            clear_false
+        };
+        assert_eq!(dec_res, expected);
+
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let clear_3 = rng.gen::<u64>() % modulus;
+
+        let ctxt_2 = cks.encrypt(clear_2);
+        let ctxt_3 = cks.encrypt(clear_3);
+
+        // Add to have non empty carries
+        sks.unchecked_add_assign(&mut ctxt_true, &ctxt_2);
+        sks.unchecked_add_assign(&mut ctxt_false, &ctxt_3);
+        assert!(!ctxt_true.block_carries_are_empty());
+        assert!(!ctxt_false.block_carries_are_empty());
+
+        executor.execute((&mut ctxt_dst, &ctxt_condition, &ctxt_true, &ctxt_false));
+        assert!(ctxt_dst.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ctxt_dst);
+        let expected = if clear_condition {
+            (clear_true + clear_2) % modulus
+        } else {
+            (clear_false + clear_3) % modulus
+        };
+        assert_eq!(dec_res, expected);
+    }
+
+    // dst with a different number of blocks than the branches
+    for dst_num_blocks in [1, NB_CTXT + 1] {
+        let clear_true = rng.gen::<u64>() % modulus;
+        let clear_false = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let ctxt_true = cks.encrypt(clear_true);
+        let ctxt_false = cks.encrypt(clear_false);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        let mut ctxt_dst = sks.create_trivial_zero_radix(dst_num_blocks);
+        executor.execute((&mut ctxt_dst, &ctxt_condition, &ctxt_true, &ctxt_false));
+        assert!(ctxt_dst.block_carries_are_empty());
+        assert_eq!(ctxt_dst.blocks.len(), NB_CTXT);
+
+        let dec_res: u64 = cks.decrypt(&ctxt_dst);
+        let expected = if clear_condition {
+            clear_true
+        } else {
+            clear_false
+        };
+        assert_eq!(dec_res, expected);
+    }
+
+    // Some test with trivial condition
+    for clear_condition in [false, true] {
+        let clear_true = rng.gen::<u64>() % modulus;
+        let clear_false = rng.gen::<u64>() % modulus;
+
+        let ctxt_true = cks.encrypt(clear_true);
+        let ctxt_false = cks.encrypt(clear_false);
+        let condition = sks.create_trivial_boolean_block(clear_condition);
+
+        executor.execute((&mut ctxt_dst, &condition, &ctxt_true, &ctxt_false));
+        assert!(ctxt_dst.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ctxt_dst);
+        let expected = if clear_condition {
+            clear_true
+        } else {
+            clear_false
+        };
+        assert_eq!(dec_res, expected);
+    }
+}
+
+pub(crate) fn default_select_valid_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext, u64), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let sentinel = rng.gen::<u64>() % modulus;
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        for (clear_a, clear_b) in [
+            (clear_0, clear_1),
+            (sentinel, clear_1),
+            (clear_0, sentinel),
+            (sentinel, sentinel),
+        ] {
+            // Dirty a, so that the propagation is also tested
+            let mut ctxt_a = cks.encrypt(clear_a / 2);
+            sks.unchecked_add_assign(&mut ctxt_a, &cks.encrypt(clear_a - clear_a / 2));
+            let ctxt_b = cks.encrypt(clear_b);
+
+            let ct_res = executor.execute((&ctxt_a, &ctxt_b, sentinel));
+            assert!(ct_res.block_carries_are_empty());
+
+            let expected = if clear_a == sentinel {
+                clear_b
+            } else {
+                clear_a
+            };
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec_res, expected,
+                "Invalid select_valid result for a: {clear_a}, b: {clear_b}, sentinel: {sentinel}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_if_then_else_carry_save_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a [BooleanBlock],
+            &'a [RadixCiphertext],
+            &'a [RadixCiphertext],
+        ),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let clear_trues = (0..4)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let clear_falses = (0..4)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let clear_conditions = (0..4).map(|_| rng.gen_bool(0.5)).collect::<Vec<_>>();
+
+        let true_cts = clear_trues
+            .iter()
+            .map(|v| cks.encrypt(*v))
+            .collect::<Vec<_>>();
+        // Dirty the false branches, so that the propagation is also tested
+        let false_cts = clear_falses
+            .iter()
+            .map(|v| {
+                let mut ct = cks.encrypt(*v / 2);
+                sks.unchecked_add_assign(&mut ct, &cks.encrypt(*v - *v / 2));
+                ct
+            })
+            .collect::<Vec<_>>();
+        let conditions = clear_conditions
+            .iter()
+            .map(|c| cks.encrypt_bool(*c))
+            .collect::<Vec<_>>();
+
+        let ct_res = executor.execute((&conditions, &true_cts, &false_cts));
+        assert!(ct_res.block_carries_are_empty());
+
+        let expected = clear_conditions
+            .iter()
+            .zip(clear_trues.iter().zip(clear_falses.iter()))
+            .map(|(c, (t, f))| if *c { *t } else { *f })
+            .sum::<u64>()
+            % modulus;
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res, expected,
+            "Invalid carry-save sum for trues: {clear_trues:?}, falses: {clear_falses:?}, \
+            conditions: {clear_conditions:?}"
+        );
+
+        // With clean inputs, a selection only costs the 2 zeroing PBS per block. When the
+        // parameters can hold the sum of two selected values (each block of a selected value
+        // has a degree of 2 * (message_modulus - 1) and a noise level of 2), the addition does
+        // not propagate either, the only propagation is the final one.
+        #[cfg(feature = "pbs-stats")]
+        {
+            let message_modulus = cks.parameters().message_modulus().0;
+            if 4 * (message_modulus - 1) <= sks.key.max_degree.get()
+                && sks.key.max_noise_level.get() >= 4
+            {
+                crate::reset_pbs_count();
+                let mut sum = sks.if_then_else_carry_save_parallelized(
+                    &conditions[0],
+                    &true_cts[0],
+                    &true_cts[1],
+                );
+                let selected = sks.if_then_else_carry_save_parallelized(
+                    &conditions[1],
+                    &true_cts[2],
+                    &true_cts[3],
+                );
+                sks.carry_save_add_assign_parallelized(&mut sum, &selected);
+                let selection_pbs_count = crate::get_pbs_count();
+                assert_eq!(
+                    selection_pbs_count,
+                    2 * 2 * NB_CTXT as u64,
+                    "The carry-save selections and their sum should only do the zeroing PBS"
+                );
+
+                let ct_res = sks.resolve_carry_save(sum);
+                assert!(ct_res.block_carries_are_empty());
+
+                let select = |c: bool, t: u64, f: u64| if c { t } else { f };
+                let expected = (select(clear_conditions[0], clear_trues[0], clear_trues[1])
+                    + select(clear_conditions[1], clear_trues[2], clear_trues[3]))
+                    % modulus;
+                let dec_res: u64 = cks.decrypt(&ct_res);
+                assert_eq!(dec_res, expected);
+            }
+        }
+    }
+}
+
+pub(crate) fn default_broadcast_condition_to_blocks_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a BooleanBlock, usize), Vec<crate::shortint::Ciphertext>>,
+{
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks.clone());
+
+    for clear_condition in [false, true] {
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        for n_blocks in [0, 1, NB_CTXT] {
+            let mut masks = executor.execute((&ctxt_condition, n_blocks));
+            assert_eq!(masks.len(), n_blocks);
+            for mask in &masks {
+                assert_eq!(mask.degree, ctxt_condition.0.degree);
+                assert_eq!(cks.decrypt_one_block(mask), u64::from(clear_condition));
+            }
+
+            // The copies are independent
+            if let Some(first) = masks.first_mut() {
+                sks.key.scalar_add_assign(first, 1);
+                for mask in masks.iter().skip(1) {
+                    assert_eq!(cks.decrypt_one_block(mask), u64::from(clear_condition));
+                }
+                assert_eq!(cks.decrypt_bool(&ctxt_condition), clear_condition);
+            }
+        }
+    }
+}
+
+pub(crate) fn default_conditional_unpack_field_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a crate::shortint::Ciphertext, u32, u64, &'a BooleanBlock),
+        crate::shortint::Ciphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    let block_modulus = cks.parameters().message_modulus().0;
+    let num_bits_in_block = block_modulus.ilog2();
+
+    executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests {
+        let clear_packed = rng.gen::<u64>() % block_modulus;
+        let packed = cks.encrypt_one_block(clear_packed);
+
+        for field_shift in 0..num_bits_in_block {
+            let max_field_mask = (block_modulus - 1) >> field_shift;
+            let field_mask = rng.gen_range(0..=max_field_mask);
+            let clear_condition = rng.gen_bool(0.5);
+            let condition = cks.encrypt_bool(clear_condition);
+
+            let ct_res = executor.execute((&packed, field_shift, field_mask, &condition));
+            assert!(ct_res.carry_is_empty());
+
+            let expected = if clear_condition {
+                (clear_packed >> field_shift) & field_mask
+            } else {
+                0
+            };
+            assert_eq!(
+                cks.decrypt_one_block(&ct_res),
+                expected,
+                "Invalid result for conditional_unpack_field, packed: {clear_packed:#b}, \
+                shift: {field_shift}, mask: {field_mask:#b}, condition: {clear_condition}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_apply_mask_matrix_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a mut [Vec<RadixCiphertext>], &'a [Vec<BooleanBlock>], i64), ()>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    let (num_rows, num_cols) = (2, 3);
+
+    for _ in 0..nb_tests {
+        let clear_scores = (0..num_rows)
+            .map(|_| {
+                (0..num_cols)
+                    .map(|_| rng.gen::<u64>() % modulus)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let clear_mask = (0..num_rows)
+            .map(|_| (0..num_cols).map(|_| rng.gen_bool(0.5)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        // Negative values are encoded in two's complement on the width of the scores
+        let masked_value = rng.gen_range(-(modulus as i64 / 2)..(modulus as i64 / 2));
+
+        let mut scores = clear_scores
+            .iter()
+            .map(|row| row.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let mask = clear_mask
+            .iter()
+            .map(|row| row.iter().map(|m| cks.encrypt_bool(*m)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        // Add to have non empty carries
+        let clear_0 = rng.gen::<u64>() % modulus;
+        sks.unchecked_scalar_add_assign(&mut scores[0][0], clear_0);
+        let mut clear_scores = clear_scores;
+        clear_scores[0][0] = (clear_scores[0][0] + clear_0) % modulus;
+
+        executor.execute((&mut scores, &mask, masked_value));
+
+        for (i, row) in scores.iter().enumerate() {
+            for (j, score) in row.iter().enumerate() {
+                assert!(score.block_carries_are_empty());
+                let expected = if clear_mask[i][j] {
+                    clear_scores[i][j]
+                } else {
+                    (masked_value as u64) % modulus
+                };
+                let dec: u64 = cks.decrypt(score);
+                assert_eq!(
+                    dec, expected,
+                    "Invalid score at ({i}, {j}), mask: {}, masked_value: {masked_value}",
+                    clear_mask[i][j]
+                );
+            }
+        }
+    }
+}
@@ -3,7 +3,9 @@ use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
-use crate::integer::server_key::radix_parallel::tests_unsigned::{CpuFunctionExecutor, NB_CTXT};
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus, CpuFunctionExecutor, NB_CTXT,
+};
 use crate::integer::tests::create_parameterized_test;
 use crate::integer::{BooleanBlock, IntegerKeyKind, RadixClientKey, ServerKey, U256};
 #[cfg(tarpaulin)]
@@ -892,3 +894,430 @@ pub(crate) fn extensive_trivial_default_comparisons_test<P, E1, E2, E3, E4, E5,
         }
     }
 }
+
+// The three-way comparison requires at least 4 bits of space per block
+create_parameterized_test!(integer_default_compare_three_way {
+    coverage => {
+        COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        COVERAGE_PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+    },
+    no_coverage => {
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+        TEST_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+        // 2M128 is too slow for 4_4, it is estimated to be 2x slower
+        TEST_PARAM_MESSAGE_4_CARRY_4_KS_PBS_GAUSSIAN_2M64,
+        TEST_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+        TEST_PARAM_MULTI_BIT_GROUP_2_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64,
+        TEST_PARAM_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+        TEST_PARAM_MULTI_BIT_GROUP_3_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64
+    }
+});
+
+fn integer_default_compare_three_way<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::compare_three_way_parallelized);
+    default_compare_three_way_test(param, executor);
+}
+
+create_parameterized_test!(integer_default_merge_step);
+
+fn integer_default_merge_step<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::merge_step_parallelized);
+    default_merge_step_test(param, executor);
+}
+
+create_parameterized_test!(integer_default_top2);
+
+fn integer_default_top2<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey, values: &Vec<RadixCiphertext>| sks.top2_parallelized(values);
+    let executor = CpuFunctionExecutor::new(&func);
+    default_top2_test(param, executor);
+}
+
+create_parameterized_test!(integer_default_heap_siftdown_step);
+
+fn integer_default_heap_siftdown_step<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func =
+        |sks: &ServerKey,
+         heap: &mut [RadixCiphertext],
+         parent: usize,
+         left: usize,
+         right: usize| { sks.heap_siftdown_step_parallelized(heap, parent, left, right) };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_heap_siftdown_step_test(param, executor);
+}
+
+create_parameterized_test!(integer_default_max_and_absdiff);
+
+fn integer_default_max_and_absdiff<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey, lhs: &RadixCiphertext, rhs: &RadixCiphertext| {
+        sks.max_and_absdiff_parallelized(lhs, rhs)
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_max_and_absdiff_test(param, executor);
+}
+
+pub(crate) fn default_compare_three_way_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        // Make sure the equal case is tested too
+        let clear_1 = if rng.gen_bool(0.3) {
+            clear_0
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ordering = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ordering.block_carries_are_empty());
+
+        let expected = match clear_0.cmp(&clear_1) {
+            std::cmp::Ordering::Less => 0,
+            std::cmp::Ordering::Equal => 1,
+            std::cmp::Ordering::Greater => 2,
+        };
+        let dec: u64 = cks.decrypt(&ordering);
+        assert_eq!(
+            dec, expected,
+            "Invalid three way comparison of {clear_0} and {clear_1}"
+        );
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let ctxt_2 = cks.encrypt(clear_2);
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+        let clear_0 = (clear_0 + clear_2) % modulus;
+
+        let ordering = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ordering.block_carries_are_empty());
+
+        let expected = match clear_0.cmp(&clear_1) {
+            std::cmp::Ordering::Less => 0,
+            std::cmp::Ordering::Equal => 1,
+            std::cmp::Ordering::Greater => 2,
+        };
+        let dec: u64 = cks.decrypt(&ordering);
+        assert_eq!(
+            dec, expected,
+            "Invalid three way comparison of {clear_0} and {clear_1}"
+        );
+    }
+}
+
+pub(crate) fn default_merge_step_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, &'a RadixCiphertext),
+        (RadixCiphertext, RadixCiphertext, BooleanBlock),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        // Make sure the equal case is tested too
+        let clear_1 = if rng.gen_bool(0.3) {
+            clear_0
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (min, max, a_le_b) = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(min.block_carries_are_empty());
+        assert!(max.block_carries_are_empty());
+
+        let dec_min: u64 = cks.decrypt(&min);
+        let dec_max: u64 = cks.decrypt(&max);
+        let dec_a_le_b = cks.decrypt_bool(&a_le_b);
+        assert_eq!(
+            (dec_min, dec_max, dec_a_le_b),
+            (
+                clear_0.min(clear_1),
+                clear_0.max(clear_1),
+                clear_0 <= clear_1
+            ),
+            "Invalid merge step of {clear_0} and {clear_1}"
+        );
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let ctxt_2 = cks.encrypt(clear_2);
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_2);
+        let clear_0 = (clear_0 + clear_2) % modulus;
+
+        let (min, max, a_le_b) = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(min.block_carries_are_empty());
+        assert!(max.block_carries_are_empty());
+
+        let dec_min: u64 = cks.decrypt(&min);
+        let dec_max: u64 = cks.decrypt(&max);
+        let dec_a_le_b = cks.decrypt_bool(&a_le_b);
+        assert_eq!(
+            (dec_min, dec_max, dec_a_le_b),
+            (
+                clear_0.min(clear_1),
+                clear_0.max(clear_1),
+                clear_0 <= clear_1
+            ),
+            "Invalid merge step of {clear_0} and {clear_1}"
+        );
+    }
+
+    // Stability: merge two sorted runs with many ties, advancing the run given by
+    // the returned flag, elements of the first run must come first among equal elements
+    let run_a = [0u64, 1, 1, 2];
+    let run_b = [1u64, 1, 2, 3];
+    let enc_a = run_a.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    let enc_b = run_b.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+
+    let (mut i, mut j) = (0, 0);
+    let mut merged = Vec::with_capacity(run_a.len() + run_b.len());
+    while i < run_a.len() && j < run_b.len() {
+        let (min, _, a_le_b) = executor.execute((&enc_a[i], &enc_b[j]));
+        let dec_min: u64 = cks.decrypt(&min);
+        if cks.decrypt_bool(&a_le_b) {
+            assert_eq!(dec_min, run_a[i]);
+            merged.push((dec_min, 'a', i));
+            i += 1;
+        } else {
+            assert_eq!(dec_min, run_b[j]);
+            merged.push((dec_min, 'b', j));
+            j += 1;
+        }
+    }
+    merged.extend(run_a[i..].iter().enumerate().map(|(k, v)| (*v, 'a', i + k)));
+    merged.extend(run_b[j..].iter().enumerate().map(|(k, v)| (*v, 'b', j + k)));
+
+    let mut expected = run_a
+        .iter()
+        .enumerate()
+        .map(|(k, v)| (*v, 'a', k))
+        .chain(run_b.iter().enumerate().map(|(k, v)| (*v, 'b', k)))
+        .collect::<Vec<_>>();
+    // sort_by_key is stable
+    expected.sort_by_key(|(v, _, _)| *v);
+    assert_eq!(merged, expected, "The merge is not stable");
+}
+
+pub(crate) fn default_top2_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a Vec<RadixCiphertext>, (RadixCiphertext, RadixCiphertext)>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let len = rng.gen_range(1..=6usize);
+        let mut clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        // Make sure the case where the maximum is duplicated is tested too
+        if len >= 2 && rng.gen_bool(0.3) {
+            let max = *clears.iter().max().unwrap();
+            let index = rng.gen_range(0..len);
+            clears[index] = max;
+        }
+
+        let mut values = clears.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+        // Add to have non empty carries on one of the values
+        let index = rng.gen_range(0..len);
+        let clear_to_add = rng.gen::<u64>() % modulus;
+        sks.unchecked_scalar_add_assign(&mut values[index], clear_to_add);
+        clears[index] = (clears[index] + clear_to_add) % modulus;
+
+        let (ct_max, ct_second) = executor.execute(&values);
+        assert!(ct_max.block_carries_are_empty());
+        assert!(ct_second.block_carries_are_empty());
+
+        let mut sorted = clears.clone();
+        sorted.sort_unstable_by(|a, b| b.cmp(a));
+        let expected_max = sorted[0];
+        // With a single value, the second is the minimum value
+        let expected_second = sorted.get(1).copied().unwrap_or(0);
+
+        let dec_max: u64 = cks.decrypt(&ct_max);
+        let dec_second: u64 = cks.decrypt(&ct_second);
+        assert_eq!(dec_max, expected_max, "Invalid max of {clears:?}");
+        assert_eq!(dec_second, expected_second, "Invalid second of {clears:?}");
+    }
+}
+
+pub(crate) fn default_max_and_absdiff_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, &'a RadixCiphertext),
+        (RadixCiphertext, RadixCiphertext),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let mut clear_a = rng.gen::<u64>() % modulus;
+        let clear_b = if rng.gen_bool(0.2) {
+            clear_a
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let mut ct_a = cks.encrypt(clear_a);
+        let ct_b = cks.encrypt(clear_b);
+
+        // Add to have non empty carries
+        let clear_to_add = rng.gen::<u64>() % modulus;
+        sks.unchecked_scalar_add_assign(&mut ct_a, clear_to_add);
+        clear_a = (clear_a + clear_to_add) % modulus;
+
+        let (ct_max, ct_diff) = executor.execute((&ct_a, &ct_b));
+        assert!(ct_max.block_carries_are_empty());
+        assert!(ct_diff.block_carries_are_empty());
+
+        let dec_max: u64 = cks.decrypt(&ct_max);
+        let dec_diff: u64 = cks.decrypt(&ct_diff);
+        assert_eq!(
+            dec_max,
+            clear_a.max(clear_b),
+            "Invalid max of {clear_a} and {clear_b}"
+        );
+        assert_eq!(
+            dec_diff,
+            clear_a.abs_diff(clear_b),
+            "Invalid absolute difference of {clear_a} and {clear_b}"
+        );
+    }
+}
+
+pub(crate) fn default_heap_siftdown_step_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a mut [RadixCiphertext], usize, usize, usize), ()>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        // Even lengths make the last internal node have no right child
+        let len = rng.gen_range(2..=7usize);
+        let parent = rng.gen_range(0..=(len - 2) / 2);
+        let left = 2 * parent + 1;
+        let right = 2 * parent + 2;
+
+        let mut clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        // Make sure ties are tested too
+        if rng.gen_bool(0.3) {
+            let index = if right < len && rng.gen_bool(0.5) {
+                right
+            } else {
+                parent
+            };
+            clears[index] = clears[left];
+        }
+
+        let mut heap = clears.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+        // Add to have non empty carries on one of the involved values
+        let index = [parent, left, right.min(left)][rng.gen_range(0..3)];
+        let clear_to_add = rng.gen::<u64>() % modulus;
+        sks.unchecked_scalar_add_assign(&mut heap[index], clear_to_add);
+        clears[index] = (clears[index] + clear_to_add) % modulus;
+
+        let mut expected = clears.clone();
+        let smallest = if right < len && expected[right] < expected[left] {
+            right
+        } else {
+            left
+        };
+        if expected[smallest] < expected[parent] {
+            expected.swap(smallest, parent);
+        }
+
+        executor.execute((heap.as_mut_slice(), parent, left, right));
+
+        for (i, ct) in heap.iter().enumerate() {
+            if i == parent || i == left || i == right {
+                assert!(ct.block_carries_are_empty());
+            }
+            let dec: u64 = cks.decrypt(ct);
+            assert_eq!(
+                dec, expected[i],
+                "Invalid value at position {i} after sift-down step of {parent} in {clears:?}"
+            );
+        }
+    }
+}
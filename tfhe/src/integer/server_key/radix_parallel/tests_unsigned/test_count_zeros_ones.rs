@@ -12,6 +12,7 @@ use std::sync::Arc;
 
 create_parameterized_test!(integer_extensive_trivial_default_count_zeros_ones);
 create_parameterized_test!(integer_default_count_zeros_ones);
+create_parameterized_test!(integer_default_select_if_popcount_ge);
 
 fn integer_extensive_trivial_default_count_zeros_ones<P>(param: P)
 where
@@ -35,6 +36,15 @@ where
     default_count_zeros_ones_test(param, count_zeros_executor, count_ones_executor);
 }
 
+fn integer_default_select_if_popcount_ge<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::select_if_popcount_ge_parallelized::<RadixCiphertext>);
+    default_select_if_popcount_ge_test(param, executor);
+}
+
 pub(crate) fn default_count_zeros_ones_test<P, E1, E2>(
     param: P,
     mut count_zeros_executor: E1,
@@ -150,3 +160,65 @@ pub(crate) fn extensive_trivial_default_count_zeros_ones_test<P, E1, E2>(
         }
     }
 }
+
+pub(crate) fn default_select_if_popcount_ge_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a RadixCiphertext,
+            u32,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    executor.setup(&cks, sks);
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+    let num_bits = modulus.ilog2();
+
+    let clear_a = rng.gen::<u64>() % modulus;
+    let clear_b = rng.gen::<u64>() % modulus;
+    let ctxt_a = cks.encrypt(clear_a);
+    let ctxt_b = cks.encrypt(clear_b);
+
+    let patterns = [
+        0u64,
+        modulus - 1,
+        0x5555_5555_5555_5555 % modulus,
+        1 << (num_bits - 1),
+        rng.gen::<u64>() % modulus,
+    ];
+
+    for clear in patterns {
+        let ctxt = cks.encrypt(clear);
+        let popcount = clear.count_ones();
+
+        for threshold in [0, popcount.saturating_sub(1), popcount, popcount + 1] {
+            let ct_res = executor.execute((&ctxt, threshold, &ctxt_a, &ctxt_b));
+            assert!(ct_res.block_carries_are_empty());
+
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            let expected = if popcount >= threshold {
+                clear_a
+            } else {
+                clear_b
+            };
+            assert_eq!(
+                dec_res, expected,
+                "Invalid select_if_popcount_ge for input {clear:#b} and threshold {threshold}"
+            );
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crate::integer::server_key::radix_parallel::tests_unsigned::{
     nb_tests_smaller_for_params, CpuFunctionExecutor, NB_CTXT,
 };
 use crate::integer::tests::create_parameterized_test;
-use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+use crate::integer::{BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::test_params::*;
@@ -132,6 +132,26 @@ create_parameterized_test!(
         }
     }
 );
+create_parameterized_test!(
+    integer_default_conditional_reduce_fraction_step {
+        coverage => {
+            COVERAGE_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            COVERAGE_PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+        },
+        no_coverage => {
+            // Due to the use of comparison,
+            // this algorithm requires 3 bits
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128,
+            TEST_PARAM_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M128,
+            // 2M128 is too slow for 4_4, it is estimated to be 2x slower
+            TEST_PARAM_MESSAGE_4_CARRY_4_KS_PBS_GAUSSIAN_2M64,
+            TEST_PARAM_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+            TEST_PARAM_MULTI_BIT_GROUP_2_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64,
+            TEST_PARAM_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+            TEST_PARAM_MULTI_BIT_GROUP_3_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64,
+        }
+    }
+);
 
 fn integer_smart_div_rem<P>(param: P)
 where
@@ -181,6 +201,15 @@ where
     default_rem_test(param, executor);
 }
 
+fn integer_default_conditional_reduce_fraction_step<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::conditional_reduce_fraction_step_parallelized);
+    default_conditional_reduce_fraction_step_test(param, executor);
+}
+
 pub(crate) fn default_div_rem_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
@@ -463,3 +492,68 @@ where
         assert_eq!(clear_0 % clear_1, q);
     }
 }
+
+pub(crate) fn default_conditional_reduce_fraction_step_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a mut RadixCiphertext,
+            &'a mut RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a BooleanBlock,
+        ),
+        (),
+    >,
+{
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests_smaller {
+        let clear_g = rng.gen_range(1..modulus); // avoid division by zero
+                                                 // Half of the time, num and den are multiples of g
+        let (clear_num, clear_den) = if rng.gen_bool(0.5) {
+            let max_factor = (modulus - 1) / clear_g + 1;
+            (
+                rng.gen_range(0..max_factor) * clear_g,
+                rng.gen_range(0..max_factor) * clear_g,
+            )
+        } else {
+            (rng.gen_range(0..modulus), rng.gen_range(0..modulus))
+        };
+        let clear_condition = clear_num % clear_g == 0 && clear_den % clear_g == 0;
+
+        let mut ctxt_num = cks.encrypt(clear_num);
+        let mut ctxt_den = cks.encrypt(clear_den);
+        let ctxt_g = cks.encrypt(clear_g);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        executor.execute((&mut ctxt_num, &mut ctxt_den, &ctxt_g, &ctxt_condition));
+        assert!(ctxt_num.block_carries_are_empty());
+        assert!(ctxt_den.block_carries_are_empty());
+
+        let expected = if clear_condition {
+            (clear_num / clear_g, clear_den / clear_g)
+        } else {
+            (clear_num, clear_den)
+        };
+        let dec_num: u64 = cks.decrypt(&ctxt_num);
+        let dec_den: u64 = cks.decrypt(&ctxt_den);
+        assert_eq!(
+            (dec_num, dec_den),
+            expected,
+            "Invalid reduction of {clear_num}/{clear_den} by {clear_g}, condition: {clear_condition}"
+        );
+    }
+}
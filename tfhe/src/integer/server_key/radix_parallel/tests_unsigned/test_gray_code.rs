@@ -0,0 +1,95 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus, CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::test_params::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_default_gray_increment);
+
+fn integer_default_gray_increment<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::gray_increment_parallelized);
+    default_gray_increment_test(param, executor);
+}
+
+fn to_gray(value: u64) -> u64 {
+    value ^ (value >> 1)
+}
+
+pub(crate) fn default_gray_increment_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a RadixCiphertext, RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests_smaller {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let mut ctxt = cks.encrypt(to_gray(clear));
+
+        let ct_res = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            to_gray((clear + 1) % modulus),
+            "Invalid gray increment of {clear}"
+        );
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let ctxt_2 = cks.encrypt(clear_2);
+        sks.unchecked_add_assign(&mut ctxt, &ctxt_2);
+        assert!(!ctxt.block_carries_are_empty());
+
+        let gray = (to_gray(clear) + clear_2) % modulus;
+        // Convert back from Gray code
+        let mut binary = gray;
+        let mut shift = 1;
+        while shift < u64::BITS {
+            binary ^= binary >> shift;
+            shift *= 2;
+        }
+
+        let ct_res = executor.execute(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, to_gray((binary + 1) % modulus));
+    }
+
+    // Cycle through all the values of a counter of at least 3 bits,
+    // and check that it wraps around
+    let bits_in_block = cks.parameters().message_modulus().0.ilog2();
+    let num_blocks = 3u32.div_ceil(bits_in_block) as usize;
+    let counter_modulus = 1u64 << (bits_in_block * num_blocks as u32);
+
+    let mut ctxt = cks.as_ref().encrypt_radix(0u64, num_blocks);
+    for i in 1..=counter_modulus {
+        ctxt = executor.execute(&ctxt);
+        assert!(ctxt.block_carries_are_empty());
+        let dec_res: u64 = cks.as_ref().decrypt_radix(&ctxt);
+        assert_eq!(dec_res, to_gray(i % counter_modulus));
+    }
+}
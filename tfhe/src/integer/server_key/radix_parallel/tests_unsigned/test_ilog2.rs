@@ -29,6 +29,7 @@ create_parameterized_test!(integer_default_checked_ilog2 {
     TEST_PARAM_MULTI_BIT_GROUP_2_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64,
     TEST_PARAM_MULTI_BIT_GROUP_3_MESSAGE_3_CARRY_3_KS_PBS_GAUSSIAN_2M64
 });
+create_parameterized_test!(integer_default_select_if_leading_zeros_ge);
 
 fn integer_default_trailing_zeros<P>(param: P)
 where
@@ -78,6 +79,14 @@ where
     default_checked_ilog2_test(param, executor);
 }
 
+fn integer_default_select_if_leading_zeros_ge<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::select_if_leading_zeros_ge_parallelized);
+    default_select_if_leading_zeros_ge_test(param, executor);
+}
+
 pub(crate) fn default_count_consecutive_bits_test<P, T>(
     direction: Direction,
     bit_value: BitValue,
@@ -503,3 +512,61 @@ where
         assert!(is_ok);
     }
 }
+
+pub(crate) fn default_select_if_leading_zeros_ge_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a RadixCiphertext,
+            u32,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks);
+
+    let num_bits = NB_CTXT as u32 * cks.parameters().message_modulus().0.ilog2();
+
+    for _ in 0..nb_tests_smaller {
+        // Use a random number of leading zeros
+        let clear = rng.gen::<u64>() % modulus;
+        let clear = clear >> rng.gen_range(0..=num_bits);
+        let clear_a = rng.gen::<u64>() % modulus;
+        let clear_b = rng.gen::<u64>() % modulus;
+        let threshold = rng.gen_range(0..=num_bits + 1);
+
+        let ctxt = cks.encrypt(clear);
+        let ctxt_a = cks.encrypt(clear_a);
+        let ctxt_b = cks.encrypt(clear_b);
+
+        let ct_res = executor.execute((&ctxt, threshold, &ctxt_a, &ctxt_b));
+        assert!(ct_res.block_carries_are_empty());
+
+        let leading_zeros = clear.leading_zeros() - (u64::BITS - num_bits);
+        let expected = if leading_zeros >= threshold {
+            clear_a
+        } else {
+            clear_b
+        };
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res, expected,
+            "Invalid result for select_if_leading_zeros_ge, input: {clear}, threshold: {threshold}"
+        );
+    }
+}
@@ -2,10 +2,10 @@ use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
 use crate::integer::server_key::radix_parallel::tests_unsigned::{
-    nb_tests_for_params, CpuFunctionExecutor,
+    nb_tests_for_params, nb_tests_smaller_for_params, CpuFunctionExecutor,
 };
 use crate::integer::tests::create_parameterized_test;
-use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey};
+use crate::integer::{BooleanBlock, IntegerKeyKind, RadixClientKey, ServerKey};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::test_params::*;
@@ -14,6 +14,8 @@ use rand::prelude::*;
 use std::sync::Arc;
 
 create_parameterized_test!(integer_default_scalar_div_rem);
+create_parameterized_test!(integer_default_conditional_scalar_div);
+create_parameterized_test!(integer_default_div_scalar_rounded);
 
 fn integer_default_scalar_div_rem<P>(param: P)
 where
@@ -23,6 +25,22 @@ where
     default_scalar_div_rem_test(param, executor);
 }
 
+fn integer_default_conditional_scalar_div<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_scalar_div_parallelized);
+    default_conditional_scalar_div_test(param, executor);
+}
+
+fn integer_default_div_scalar_rounded<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::div_scalar_rounded_parallelized);
+    default_div_scalar_rounded_test(param, executor);
+}
+
 pub(crate) fn default_scalar_div_rem_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
@@ -115,3 +133,132 @@ where
     });
     assert!(result.is_err(), "division by zero should panic");
 }
+
+pub(crate) fn default_conditional_scalar_div_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, u64, &'a BooleanBlock), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let num_block =
+        (32f64 / (cks.parameters().message_modulus().0 as f64).log(2.0)).ceil() as usize;
+
+    let cks = RadixClientKey::from((cks, num_block));
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(num_block as u32);
+
+    executor.setup(&cks, sks);
+
+    for _ in 0..nb_tests {
+        let clear = rng.gen::<u64>() % modulus;
+        let scalar = rng.gen_range(1u32..=u32::MAX) as u64;
+        let clear_condition = rng.gen_bool(0.5);
+
+        let ct = cks.encrypt(clear);
+        let condition = cks.encrypt_bool(clear_condition);
+
+        let result = executor.execute((&ct, scalar, &condition));
+        let result2 = executor.execute((&ct, scalar, &condition));
+        assert!(result.block_carries_are_empty());
+        assert_eq!(result, result2, "Failed determinism check");
+
+        let expected = if clear_condition {
+            clear / scalar
+        } else {
+            clear
+        };
+        let decrypted: u64 = cks.decrypt(&result);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid conditional_scalar_div result for {clear} / {scalar} \
+            with condition {clear_condition}"
+        );
+    }
+}
+
+pub(crate) fn default_div_scalar_rounded_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, u64, &'a BooleanBlock), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+    let num_block =
+        (32f64 / (cks.parameters().message_modulus().0 as f64).log(2.0)).ceil() as usize;
+
+    let cks = RadixClientKey::from((cks, num_block));
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(num_block as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for clear_round_up in [false, true] {
+        let round_up = cks.encrypt_bool(clear_round_up);
+
+        for i in 0..nb_tests {
+            let scalar = match i % 3 {
+                // Divisor bigger than the numerator
+                0 => rng.gen_range(modulus..=u64::MAX),
+                _ => rng.gen_range(1..modulus),
+            };
+            let clear = match i % 4 {
+                // Exact division, both modes give the same result
+                0 => (rng.gen::<u64>() % modulus) / scalar * scalar,
+                1 => modulus - 1,
+                _ => rng.gen::<u64>() % modulus,
+            };
+
+            let mut ct = cks.encrypt(clear);
+
+            let result = executor.execute((&ct, scalar, &round_up));
+            assert!(result.block_carries_are_empty());
+            assert_eq!(result.blocks.len(), num_block);
+
+            let expected = if clear_round_up {
+                clear.div_ceil(scalar)
+            } else {
+                clear / scalar
+            };
+            let decrypted: u64 = cks.decrypt(&result);
+            assert_eq!(
+                decrypted, expected,
+                "Invalid div_scalar_rounded result for {clear} / {scalar} \
+                with round_up {clear_round_up}"
+            );
+
+            // Add to have non empty carries
+            let clear_2 = rng.gen::<u64>() % modulus;
+            sks.unchecked_scalar_add_assign(&mut ct, clear_2);
+            let clear = (clear + clear_2) % modulus;
+
+            let result = executor.execute((&ct, scalar, &round_up));
+            assert!(result.block_carries_are_empty());
+
+            let expected = if clear_round_up {
+                clear.div_ceil(scalar)
+            } else {
+                clear / scalar
+            };
+            let decrypted: u64 = cks.decrypt(&result);
+            assert_eq!(
+                decrypted, expected,
+                "Invalid div_scalar_rounded result for {clear} / {scalar} \
+                with round_up {clear_round_up}"
+            );
+        }
+    }
+}
@@ -1,19 +1,27 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_scalar_left_shift_test, default_scalar_right_shift_test,
     unchecked_scalar_left_shift_test, unchecked_scalar_right_shift_test,
 };
-use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus, CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::server_key::ShiftDir;
 use crate::integer::tests::create_parameterized_test;
-use crate::integer::ServerKey;
+use crate::integer::{BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::test_params::*;
 use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
 
 create_parameterized_test!(integer_unchecked_scalar_left_shift);
 create_parameterized_test!(integer_default_scalar_left_shift);
 create_parameterized_test!(integer_unchecked_scalar_right_shift);
 create_parameterized_test!(integer_default_scalar_right_shift);
+create_parameterized_test!(integer_default_conditional_shift_stage);
 
 fn integer_default_scalar_left_shift<P>(param: P)
 where
@@ -46,3 +54,79 @@ where
     let executor = CpuFunctionExecutor::new(&ServerKey::unchecked_scalar_right_shift_parallelized);
     unchecked_scalar_right_shift_test(param, executor);
 }
+
+fn integer_default_conditional_shift_stage<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_shift_stage_parallelized);
+    default_conditional_shift_stage_test(param, executor);
+}
+
+pub(crate) fn default_conditional_shift_stage_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, u32, &'a BooleanBlock, ShiftDir),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+    let nb_bits = modulus.ilog2();
+
+    let expected_shift = |clear: u64, shift: u32, direction: ShiftDir| {
+        let shift = shift % nb_bits;
+        match direction {
+            ShiftDir::Left => (clear << shift) % modulus,
+            ShiftDir::LogicalRight => clear >> shift,
+            ShiftDir::ArithmeticRight => {
+                let sign_bit = (clear >> (nb_bits - 1)) & 1;
+                let padding = (modulus - 1) * sign_bit;
+                let padding = (padding << (nb_bits - shift)) % modulus;
+                (clear >> shift) | padding
+            }
+        }
+    };
+
+    for direction in [
+        ShiftDir::Left,
+        ShiftDir::LogicalRight,
+        ShiftDir::ArithmeticRight,
+    ] {
+        for _ in 0..nb_tests {
+            let clear = rng.gen::<u64>() % modulus;
+            // Also test amounts that are greater than the number of bits
+            let shift_amount = rng.gen_range(0..nb_bits * 2);
+            let clear_shift_bit = rng.gen_bool(0.5);
+
+            let mut ct = cks.encrypt(clear / 2);
+            // Non empty carries, so that the propagation is also tested
+            sks.unchecked_add_assign(&mut ct, &cks.encrypt(clear - clear / 2));
+            let shift_bit = cks.encrypt_bool(clear_shift_bit);
+
+            let ct_res = executor.execute((&ct, shift_amount, &shift_bit, direction));
+            assert!(ct_res.block_carries_are_empty());
+
+            let expected = if clear_shift_bit {
+                expected_shift(clear, shift_amount, direction)
+            } else {
+                clear
+            };
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec_res, expected,
+                "Invalid {direction:?} stage of {clear} by {shift_amount}, shift bit: {clear_shift_bit}"
+            );
+        }
+    }
+}
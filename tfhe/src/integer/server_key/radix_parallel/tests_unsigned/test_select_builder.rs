@@ -0,0 +1,103 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus, CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::test_params::*;
+use crate::shortint::parameters::*;
+use rand::prelude::*;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_default_select_builder);
+
+fn integer_default_select_builder<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey,
+                initial_value: &RadixCiphertext,
+                assignments: &[(BooleanBlock, RadixCiphertext)]| {
+        assignments
+            .iter()
+            .fold(
+                sks.select_builder(initial_value),
+                |builder, (condition, value)| builder.assign_if(condition, value),
+            )
+            .build()
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_select_builder_test(param, executor);
+}
+
+pub(crate) fn default_select_builder_test<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, &'a [(BooleanBlock, RadixCiphertext)]),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let num_assignments = rng.gen_range(0..=5usize);
+
+        let clear_initial_value = rng.gen_range(0..modulus);
+        let clear_assignments = (0..num_assignments)
+            .map(|_| (rng.gen_bool(0.5), rng.gen_range(0..modulus)))
+            .collect::<Vec<_>>();
+
+        let mut initial_value: RadixCiphertext = cks.encrypt(clear_initial_value);
+        let mut assignments = clear_assignments
+            .iter()
+            .map(|(condition, value)| (cks.encrypt_bool(*condition), cks.encrypt(*value)))
+            .collect::<Vec<_>>();
+
+        let expected =
+            clear_assignments
+                .iter()
+                .fold(clear_initial_value, |current, (condition, value)| {
+                    if *condition {
+                        *value
+                    } else {
+                        current
+                    }
+                });
+
+        let result = executor.execute((&initial_value, &assignments));
+        assert!(result.block_carries_are_empty());
+        let dec: u64 = cks.decrypt(&result);
+        assert_eq!(
+            dec, expected,
+            "Invalid result for select_builder, initial value: {clear_initial_value}, \
+            assignments: {clear_assignments:?}"
+        );
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen_range(0..modulus);
+        let ctxt_2 = cks.encrypt(clear_2);
+        sks.unchecked_add_assign(&mut initial_value, &ctxt_2);
+        for (_, value) in assignments.iter_mut() {
+            sks.unchecked_add_assign(value, &ctxt_2);
+        }
+
+        let result = executor.execute((&initial_value, &assignments));
+        assert!(result.block_carries_are_empty());
+        let dec: u64 = cks.decrypt(&result);
+        assert_eq!(dec, (expected + clear_2) % modulus);
+    }
+}
@@ -41,6 +41,9 @@ create_parameterized_test!(integer_advanced_sub_assign_with_borrow_at_least_4_bi
     }
 });
 create_parameterized_test!(integer_advanced_sub_assign_with_borrow_sequential);
+create_parameterized_test!(integer_default_conditional_subtract_if_ge_scalar);
+create_parameterized_test!(integer_default_conditional_subtract_if_ge);
+create_parameterized_test!(integer_default_saturating_conditional_decrement);
 
 fn integer_unchecked_sub<P>(param: P)
 where
@@ -147,6 +150,32 @@ where
     default_overflowing_sub_test(param, executor);
 }
 
+fn integer_default_conditional_subtract_if_ge_scalar<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::conditional_subtract_if_ge_scalar_parallelized);
+    default_conditional_subtract_if_ge_scalar_test(param, executor);
+}
+
+fn integer_default_conditional_subtract_if_ge<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::conditional_subtract_if_ge_parallelized);
+    default_conditional_subtract_if_ge_test(param, executor);
+}
+
+fn integer_default_saturating_conditional_decrement<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&ServerKey::saturating_conditional_decrement_parallelized);
+    default_saturating_conditional_decrement_test(param, executor);
+}
+
 impl ExpectedDegrees {
     fn after_unchecked_sub(&mut self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> &Self {
         let negated_rhs_degrees = NegatedDegreeIter::new(
@@ -589,3 +618,177 @@ pub(crate) fn extensive_trivial_default_overflowing_sub_test<P, T>(
         }
     }
 }
+
+pub(crate) fn default_conditional_subtract_if_ge_scalar_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a mut RadixCiphertext, u64), ()>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let p = rng.gen_range(1..modulus);
+        // The correction is exact for ct < 2p
+        let clear = rng.gen_range(0..(2 * p).min(modulus));
+
+        let mut ct = cks.encrypt(clear);
+        executor.execute((&mut ct, p));
+        panic_if_any_block_is_not_clean(&ct, &cks);
+
+        let expected = if clear >= p { clear - p } else { clear };
+        let dec: u64 = cks.decrypt(&ct);
+        assert_eq!(
+            dec, expected,
+            "Invalid conditional subtraction of {p} from {clear}"
+        );
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen_range(0..=(2 * p).min(modulus) - 1 - clear);
+        let mut ct = cks.encrypt(clear);
+        let ct_2 = cks.encrypt(clear_2);
+        sks.unchecked_add_assign(&mut ct, &ct_2);
+        let clear = clear + clear_2;
+
+        executor.execute((&mut ct, p));
+        panic_if_any_block_is_not_clean(&ct, &cks);
+
+        let expected = if clear >= p { clear - p } else { clear };
+        let dec: u64 = cks.decrypt(&ct);
+        assert_eq!(
+            dec, expected,
+            "Invalid conditional subtraction of {p} from {clear}"
+        );
+    }
+
+    // Values of p that do not fit in the ciphertext are never subtracted
+    let clear = rng.gen::<u64>() % modulus;
+    let mut ct = cks.encrypt(clear);
+    executor.execute((&mut ct, modulus));
+    let dec: u64 = cks.decrypt(&ct);
+    assert_eq!(dec, clear);
+}
+
+pub(crate) fn default_conditional_subtract_if_ge_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a mut RadixCiphertext, &'a RadixCiphertext), ()>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let clear_p = rng.gen_range(1..modulus);
+        // The correction is exact for ct < 2p
+        let clear = rng.gen_range(0..(2 * clear_p).min(modulus));
+
+        let mut ct = cks.encrypt(clear);
+        let mut p = cks.encrypt(clear_p);
+        executor.execute((&mut ct, &p));
+        panic_if_any_block_is_not_clean(&ct, &cks);
+
+        let expected = if clear >= clear_p {
+            clear - clear_p
+        } else {
+            clear
+        };
+        let dec: u64 = cks.decrypt(&ct);
+        assert_eq!(
+            dec, expected,
+            "Invalid conditional subtraction of {clear_p} from {clear}"
+        );
+
+        // Add to have non empty carries on both inputs, while keeping ct < 2p
+        let mut ct = cks.encrypt(clear);
+        let zero = cks.encrypt(0u64);
+        sks.unchecked_add_assign(&mut ct, &zero);
+        sks.unchecked_add_assign(&mut p, &zero);
+
+        executor.execute((&mut ct, &p));
+        panic_if_any_block_is_not_clean(&ct, &cks);
+
+        let dec: u64 = cks.decrypt(&ct);
+        assert_eq!(
+            dec, expected,
+            "Invalid conditional subtraction of {clear_p} from {clear}"
+        );
+    }
+}
+
+pub(crate) fn default_saturating_conditional_decrement_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a mut RadixCiphertext, &'a BooleanBlock), ()>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    // The boundary: 0 stays 0, whatever the condition
+    for clear_condition in [false, true] {
+        let mut ct = cks.encrypt(0u64);
+        let condition = cks.encrypt_bool(clear_condition);
+        executor.execute((&mut ct, &condition));
+        panic_if_any_block_is_not_clean(&ct, &cks);
+        let dec: u64 = cks.decrypt(&ct);
+        assert_eq!(dec, 0, "0 must stay 0 with condition {clear_condition}");
+    }
+
+    for _ in 0..nb_tests {
+        // Small values, so that the counter reaches 0
+        let mut clear = rng.gen_range(0..3u64);
+        let mut ct = cks.encrypt(clear);
+
+        for _ in 0..4 {
+            let clear_condition = rng.gen_bool(0.7);
+            let condition = cks.encrypt_bool(clear_condition);
+
+            executor.execute((&mut ct, &condition));
+            panic_if_any_block_is_not_clean(&ct, &cks);
+
+            if clear_condition {
+                clear = clear.saturating_sub(1);
+            }
+            let dec: u64 = cks.decrypt(&ct);
+            assert_eq!(dec, clear);
+        }
+
+        // Add to have non empty carries
+        let clear_2 = rng.gen::<u64>() % modulus;
+        let ct_2 = cks.encrypt(clear_2);
+        sks.unchecked_add_assign(&mut ct, &ct_2);
+        clear = (clear + clear_2) % modulus;
+
+        let condition = cks.encrypt_bool(true);
+        executor.execute((&mut ct, &condition));
+        panic_if_any_block_is_not_clean(&ct, &cks);
+        let dec: u64 = cks.decrypt(&ct);
+        assert_eq!(dec, clear.saturating_sub(1));
+    }
+}
@@ -3,6 +3,7 @@ use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{FunctionE
 use crate::integer::server_key::radix_parallel::tests_unsigned::{
     nb_tests_smaller_for_params, overflowing_sum_slice_under_modulus, CpuFunctionExecutor,
 };
+use crate::integer::server_key::ReduceOp;
 use crate::integer::tests::create_parameterized_test;
 use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
 #[cfg(tarpaulin)]
@@ -15,6 +16,8 @@ use std::sync::Arc;
 create_parameterized_test!(integer_smart_sum_ciphertexts_slice);
 create_parameterized_test!(integer_default_unsigned_overflowing_sum_ciphertexts_vec);
 create_parameterized_test!(integer_default_sum_ciphertexts_vec);
+create_parameterized_test!(integer_default_reduce_with_refresh);
+create_parameterized_test!(integer_default_reduce_select_chunked);
 
 fn integer_default_unsigned_overflowing_sum_ciphertexts_vec<P>(param: P)
 where
@@ -37,6 +40,22 @@ where
     default_sum_ciphertexts_vec_test(param, executor);
 }
 
+fn integer_default_reduce_with_refresh<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::reduce_with_refresh_parallelized);
+    default_reduce_with_refresh_test(param, executor);
+}
+
+fn integer_default_reduce_select_chunked<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::reduce_select_chunked_parallelized);
+    default_reduce_select_chunked_test(param, executor);
+}
+
 pub(crate) fn integer_default_unsigned_overflowing_sum_ciphertexts_test<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -218,3 +237,134 @@ where
         }
     }
 }
+
+pub(crate) fn default_reduce_with_refresh_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a [RadixCiphertext], ReduceOp, usize), RadixCiphertext>,
+{
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    // 64 elements gives a 6 layers deep tree
+    let len = 64;
+    let mut clears = (0..len)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let mut ctxts = clears
+        .iter()
+        .copied()
+        .map(|clear| cks.encrypt(clear))
+        .collect::<Vec<_>>();
+
+    // Make some of the inputs have non empty carries
+    for i in [0, len / 2 + 1] {
+        let clear = rng.gen::<u64>() % modulus;
+        let ctxt = cks.encrypt(clear);
+        sks.unchecked_add_assign(&mut ctxts[i], &ctxt);
+        clears[i] = (clears[i] + clear) % modulus;
+    }
+
+    for (op, refresh_every) in [
+        (ReduceOp::Sum, 0),
+        (ReduceOp::Sum, 1),
+        (ReduceOp::Sum, 2),
+        // More layers than the tree has
+        (ReduceOp::Sum, 10),
+        (ReduceOp::Min, 2),
+        (ReduceOp::Max, 0),
+    ] {
+        let ct_res = executor.execute((&ctxts, op, refresh_every));
+        assert!(ct_res.block_carries_are_empty());
+
+        let expected = match op {
+            ReduceOp::Sum => clears.iter().fold(0, |acc, v| (acc + v) % modulus),
+            ReduceOp::Min => *clears.iter().min().unwrap(),
+            ReduceOp::Max => *clears.iter().max().unwrap(),
+        };
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res, expected,
+            "Invalid {op:?} reduction with a refresh every {refresh_every} layers"
+        );
+    }
+}
+
+pub(crate) fn default_reduce_select_chunked_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a [RadixCiphertext], usize, ReduceOp), RadixCiphertext>,
+{
+    let param = param.into();
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    let len = 1000;
+    let mut clears = (0..len)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let mut ctxts = clears
+        .iter()
+        .copied()
+        .map(|clear| cks.encrypt(clear))
+        .collect::<Vec<_>>();
+
+    // Make some of the inputs have non empty carries
+    for i in [0, len / 2 + 1] {
+        let clear = rng.gen::<u64>() % modulus;
+        let ctxt = cks.encrypt(clear);
+        sks.unchecked_add_assign(&mut ctxts[i], &ctxt);
+        clears[i] = (clears[i] + clear) % modulus;
+    }
+
+    // A chunk size that does not divide the length, so that the last chunk is smaller
+    let chunk = 7;
+    let ct_res = executor.execute((&ctxts, chunk, ReduceOp::Sum));
+    assert!(ct_res.block_carries_are_empty());
+
+    let expected = clears.iter().fold(0, |acc, v| (acc + v) % modulus);
+    let dec_res: u64 = cks.decrypt(&ct_res);
+    assert_eq!(
+        dec_res, expected,
+        "Invalid Sum reduction of {len} values by chunks of {chunk}"
+    );
+
+    // Comparisons are much more expensive, so fewer values are used
+    let len = 50;
+    for (op, chunk) in [
+        (ReduceOp::Min, chunk),
+        (ReduceOp::Max, 1),
+        (ReduceOp::Max, len),
+    ] {
+        let ct_res = executor.execute((&ctxts[..len], chunk, op));
+        assert!(ct_res.block_carries_are_empty());
+
+        let expected = match op {
+            ReduceOp::Sum => unreachable!(),
+            ReduceOp::Min => *clears[..len].iter().min().unwrap(),
+            ReduceOp::Max => *clears[..len].iter().max().unwrap(),
+        };
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res, expected,
+            "Invalid {op:?} reduction of {len} values by chunks of {chunk}"
+        );
+    }
+}
@@ -2,7 +2,7 @@ use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
 use crate::integer::server_key::radix_parallel::tests_unsigned::panic_if_any_block_is_not_clean_or_trivial;
 use crate::integer::{BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::sync::Arc;
 
 use super::{
@@ -41,6 +41,13 @@ create_parameterized_test!(integer_default_index_of);
 create_parameterized_test!(integer_default_index_of_clear);
 create_parameterized_test!(integer_default_first_index_of);
 create_parameterized_test!(integer_default_first_index_of_clear);
+create_parameterized_test!(integer_default_match_transform);
+create_parameterized_test!(integer_default_map_lookup);
+create_parameterized_test!(integer_default_quantize);
+create_parameterized_test!(integer_default_modinv_scalar);
+create_parameterized_test!(integer_default_emit_digit_if);
+create_parameterized_test!(integer_default_mealy_step);
+create_parameterized_test!(integer_default_match_cases);
 
 fn integer_unchecked_match_value<P>(param: P)
 where
@@ -222,6 +229,77 @@ where
     default_first_index_of_clear_test_case(param, executor);
 }
 
+fn integer_default_match_transform<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey, tag: &RadixCiphertext, x: &RadixCiphertext| {
+        let message_modulus = sks.message_modulus().0;
+        let transforms: [(u64, &(dyn Fn(u64) -> u64 + Sync)); 3] = [
+            (0, &|block| block + 1),
+            (1, &|block| block * 2),
+            (2, &|block| message_modulus - 1 - block),
+        ];
+        sks.match_transform_parallelized(tag, &transforms, x)
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_match_transform_test_case(param, executor);
+}
+
+fn integer_default_map_lookup<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::map_lookup_parallelized);
+    default_map_lookup_test_case(param, executor);
+}
+
+fn integer_default_quantize<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::quantize_parallelized);
+    default_quantize_test_case(param, executor);
+}
+
+fn integer_default_modinv_scalar<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::modinv_scalar_parallelized);
+    default_modinv_scalar_test_case(param, executor);
+}
+
+fn integer_default_emit_digit_if<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::emit_digit_if_parallelized);
+    default_emit_digit_if_test_case(param, executor);
+}
+
+fn integer_default_mealy_step<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::mealy_step_parallelized);
+    default_mealy_step_test_case(param, executor);
+}
+
+fn integer_default_match_cases<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let func = |sks: &ServerKey,
+                selector: &RadixCiphertext,
+                cases: &[(u64, &RadixCiphertext)],
+                default: &RadixCiphertext| {
+        sks.match_cases_parallelized(selector, cases, default)
+    };
+    let executor = CpuFunctionExecutor::new(&func);
+    default_match_cases_test_case(param, executor);
+}
+
 /// This function takes a list of ciphertexts and their corresponding clear values
 /// and picks one ciphertext at random to make it so it has carries (via unchecked_add)
 ///
@@ -1936,3 +2014,537 @@ where
         assert_eq!(is_in, expected_is_in);
     }
 }
+
+pub(crate) fn default_match_transform_test_case<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    let message_modulus = cks.parameters().message_modulus().0;
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    // Same arms as the ones given by the executor, tag 3 has no arm
+    let apply_arm = |tag: u64, x: u64| {
+        let block_fn = |block: u64| match tag {
+            0 => (block + 1) % message_modulus,
+            1 => (block * 2) % message_modulus,
+            2 => message_modulus - 1 - block,
+            _ => 0,
+        };
+        (0..NB_CTXT as u32).fold(0, |acc, i| {
+            let block = (x / message_modulus.pow(i)) % message_modulus;
+            acc + block_fn(block) * message_modulus.pow(i)
+        })
+    };
+
+    for _ in 0..nb_tests {
+        let clear_x = rng.gen_range(0..modulus);
+        let clear_tag = rng.gen_range(0..4);
+
+        let mut ctxt_x = cks.encrypt(clear_x);
+        let ctxt_tag = cks.encrypt(clear_tag);
+
+        let ct_res = executor.execute((&ctxt_tag, &ctxt_x));
+        panic_if_any_block_is_not_clean_or_trivial(&ct_res, &cks);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            apply_arm(clear_tag, clear_x),
+            "Invalid match_transform result for tag {clear_tag} and x {clear_x}"
+        );
+
+        // Add to have non empty carries
+        let clear_0 = rng.gen_range(0..modulus);
+        sks.unchecked_scalar_add_assign(&mut ctxt_x, clear_0);
+        let clear_x = (clear_x + clear_0) % modulus;
+
+        let ct_res = executor.execute((&ctxt_tag, &ctxt_x));
+        panic_if_any_block_is_not_clean_or_trivial(&ct_res, &cks);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            apply_arm(clear_tag, clear_x),
+            "Invalid match_transform result for tag {clear_tag} and x {clear_x}"
+        );
+    }
+}
+
+pub(crate) fn default_map_lookup_test_case<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, &'a [(u64, u64)], u64, usize),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    // Small keys take the packed path, the NB_CTXT one takes the per-entry comparison path
+    for num_key_blocks in [1, 2, NB_CTXT] {
+        let key_modulus =
+            unsigned_modulus(cks.parameters().message_modulus(), num_key_blocks as u32);
+        let num_entries = key_modulus.min(6) as usize;
+
+        for _ in 0..nb_tests {
+            let mut keys = HashSet::new();
+            while keys.len() < num_entries {
+                keys.insert(rng.gen_range(0..key_modulus));
+            }
+            // This key can never match, as the encrypted key cannot hold it
+            keys.insert(key_modulus);
+            let entries = keys
+                .into_iter()
+                .map(|key| (key, rng.gen::<u64>()))
+                .collect::<Vec<_>>();
+            let default = rng.gen::<u64>();
+
+            let clear_key = rng.gen_range(0..key_modulus);
+            let mut ctxt_key = cks.as_ref().encrypt_radix(clear_key, num_key_blocks);
+
+            let expected = entries
+                .iter()
+                .find(|(key, _)| *key == clear_key)
+                .map_or(default, |(_, value)| *value)
+                % modulus;
+
+            let ct_res = executor.execute((&ctxt_key, &entries, default, NB_CTXT));
+            assert_eq!(ct_res.blocks.len(), NB_CTXT);
+            panic_if_any_block_is_not_clean_or_trivial(&ct_res, &cks);
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec_res, expected,
+                "Invalid map_lookup result for key {clear_key} ({num_key_blocks} blocks)"
+            );
+
+            // Add to have non empty carries
+            let clear_0 = rng.gen_range(0..key_modulus);
+            sks.unchecked_scalar_add_assign(&mut ctxt_key, clear_0);
+            let clear_key = (clear_key + clear_0) % key_modulus;
+
+            let expected = entries
+                .iter()
+                .find(|(key, _)| *key == clear_key)
+                .map_or(default, |(_, value)| *value)
+                % modulus;
+
+            let ct_res = executor.execute((&ctxt_key, &entries, default, NB_CTXT));
+            panic_if_any_block_is_not_clean_or_trivial(&ct_res, &cks);
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec_res, expected,
+                "Invalid map_lookup result for key {clear_key} ({num_key_blocks} blocks)"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_quantize_test_case<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, &'a [u64], &'a [u64], usize),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for num_thresholds in [0, 1, 3, 7] {
+        for _ in 0..nb_tests {
+            let mut thresholds = BTreeSet::new();
+            while thresholds.len() < num_thresholds {
+                thresholds.insert(rng.gen_range(0..modulus));
+            }
+            let thresholds = thresholds.into_iter().collect::<Vec<_>>();
+            let levels = (0..=num_thresholds)
+                .map(|_| rng.gen_range(0..modulus))
+                .collect::<Vec<_>>();
+
+            // Values on the thresholds are the edge cases
+            let clear = if !thresholds.is_empty() && rng.gen_bool(0.5) {
+                thresholds[rng.gen_range(0..thresholds.len())]
+            } else {
+                rng.gen_range(0..modulus)
+            };
+            let mut ctxt = cks.encrypt(clear);
+
+            let expected_level = |value: u64| {
+                levels[thresholds
+                    .iter()
+                    .filter(|threshold| **threshold <= value)
+                    .count()]
+            };
+
+            let ct_res = executor.execute((&ctxt, &thresholds, &levels, NB_CTXT));
+            assert_eq!(ct_res.blocks.len(), NB_CTXT);
+            panic_if_any_block_is_not_clean_or_trivial(&ct_res, &cks);
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec_res,
+                expected_level(clear),
+                "Invalid quantization of {clear} with thresholds {thresholds:?}"
+            );
+
+            // Add to have non empty carries
+            let clear_0 = rng.gen_range(0..modulus);
+            sks.unchecked_scalar_add_assign(&mut ctxt, clear_0);
+            let clear = (clear + clear_0) % modulus;
+
+            let ct_res = executor.execute((&ctxt, &thresholds, &levels, NB_CTXT));
+            panic_if_any_block_is_not_clean_or_trivial(&ct_res, &cks);
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec_res,
+                expected_level(clear),
+                "Invalid quantization of {clear} with thresholds {thresholds:?}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_modinv_scalar_test_case<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, u64), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    executor.setup(&cks, sks.clone());
+
+    // Small inputs take the single lookup table path, the NB_CTXT one takes the
+    // per-entry comparison path
+    for num_blocks in [1, 2, NB_CTXT] {
+        let modulus = unsigned_modulus(cks.parameters().message_modulus(), num_blocks as u32);
+        let primes = [2u64, 3, 5, 7, 13, 31, 61]
+            .into_iter()
+            .filter(|p| *p <= modulus)
+            .collect::<Vec<_>>();
+
+        for _ in 0..nb_tests {
+            let p = primes[rng.gen_range(0..primes.len())];
+            // 0 and p - 1 are edge cases, p is not reduced
+            let clear = match rng.gen_range(0..4) {
+                0 => 0,
+                1 => p - 1,
+                2 if p < modulus => p,
+                _ => rng.gen_range(0..p),
+            };
+            let mut ctxt = cks.as_ref().encrypt_radix(clear, num_blocks);
+
+            let expected = |value: u64| {
+                (1..p)
+                    .find(|inverse| value < p && value * inverse % p == 1)
+                    .unwrap_or(0)
+            };
+
+            let ct_res = executor.execute((&ctxt, p));
+            assert_eq!(ct_res.blocks.len(), num_blocks);
+            panic_if_any_block_is_not_clean_or_trivial(&ct_res, &cks);
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec_res,
+                expected(clear),
+                "Invalid modular inverse of {clear} mod {p} ({num_blocks} blocks)"
+            );
+
+            // Add to have non empty carries, while staying reduced
+            let clear_0 = rng.gen_range(0..p - clear % p);
+            sks.unchecked_scalar_add_assign(&mut ctxt, clear_0);
+            let clear = (clear + clear_0) % modulus;
+
+            let ct_res = executor.execute((&ctxt, p));
+            panic_if_any_block_is_not_clean_or_trivial(&ct_res, &cks);
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec_res,
+                expected(clear),
+                "Invalid modular inverse of {clear} mod {p} ({num_blocks} blocks)"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_emit_digit_if_test_case<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, u32, &'a [u64], &'a BooleanBlock),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    executor.setup(&cks, sks.clone());
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+    let num_bits = modulus.ilog2();
+    let num_digits = num_bits.div_ceil(6);
+
+    for _ in 0..nb_tests {
+        let alphabet = (0..64)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let clear = rng.gen::<u64>() % modulus;
+        let digit_pos = rng.gen_range(0..num_digits);
+        let clear_condition = rng.gen_bool(0.5);
+
+        let mut ctxt = cks.encrypt(clear);
+        let ctxt_condition = cks.encrypt_bool(clear_condition);
+
+        let expected = |clear: u64| {
+            if clear_condition {
+                alphabet[((clear >> (6 * digit_pos)) % 64) as usize]
+            } else {
+                0
+            }
+        };
+
+        let ct_res = executor.execute((&ctxt, digit_pos, &alphabet, &ctxt_condition));
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            expected(clear),
+            "Invalid digit {digit_pos} of {clear} with condition {clear_condition}"
+        );
+
+        // Add to have non empty carries
+        let clear_0 = rng.gen::<u64>() % modulus;
+        sks.unchecked_scalar_add_assign(&mut ctxt, clear_0);
+        let clear = (clear + clear_0) % modulus;
+
+        let ct_res = executor.execute((&ctxt, digit_pos, &alphabet, &ctxt_condition));
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            expected(clear),
+            "Invalid digit {digit_pos} of {clear} with condition {clear_condition}"
+        );
+    }
+}
+
+pub(crate) fn default_mealy_step_test_case<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a [Vec<u64>],
+            &'a [Vec<u64>],
+        ),
+        (RadixCiphertext, RadixCiphertext),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    executor.setup(&cks, sks.clone());
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let num_states = rng.gen_range(1..=5u64);
+        let num_inputs = rng.gen_range(1..=4u64);
+        let next_table = (0..num_states)
+            .map(|_| {
+                (0..num_inputs)
+                    .map(|_| rng.gen_range(0..num_states))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        // Outputs may be wider than the state
+        let out_table = (0..num_states)
+            .map(|_| {
+                (0..num_inputs)
+                    .map(|_| rng.gen::<u64>() % modulus.saturating_mul(modulus))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let max_output = *out_table.iter().flatten().max().unwrap();
+
+        // Out of range states and inputs are tested too
+        let clear_state = rng.gen_range(0..=num_states);
+        let clear_input = rng.gen_range(0..=num_inputs);
+
+        let mut ctxt_state = cks.encrypt(clear_state);
+        let ctxt_input = cks.encrypt(clear_input);
+
+        let (expected_state, expected_output) =
+            if clear_state < num_states && clear_input < num_inputs {
+                (
+                    next_table[clear_state as usize][clear_input as usize],
+                    out_table[clear_state as usize][clear_input as usize],
+                )
+            } else {
+                (0, 0)
+            };
+
+        let (ct_state, ct_output) =
+            executor.execute((&ctxt_state, &ctxt_input, &next_table, &out_table));
+        assert_eq!(ct_state.blocks.len(), NB_CTXT);
+        assert_eq!(
+            ct_output.blocks.len(),
+            sks.num_blocks_to_represent_unsigned_value(max_output)
+        );
+        panic_if_any_block_is_not_clean_or_trivial(&ct_state, &cks);
+        panic_if_any_block_is_not_clean_or_trivial(&ct_output, &cks);
+        let dec_state: u64 = cks.decrypt(&ct_state);
+        let dec_output: u64 = cks.decrypt(&ct_output);
+        assert_eq!(
+            (dec_state, dec_output),
+            (expected_state, expected_output),
+            "Invalid step from state {clear_state} with input {clear_input}"
+        );
+
+        // Add an encrypted 0 to have non empty carries, while keeping the same state
+        let ctxt_zero = cks.encrypt(0u64);
+        sks.unchecked_add_assign(&mut ctxt_state, &ctxt_zero);
+        assert!(!ctxt_state.block_carries_are_empty());
+        let (ct_state, ct_output) =
+            executor.execute((&ctxt_state, &ctxt_input, &next_table, &out_table));
+        panic_if_any_block_is_not_clean_or_trivial(&ct_state, &cks);
+        panic_if_any_block_is_not_clean_or_trivial(&ct_output, &cks);
+        let dec_state: u64 = cks.decrypt(&ct_state);
+        let dec_output: u64 = cks.decrypt(&ct_output);
+        assert_eq!(
+            (dec_state, dec_output),
+            (expected_state, expected_output),
+            "Invalid step from state {clear_state} with input {clear_input}"
+        );
+    }
+}
+
+pub(crate) fn default_match_cases_test_case<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (
+            &'a RadixCiphertext,
+            &'a [(u64, &'a RadixCiphertext)],
+            &'a RadixCiphertext,
+        ),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    executor.setup(&cks, sks.clone());
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        // Odd numbers of cases leave a node without sibling in the tree, and keys may be
+        // duplicated, or too big to be represented by the selector
+        let num_cases = rng.gen_range(0..=MAX_VEC_LEN);
+        let keys = (0..num_cases)
+            .map(|_| rng.gen_range(0..(modulus / 4).max(2)))
+            .collect::<Vec<_>>();
+        let clear_values = (0..num_cases)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let clear_default = rng.gen::<u64>() % modulus;
+        let clear_selector = match rng.gen_range(0..3) {
+            0 if num_cases > 0 => keys[rng.gen_range(0..num_cases)],
+            _ => rng.gen_range(0..(modulus / 4).max(2)),
+        };
+
+        let mut values = clear_values
+            .iter()
+            .map(|v| cks.encrypt(*v))
+            .collect::<Vec<_>>();
+        let mut clears = clear_values.clone();
+        if num_cases > 0 {
+            make_one_ciphertext_have_carries(
+                &mut clears,
+                &mut values,
+                &mut rng,
+                &sks,
+                u64::MAX,
+                modulus,
+            );
+        }
+        let ctxt_default = cks.encrypt(clear_default);
+        let mut ctxt_selector = cks.encrypt(clear_selector);
+        // Add an encrypted 0 to have non empty carries on the selector
+        sks.unchecked_add_assign(&mut ctxt_selector, &cks.encrypt(0u64));
+
+        let mut cases = keys.iter().copied().zip(values.iter()).collect::<Vec<_>>();
+        if rng.gen_bool(0.2) {
+            // A key that cannot be represented never matches
+            cases.push((modulus, &ctxt_default));
+        }
+
+        let expected = keys
+            .iter()
+            .position(|key| *key == clear_selector)
+            .map_or(clear_default, |i| clears[i]);
+
+        let ct_res = executor.execute((&ctxt_selector, &cases, &ctxt_default));
+        if num_cases > 0 {
+            assert!(ct_res.block_carries_are_empty());
+        }
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res, expected,
+            "Invalid match of {clear_selector} with keys {keys:?} and values {clears:?}"
+        );
+    }
+}
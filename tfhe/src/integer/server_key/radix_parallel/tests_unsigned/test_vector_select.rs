@@ -0,0 +1,789 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, unsigned_modulus, CpuFunctionExecutor, NB_CTXT,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::test_params::*;
+use crate::shortint::parameters::*;
+use rand::prelude::*;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_default_select_matrix_row);
+create_parameterized_test!(integer_default_ring_read);
+create_parameterized_test!(integer_default_tree_aggregate);
+create_parameterized_test!(integer_default_strided_gather);
+create_parameterized_test!(integer_default_sample2d);
+create_parameterized_test!(integer_default_select_from_slice);
+create_parameterized_test!(integer_default_get_at);
+create_parameterized_test!(integer_default_weighted_gather_sum);
+create_parameterized_test!(integer_default_histogram_update);
+create_parameterized_test!(integer_default_bounded_register_update);
+create_parameterized_test!(integer_default_bitset_conditional_insert);
+
+fn integer_default_select_matrix_row<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::select_matrix_row_parallelized);
+    default_select_matrix_row_test(param, executor);
+}
+
+fn integer_default_histogram_update<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::histogram_update_parallelized);
+    default_histogram_update_test(param, executor);
+}
+
+fn integer_default_bounded_register_update<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::bounded_register_update_parallelized);
+    default_bounded_register_update_test(param, executor);
+}
+
+fn integer_default_bitset_conditional_insert<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::bitset_conditional_insert_parallelized);
+    default_bitset_conditional_insert_test(param, executor);
+}
+
+fn integer_default_ring_read<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::ring_read_parallelized);
+    default_ring_read_test(param, executor);
+}
+
+fn integer_default_tree_aggregate<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::tree_aggregate_parallelized);
+    default_tree_aggregate_test(param, executor);
+}
+
+fn integer_default_strided_gather<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::strided_gather_parallelized);
+    default_strided_gather_test(param, executor);
+}
+
+fn integer_default_sample2d<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::sample2d_parallelized);
+    default_sample2d_test(param, executor);
+}
+
+fn integer_default_select_from_slice<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::select_from_slice_parallelized);
+    default_select_from_slice_test(param, executor);
+}
+
+fn integer_default_get_at<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::get_at_parallelized);
+    default_get_at_test(param, executor);
+}
+
+fn integer_default_weighted_gather_sum<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::weighted_gather_sum_parallelized);
+    default_weighted_gather_sum_test(param, executor);
+}
+
+pub(crate) fn default_select_matrix_row_test<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, &'a [Vec<RadixCiphertext>]),
+        Vec<RadixCiphertext>,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let num_rows = rng.gen_range(1..=5usize);
+        let num_columns = rng.gen_range(1..=4usize);
+
+        let clear_matrix = (0..num_rows)
+            .map(|_| {
+                (0..num_columns)
+                    .map(|_| rng.gen_range(0..modulus))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let matrix = clear_matrix
+            .iter()
+            .map(|row| row.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        // Also test out of range indices
+        let clear_index = rng.gen_range(0..num_rows as u64 + 2);
+        let index = cks.encrypt(clear_index);
+
+        let row = executor.execute((&index, &matrix));
+        assert_eq!(row.len(), num_columns);
+
+        let expected = clear_matrix
+            .get(clear_index as usize)
+            .cloned()
+            .unwrap_or_else(|| vec![0; num_columns]);
+        for (ct, expected) in row.iter().zip(expected.iter()) {
+            assert!(ct.block_carries_are_empty());
+            let dec: u64 = cks.decrypt(ct);
+            assert_eq!(
+                dec, *expected,
+                "Invalid result for select_matrix_row, index: {clear_index}, matrix: {clear_matrix:?}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_ring_read_test<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<
+        (&'a [RadixCiphertext], &'a RadixCiphertext, usize),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        // Lengths that are powers of two and lengths that are not
+        for len in [1usize, 4, 5, 7] {
+            let clear_buffer = (0..len)
+                .map(|_| rng.gen_range(0..modulus))
+                .collect::<Vec<_>>();
+            let buffer = clear_buffer
+                .iter()
+                .map(|v| cks.encrypt(*v))
+                .collect::<Vec<_>>();
+
+            // The head is not necessarily smaller than the length,
+            // and the offset may be greater than the length
+            let clear_head = rng.gen_range(0..modulus);
+            let head = cks.encrypt(clear_head);
+            let offset = rng.gen_range(0..3 * len);
+
+            let result = executor.execute((&buffer, &head, offset));
+            assert!(result.block_carries_are_empty());
+
+            let expected = clear_buffer[((clear_head as usize) + offset) % len];
+            let dec: u64 = cks.decrypt(&result);
+            assert_eq!(
+                dec, expected,
+                "Invalid result for ring_read, head: {clear_head}, offset: {offset}, buffer: {clear_buffer:?}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_tree_aggregate_test<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<(&'a [BooleanBlock], &'a [RadixCiphertext]), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        for num_leaves in [1usize, 4, 5] {
+            let clear_leaves = (0..num_leaves)
+                .map(|_| rng.gen_range(0..modulus))
+                .collect::<Vec<_>>();
+            let mut leaves = clear_leaves
+                .iter()
+                .map(|v| cks.encrypt(*v))
+                .collect::<Vec<_>>();
+
+            // One-hot indicators
+            let taken_leaf = rng.gen_range(0..num_leaves);
+            let indicators = (0..num_leaves)
+                .map(|i| cks.encrypt_bool(i == taken_leaf))
+                .collect::<Vec<_>>();
+
+            let result = executor.execute((&indicators, &leaves));
+            assert!(result.block_carries_are_empty());
+            let dec: u64 = cks.decrypt(&result);
+            assert_eq!(
+                dec, clear_leaves[taken_leaf],
+                "Invalid result for tree_aggregate, taken leaf: {taken_leaf}, leaves: {clear_leaves:?}"
+            );
+
+            // Indicators that are not one-hot, and leaves with non empty carries
+            let clear_indicators = (0..num_leaves)
+                .map(|_| rng.gen_bool(0.5))
+                .collect::<Vec<_>>();
+            let indicators = clear_indicators
+                .iter()
+                .map(|b| cks.encrypt_bool(*b))
+                .collect::<Vec<_>>();
+            let clear_0 = rng.gen_range(0..modulus);
+            let ct_0 = cks.encrypt(clear_0);
+            sks.unchecked_add_assign(&mut leaves[0], &ct_0);
+            let mut clear_leaves = clear_leaves;
+            clear_leaves[0] = (clear_leaves[0] + clear_0) % modulus;
+
+            let result = executor.execute((&indicators, &leaves));
+            assert!(result.block_carries_are_empty());
+            let expected = clear_leaves
+                .iter()
+                .zip(clear_indicators.iter())
+                .filter(|(_, indicator)| **indicator)
+                .fold(0, |acc, (leaf, _)| (acc + leaf) % modulus);
+            let dec: u64 = cks.decrypt(&result);
+            assert_eq!(
+                dec, expected,
+                "Invalid result for tree_aggregate, indicators: {clear_indicators:?}, leaves: {clear_leaves:?}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_strided_gather_test<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<(&'a RadixCiphertext, u64, &'a [RadixCiphertext]), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        let len = 7usize;
+        let clear_buffer = (0..len)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        let buffer = clear_buffer
+            .iter()
+            .map(|v| cks.encrypt(*v))
+            .collect::<Vec<_>>();
+
+        // The large stride makes the product overflow the number of blocks of base
+        for stride in [0u64, 1, 2, 3, modulus - 1] {
+            // Mostly in range bases, but also bases far out of range
+            let clear_base = if rng.gen_bool(0.75) {
+                rng.gen_range(0..len as u64)
+            } else {
+                rng.gen_range(0..modulus)
+            };
+            let base = cks.encrypt(clear_base);
+
+            let result = executor.execute((&base, stride, &buffer));
+            assert!(result.block_carries_are_empty());
+
+            let index = clear_base * stride;
+            let expected = if index < len as u64 {
+                clear_buffer[index as usize]
+            } else {
+                0
+            };
+            let dec: u64 = cks.decrypt(&result);
+            assert_eq!(
+                dec, expected,
+                "Invalid result for strided_gather, base: {clear_base}, stride: {stride}, buffer: {clear_buffer:?}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_sample2d_test<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<
+        (
+            &'a [RadixCiphertext],
+            usize,
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        for (width, height) in [(1usize, 3usize), (3, 2), (2, 4)] {
+            let clear_buffer = (0..width * height)
+                .map(|_| rng.gen_range(0..modulus))
+                .collect::<Vec<_>>();
+            let buffer = clear_buffer
+                .iter()
+                .map(|v| cks.encrypt(*v))
+                .collect::<Vec<_>>();
+
+            // Mostly in range coordinates, but also coordinates far out of range
+            let mut gen_coordinate = |bound: usize| {
+                if rng.gen_bool(0.75) {
+                    rng.gen_range(0..bound as u64)
+                } else {
+                    rng.gen_range(0..modulus)
+                }
+            };
+            let clear_x = gen_coordinate(width);
+            let clear_y = gen_coordinate(height);
+            let x = cks.encrypt(clear_x);
+            let y = cks.encrypt(clear_y);
+
+            let result = executor.execute((&buffer, width, &x, &y));
+            assert!(result.block_carries_are_empty());
+
+            let expected = if clear_x < width as u64 && clear_y < height as u64 {
+                clear_buffer[clear_y as usize * width + clear_x as usize]
+            } else {
+                0
+            };
+            let dec: u64 = cks.decrypt(&result);
+            assert_eq!(
+                dec, expected,
+                "Invalid result for sample2d, x: {clear_x}, y: {clear_y}, width: {width}, buffer: {clear_buffer:?}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_select_from_slice_test<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a [RadixCiphertext]), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    // Large enough to require an index of more than one block with all the parameters
+    let len = 300usize;
+    let clear_values = (0..len)
+        .map(|_| rng.gen_range(0..modulus))
+        .collect::<Vec<_>>();
+    let values = clear_values
+        .iter()
+        .map(|v| cks.encrypt(*v))
+        .collect::<Vec<_>>();
+
+    let message_modulus = cks.parameters().message_modulus().0;
+    let mut num_index_blocks = 1;
+    while message_modulus.pow(num_index_blocks) < len as u64 {
+        num_index_blocks += 1;
+    }
+    assert!(num_index_blocks > 1);
+    let index_modulus = message_modulus.pow(num_index_blocks);
+
+    for _ in 0..nb_tests {
+        // The last positions need all the blocks of the index,
+        // and some indices are out of range (when representable)
+        for clear_index in [
+            rng.gen_range(0..len as u64),
+            rng.gen_range(message_modulus..len as u64),
+            len as u64 - 1,
+            rng.gen_range(0..index_modulus),
+        ] {
+            let index = cks
+                .as_ref()
+                .encrypt_radix(clear_index, num_index_blocks as usize);
+
+            let result = executor.execute((&index, &values));
+            assert!(result.block_carries_are_empty());
+
+            let expected = clear_values.get(clear_index as usize).copied().unwrap_or(0);
+            let dec: u64 = cks.decrypt(&result);
+            assert_eq!(
+                dec, expected,
+                "Invalid result for select_from_slice, index: {clear_index}, len: {len}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_get_at_test<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<(&'a [RadixCiphertext], &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    for _ in 0..nb_tests {
+        // Lengths that are not a power of two need padding
+        let len = rng.gen_range(1..=9usize);
+        let mut clear_table = (0..len)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        let mut table = clear_table
+            .iter()
+            .map(|v| cks.encrypt(*v))
+            .collect::<Vec<_>>();
+
+        // Add to have non empty carries on one of the elements
+        let i = rng.gen_range(0..len);
+        let clear_0 = rng.gen_range(0..modulus);
+        sks.unchecked_scalar_add_assign(&mut table[i], clear_0);
+        clear_table[i] = (clear_table[i] + clear_0) % modulus;
+
+        // Indices in the padding and past the padding are out of range
+        for clear_index in [
+            rng.gen_range(0..len as u64),
+            len as u64 - 1,
+            rng.gen_range(len as u64..=len.next_power_of_two() as u64),
+            rng.gen_range(0..modulus),
+        ] {
+            let mut index = cks.encrypt(clear_index);
+            // Add an encrypted 0 to have non empty carries on the index
+            sks.unchecked_add_assign(&mut index, &cks.encrypt(0u64));
+
+            let result = executor.execute((&table, &index));
+            assert!(result.block_carries_are_empty());
+
+            let expected = clear_table.get(clear_index as usize).copied().unwrap_or(0);
+            let dec: u64 = cks.decrypt(&result);
+            assert_eq!(
+                dec, expected,
+                "Invalid result for get_at, index: {clear_index}, table: {clear_table:?}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_weighted_gather_sum_test<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<
+        (&'a [RadixCiphertext], &'a [u64], &'a [RadixCiphertext]),
+        RadixCiphertext,
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    let len = 5usize;
+
+    for num_indices in [0, 1, 4] {
+        for _ in 0..nb_tests {
+            let clear_table = (0..len)
+                .map(|_| rng.gen_range(0..modulus))
+                .collect::<Vec<_>>();
+            let mut table = clear_table
+                .iter()
+                .map(|v| cks.encrypt(*v))
+                .collect::<Vec<_>>();
+
+            // Some indices are out of range, and the weights can be 0 or make the sum overflow
+            let clear_indices = (0..num_indices)
+                .map(|_| rng.gen_range(0..len as u64 + 2))
+                .collect::<Vec<_>>();
+            let weights = (0..num_indices)
+                .map(|_| rng.gen_range(0..modulus))
+                .collect::<Vec<_>>();
+            let indices = clear_indices
+                .iter()
+                .map(|index| cks.encrypt(*index))
+                .collect::<Vec<_>>();
+
+            let expected = |clear_table: &[u64]| {
+                clear_indices
+                    .iter()
+                    .zip(weights.iter())
+                    .map(|(index, weight)| {
+                        let value = clear_table.get(*index as usize).copied().unwrap_or(0);
+                        value.wrapping_mul(*weight) % modulus
+                    })
+                    .fold(0, |acc, term| (acc + term) % modulus)
+            };
+
+            let result = executor.execute((&indices, &weights, &table));
+            assert!(result.block_carries_are_empty());
+            let dec: u64 = cks.decrypt(&result);
+            assert_eq!(
+                dec,
+                expected(&clear_table),
+                "Invalid result for weighted_gather_sum, indices: {clear_indices:?}, \
+                weights: {weights:?}, table: {clear_table:?}"
+            );
+
+            // Add to have non empty carries in the table
+            let clear_0 = rng.gen_range(0..modulus);
+            sks.unchecked_scalar_add_assign(&mut table[0], clear_0);
+            let mut clear_table = clear_table;
+            clear_table[0] = (clear_table[0] + clear_0) % modulus;
+
+            let result = executor.execute((&indices, &weights, &table));
+            assert!(result.block_carries_are_empty());
+            let dec: u64 = cks.decrypt(&result);
+            assert_eq!(
+                dec,
+                expected(&clear_table),
+                "Invalid result for weighted_gather_sum, indices: {clear_indices:?}, \
+                weights: {weights:?}, table: {clear_table:?}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_histogram_update_test<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<(&'a mut [RadixCiphertext], &'a RadixCiphertext), ()>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+    // Enough updates so that some buckets have to be propagated
+    let num_updates = 2
+        * cks.parameters().message_modulus().0 as usize
+        * cks.parameters().carry_modulus().0 as usize;
+
+    for _ in 0..nb_tests {
+        for num_buckets in [1usize, 3] {
+            let mut buckets = (0..num_buckets)
+                .map(|_| sks.create_trivial_zero_radix::<RadixCiphertext>(NB_CTXT))
+                .collect::<Vec<_>>();
+            let mut expected = vec![0u64; num_buckets];
+
+            for _ in 0..num_updates {
+                // Mostly in range values, with some that must be clamped
+                let clear_value = if rng.gen_bool(0.8) {
+                    rng.gen_range(0..num_buckets as u64)
+                } else {
+                    rng.gen_range(0..modulus)
+                };
+                let value = cks.encrypt(clear_value);
+
+                executor.execute((&mut buckets, &value));
+                expected[(clear_value as usize).min(num_buckets - 1)] += 1;
+            }
+
+            for (bucket, expected) in buckets.iter_mut().zip(expected.iter()) {
+                sks.full_propagate_parallelized(bucket);
+                let decrypted: u64 = cks.decrypt(bucket);
+                assert_eq!(
+                    decrypted, *expected,
+                    "Invalid histogram bucket with {num_buckets} buckets"
+                );
+            }
+        }
+    }
+}
+
+pub(crate) fn default_bounded_register_update_test<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<(&'a mut [RadixCiphertext], &'a RadixCiphertext, i64, u64), ()>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks.clone());
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    let num_regs = 4u64;
+
+    for _ in 0..nb_tests {
+        let max = rng.gen_range(0..modulus);
+        let mut clear_regs = (0..num_regs)
+            .map(|_| rng.gen_range(0..=max))
+            .collect::<Vec<_>>();
+        let mut regs = clear_regs
+            .iter()
+            .map(|v| cks.encrypt(*v))
+            .collect::<Vec<_>>();
+
+        // Add to have non empty carries, while staying in [0, max]
+        let clear_0 = rng.gen_range(0..=max - clear_regs[0]);
+        sks.unchecked_scalar_add_assign(&mut regs[0], clear_0);
+        clear_regs[0] += clear_0;
+
+        // Deltas that saturate at 0, at max, beyond the width of the registers,
+        // and out of range indices
+        let modulus = modulus as i64;
+        for (clear_index, delta) in [
+            (rng.gen_range(0..num_regs), rng.gen_range(-modulus..modulus)),
+            (rng.gen_range(0..num_regs), -modulus),
+            (rng.gen_range(0..num_regs), modulus),
+            (rng.gen_range(0..num_regs), i64::MAX),
+            (
+                rng.gen_range(num_regs..num_regs * 2),
+                rng.gen_range(-modulus..modulus),
+            ),
+        ] {
+            let index = cks.encrypt(clear_index);
+
+            executor.execute((&mut regs, &index, delta, max));
+
+            if let Some(reg) = clear_regs.get_mut(clear_index as usize) {
+                *reg = (*reg as i128 + delta as i128).clamp(0, max as i128) as u64;
+            }
+            for (i, (reg, clear_reg)) in regs.iter().zip(clear_regs.iter()).enumerate() {
+                assert!(reg.block_carries_are_empty());
+                let dec: u64 = cks.decrypt(reg);
+                assert_eq!(
+                    dec, *clear_reg,
+                    "Invalid register {i} after adding {delta} at index {clear_index}, max: {max}"
+                );
+            }
+        }
+    }
+}
+
+pub(crate) fn default_bitset_conditional_insert_test<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<
+        (
+            &'a mut RadixCiphertext,
+            &'a RadixCiphertext,
+            &'a BooleanBlock,
+        ),
+        (),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let sks = Arc::new(sks);
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+    let num_bits = modulus.ilog2() as u64;
+
+    for _ in 0..nb_tests {
+        let mut clear_bitset = rng.gen_range(0..modulus);
+        let mut bitset = cks.encrypt(clear_bitset);
+
+        for _ in 0..4 {
+            // Mostly in range indices, with some that must be clamped
+            let clear_index = if rng.gen_bool(0.8) {
+                rng.gen_range(0..num_bits)
+            } else {
+                rng.gen_range(0..modulus)
+            };
+            let clear_condition = rng.gen_bool(0.5);
+            let index = cks.encrypt(clear_index);
+            let condition = cks.encrypt_bool(clear_condition);
+
+            executor.execute((&mut bitset, &index, &condition));
+            if clear_condition {
+                clear_bitset |= 1 << clear_index.min(num_bits - 1);
+            }
+
+            assert!(bitset.block_carries_are_empty());
+            let decrypted: u64 = cks.decrypt(&bitset);
+            assert_eq!(
+                decrypted, clear_bitset,
+                "Invalid bitset after inserting {clear_index} with condition {clear_condition}"
+            );
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::core_crypto::prelude::UnsignedInteger;
 use crate::integer::block_decomposition::{BlockDecomposer, Decomposable, DecomposableInto};
+use crate::integer::server_key::num_bits_to_represent_unsigned_value;
 use crate::integer::{BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey};
 use crate::prelude::CastInto;
 use crate::shortint::Ciphertext;
@@ -271,6 +272,818 @@ impl ServerKey {
         }
     }
 
+    /// `match` an encrypted selector to one of the encrypted `cases`
+    ///
+    /// Each case is a pair `(key, value)`, the result encrypts the `value` of the case whose
+    /// `key` is equal to `selector`, or `default` if no case matches. When several cases have
+    /// the same key, the first one wins, like the arms of a `match`.
+    ///
+    /// This is the multi-way version of `if_then_else_parallelized`, for selecting between
+    /// ciphertexts (unlike [Self::match_value_parallelized], which maps the input to clear
+    /// values):
+    ///
+    /// - the equality of `selector` with every key is computed at once, in parallel
+    /// - the cases are then folded pairwise with a balanced tree of cmuxes, where each node keeps
+    ///   the value of its left child if the left child matched, and the value of its right child
+    ///   otherwise, along with whether one of the two matched
+    /// - the root is finally selected against `default`
+    ///
+    /// So the critical path has `O(log N)` cmux levels, instead of the `O(N)` levels of a chain
+    /// of selections.
+    ///
+    /// Keys that cannot be represented with the number of blocks of `selector` never match.
+    ///
+    /// If `cases` is empty, a clone of `default` is returned, otherwise the returned ciphertext
+    /// has clean blocks.
+    ///
+    /// # Panics
+    ///
+    /// - if the values of the cases and the default do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let a = cks.encrypt(10u8);
+    /// let b = cks.encrypt(20u8);
+    /// let c = cks.encrypt(30u8);
+    /// let default = cks.encrypt(255u8);
+    /// let cases = [(0, &a), (1, &b), (5, &c)];
+    ///
+    /// let selector = cks.encrypt(5u8);
+    /// let ct_res = sks.match_cases_parallelized(&selector, &cases, &default);
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 30);
+    ///
+    /// let selector = cks.encrypt(2u8);
+    /// let ct_res = sks.match_cases_parallelized(&selector, &cases, &default);
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 255);
+    /// ```
+    pub fn match_cases_parallelized<T>(
+        &self,
+        selector: &RadixCiphertext,
+        cases: &[(u64, &T)],
+        default: &T,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = default.blocks().len();
+        assert!(
+            cases
+                .iter()
+                .all(|(_, value)| value.blocks().len() == num_blocks),
+            "All the cases and the default must have the same number of blocks"
+        );
+
+        if cases.is_empty() {
+            return default.clone();
+        }
+
+        let message_modulus = self.message_modulus().0;
+        let selector_modulus = message_modulus.checked_pow(selector.blocks.len() as u32);
+        let cases = cases
+            .iter()
+            .filter(|(key, _)| selector_modulus.is_none_or(|modulus| *key < modulus))
+            .collect::<Vec<_>>();
+
+        let clean = |ct: &T| {
+            let mut ct = ct.clone();
+            if !ct.block_carries_are_empty() {
+                self.full_propagate_parallelized(&mut ct);
+            }
+            ct
+        };
+
+        if cases.is_empty() {
+            return clean(default);
+        }
+
+        let mut tmp_selector;
+        let selector = if selector.block_carries_are_empty() {
+            selector
+        } else {
+            tmp_selector = selector.clone();
+            self.full_propagate_parallelized(&mut tmp_selector);
+            &tmp_selector
+        };
+
+        let ((selectors, values), default) = rayon::join(
+            || {
+                rayon::join(
+                    || {
+                        self.compute_equality_selectors(
+                            selector,
+                            cases.par_iter().map(|(key, _)| *key),
+                        )
+                    },
+                    || {
+                        cases
+                            .par_iter()
+                            .map(|(_, value)| clean(value))
+                            .collect::<Vec<_>>()
+                    },
+                )
+            },
+            || clean(default),
+        );
+
+        let mut nodes = selectors.into_iter().zip(values).collect::<Vec<_>>();
+        while nodes.len() > 1 {
+            nodes = nodes
+                .into_par_iter()
+                .chunks(2)
+                .map(|mut pair| {
+                    if pair.len() == 1 {
+                        return pair.pop().unwrap();
+                    }
+                    let (right_matched, right_value) = pair.pop().unwrap();
+                    let (left_matched, left_value) = pair.pop().unwrap();
+                    rayon::join(
+                        || self.boolean_bitor(&left_matched, &right_matched),
+                        || {
+                            self.unchecked_if_then_else_parallelized(
+                                &left_matched,
+                                &left_value,
+                                &right_value,
+                            )
+                        },
+                    )
+                })
+                .collect();
+        }
+
+        let (matched, value) = nodes.pop().unwrap();
+        self.unchecked_if_then_else_parallelized(&matched, &value, &default)
+    }
+
+    /// Applies to `x` the transform of the arm whose tag matches the encrypted `tag`
+    ///
+    /// Each arm of `transforms` is a pair `(tag_value, func)`, where `func` is a lookup table
+    /// function applied to each block of `x` independently (its outputs are taken modulo the
+    /// message modulus). The result is the transformed `x` of the arm whose `tag_value` is equal
+    /// to `tag`.
+    ///
+    /// - If no arm matches `tag`, the result encrypts 0.
+    /// - Arms with a `tag_value` that `tag` cannot hold never match.
+    ///
+    /// All the arms are applied (in parallel) to `x`, so the cost grows linearly with the number
+    /// of arms: `2 * x.blocks().len()` PBS per arm, on top of the tag comparisons and the final
+    /// sum of the masked results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if two arms have the same tag value
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let x = 0b00_01_10_11u8;
+    /// let ct_x = cks.encrypt(x);
+    ///
+    /// let increment = |block: u64| block + 1;
+    /// let double = |block: u64| block * 2;
+    /// let transforms: [(u64, &(dyn Fn(u64) -> u64 + Sync)); 2] = [(0, &increment), (1, &double)];
+    ///
+    /// let ct_tag = cks.encrypt(1u8);
+    /// let ct_res = sks.match_transform_parallelized(&ct_tag, &transforms, &ct_x);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 0b00_10_00_10);
+    ///
+    /// // No arm matches tag 2
+    /// let ct_tag = cks.encrypt(2u8);
+    /// let ct_res = sks.match_transform_parallelized(&ct_tag, &transforms, &ct_x);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 0);
+    /// ```
+    pub fn match_transform_parallelized(
+        &self,
+        tag: &RadixCiphertext,
+        transforms: &[(u64, &(dyn Fn(u64) -> u64 + Sync))],
+        x: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        assert!(
+            transforms
+                .iter()
+                .map(|(tag_value, _)| tag_value)
+                .all_unique(),
+            "Tag values of the arms must be unique"
+        );
+
+        let num_tag_blocks = tag.blocks.len() as u32;
+        let tag_modulus = self.message_modulus().0.checked_pow(num_tag_blocks);
+        let transforms = transforms
+            .iter()
+            .filter(|(tag_value, _)| tag_modulus.is_none_or(|modulus| *tag_value < modulus))
+            .collect::<Vec<_>>();
+
+        if transforms.is_empty() {
+            return self.create_trivial_zero_radix(x.blocks.len());
+        }
+
+        let mut tmp_tag;
+        let tag = if tag.block_carries_are_empty() {
+            tag
+        } else {
+            tmp_tag = tag.clone();
+            self.full_propagate_parallelized(&mut tmp_tag);
+            &tmp_tag
+        };
+
+        let mut tmp_x;
+        let x = if x.block_carries_are_empty() {
+            x
+        } else {
+            tmp_x = x.clone();
+            self.full_propagate_parallelized(&mut tmp_x);
+            &tmp_x
+        };
+
+        let message_modulus = self.message_modulus().0;
+        let (selectors, transformed) = rayon::join(
+            || {
+                self.compute_equality_selectors(
+                    tag,
+                    transforms.par_iter().map(|(tag_value, _)| *tag_value),
+                )
+            },
+            || {
+                transforms
+                    .par_iter()
+                    .map(|(_, func)| {
+                        let lut = self
+                            .key
+                            .generate_lookup_table(|block| func(block) % message_modulus);
+                        let blocks = x
+                            .blocks
+                            .par_iter()
+                            .map(|block| self.key.apply_lookup_table(block, &lut))
+                            .collect::<Vec<_>>();
+                        RadixCiphertext::from(blocks)
+                    })
+                    .collect::<Vec<_>>()
+            },
+        );
+
+        let masked = transformed
+            .par_iter()
+            .zip(selectors.par_iter())
+            .map(|(ct, selector)| self.mask_with_selector(ct, selector))
+            .collect::<Vec<_>>();
+
+        self.sum_one_hot_vector(masked)
+    }
+
+    /// Looks up the encrypted `key` in a map of clear `(key, value)` entries
+    ///
+    /// Returns a radix ciphertext with `num_blocks` blocks encrypting the value of the entry
+    /// whose key is equal to `key`, or `default` if no entry matches. Values (and `default`)
+    /// are truncated to `num_blocks` blocks.
+    ///
+    /// Entries with a key that `key` cannot hold never match.
+    ///
+    /// # Capacity
+    ///
+    /// When all the values that `key` can hold fit in a single block (i.e.
+    /// `message_modulus^key.blocks.len() <= message_modulus * carry_modulus`), the key blocks
+    /// are packed into one block and each output block is computed by a single PBS of that
+    /// packed block. As the whole map is encoded in the lookup tables, the cost does not depend
+    /// on the number of entries, which is at most `message_modulus * carry_modulus` distinct
+    /// keys in that case (e.g. 16 entries for a 2 blocks key with 2_2 parameters).
+    ///
+    /// Otherwise, the key is compared to each entry and the matching value is selected
+    /// like in [Self::match_value_or_parallelized], so the cost grows linearly with the
+    /// number of entries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if two entries have the same key
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 2 * 2 = 4 bits of message for the key, it fits in a single block
+    /// let size = 2;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let entries = [(1u64, 200u64), (7, 42), (12, 1000)];
+    /// let default = 5;
+    ///
+    /// let ct_key = cks.encrypt(7u8);
+    /// let ct_res = sks.map_lookup_parallelized(&ct_key, &entries, default, 5);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 42);
+    ///
+    /// let ct_key = cks.encrypt(3u8);
+    /// let ct_res = sks.map_lookup_parallelized(&ct_key, &entries, default, 5);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, default);
+    /// ```
+    pub fn map_lookup_parallelized(
+        &self,
+        key: &RadixCiphertext,
+        entries: &[(u64, u64)],
+        default: u64,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        assert!(
+            entries.iter().map(|(entry_key, _)| entry_key).all_unique(),
+            "Keys of the entries must be unique"
+        );
+
+        let message_modulus = self.message_modulus().0;
+        let num_key_blocks = key.blocks.len() as u32;
+        let key_modulus = message_modulus.checked_pow(num_key_blocks);
+        let entries = entries
+            .iter()
+            .copied()
+            .filter(|(entry_key, _)| key_modulus.is_none_or(|modulus| *entry_key < modulus))
+            .collect::<Vec<_>>();
+
+        if entries.is_empty() || num_blocks == 0 {
+            return self.create_trivial_radix(default, num_blocks);
+        }
+
+        let mut tmp_key;
+        let key = if key.block_carries_are_empty() {
+            key
+        } else {
+            tmp_key = key.clone();
+            self.full_propagate_parallelized(&mut tmp_key);
+            &tmp_key
+        };
+
+        // Packing the key blocks as sum(block_i * message_modulus^i) gives a noise level of
+        // sum(message_modulus^i)
+        let packing_noise_level = (0..num_key_blocks)
+            .map(|i| message_modulus.pow(i))
+            .sum::<u64>();
+        let fits_in_one_block = key_modulus.is_some_and(|modulus| {
+            modulus <= message_modulus * self.carry_modulus().0
+                && packing_noise_level <= self.key.max_noise_level.get()
+        });
+
+        if !fits_in_one_block {
+            let matches = MatchValues(entries);
+            let result = self.unchecked_match_value_or_parallelized(key, &matches, default);
+            return self.cast_to_unsigned(result, num_blocks);
+        }
+
+        let mut packed = key.blocks.last().unwrap().clone();
+        for block in key.blocks.iter().rev().skip(1) {
+            self.key
+                .unchecked_scalar_mul_assign(&mut packed, message_modulus as u8);
+            self.key.unchecked_add_assign(&mut packed, block);
+        }
+
+        // For each possible key value, the blocks of the output value
+        let table = (0..key_modulus.unwrap())
+            .map(|key_value| {
+                let value = entries
+                    .iter()
+                    .find(|(entry_key, _)| *entry_key == key_value)
+                    .map_or(default, |(_, value)| *value);
+                BlockDecomposer::with_block_count(value, message_modulus.ilog2(), num_blocks)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let blocks = (0..num_blocks)
+            .into_par_iter()
+            .map(|block_index| {
+                let first = table[0][block_index];
+                if table.iter().all(|blocks| blocks[block_index] == first) {
+                    // The block does not depend on the key
+                    return self.key.create_trivial(first);
+                }
+                let lut = self.key.generate_lookup_table(|key_value| {
+                    table
+                        .get(key_value as usize)
+                        .map_or(0, |blocks| blocks[block_index])
+                });
+                self.key.apply_lookup_table(&packed, &lut)
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Quantizes the encrypted value, mapping it to the level of the interval it falls in
+    ///
+    /// The `thresholds` delimit `thresholds.len() + 1` intervals, the returned radix ciphertext
+    /// has `num_blocks` blocks and encrypts:
+    ///
+    /// - `levels[0]` if `ct < thresholds[0]`
+    /// - `levels[i]` if `thresholds[i - 1] <= ct < thresholds[i]`
+    /// - `levels[thresholds.len()]` if `ct >= thresholds[thresholds.len() - 1]`
+    ///
+    /// The index of the interval is computed as the number of thresholds that are lower or equal
+    /// to `ct` (one scalar comparison per threshold, all done in parallel), then the level is
+    /// gathered using [Self::map_lookup_parallelized]. Levels are truncated to `num_blocks`
+    /// blocks.
+    ///
+    /// # Monotonicity
+    ///
+    /// The thresholds must be strictly increasing, so that the number of thresholds lower or
+    /// equal to `ct` is the index of its interval. The levels do not need to be monotonic.
+    ///
+    /// # Panics
+    ///
+    /// - if the thresholds are not strictly increasing
+    /// - if `levels.len() != thresholds.len() + 1`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let thresholds = [64, 128, 192];
+    /// let levels = [0, 1, 2, 3];
+    ///
+    /// let ct = cks.encrypt(150u8);
+    /// let ct_res = sks.quantize_parallelized(&ct, &thresholds, &levels, 1);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 2);
+    /// ```
+    pub fn quantize_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        thresholds: &[u64],
+        levels: &[u64],
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        assert!(
+            thresholds.windows(2).all(|pair| pair[0] < pair[1]),
+            "Thresholds must be strictly increasing"
+        );
+        assert_eq!(
+            levels.len(),
+            thresholds.len() + 1,
+            "There must be exactly one more level than thresholds"
+        );
+
+        if thresholds.is_empty() {
+            return self.create_trivial_radix(levels[0], num_blocks);
+        }
+
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        // The index can be at most thresholds.len()
+        let num_index_blocks = num_bits_to_represent_unsigned_value(thresholds.len())
+            .div_ceil(self.message_modulus().0.ilog2() as usize);
+        let index_terms = thresholds
+            .par_iter()
+            .map(|threshold| {
+                self.scalar_ge_parallelized(ct, *threshold)
+                    .into_radix::<RadixCiphertext>(num_index_blocks, self)
+            })
+            .collect::<Vec<_>>();
+        let index = self
+            .unchecked_sum_ciphertexts_vec_parallelized(index_terms)
+            .unwrap();
+
+        let entries = levels
+            .iter()
+            .enumerate()
+            .map(|(interval_index, level)| (interval_index as u64, *level))
+            .collect::<Vec<_>>();
+        self.map_lookup_parallelized(&index, &entries, levels[0], num_blocks)
+    }
+
+    /// Computes the modular inverse of the encrypted value modulo the clear prime `p`
+    ///
+    /// The returned radix ciphertext has the same number of blocks as `ct` and encrypts
+    /// `x^-1 mod p`, that is the value `y` in `[1, p)` such that `x * y = 1 mod p`.
+    ///
+    /// The inverse is computed as a table lookup over all the residues mod `p` using
+    /// [Self::map_lookup_parallelized]: when `ct` fits in a single block the whole table
+    /// is a single lookup table per output block, otherwise each residue is compared
+    /// to `ct`, so the cost grows linearly with `p`.
+    ///
+    /// # Size of `p`
+    ///
+    /// `p` must be a prime that is at most the message range of `ct`
+    /// (`message_modulus^num_blocks`), so that all the residues and their inverses can be
+    /// represented. It is meant for small primes, as the table has `p` entries.
+    ///
+    /// # Zero and non reduced inputs
+    ///
+    /// 0 has no inverse, by convention it is mapped to 0. Inputs that are not reduced
+    /// (i.e. `>= p`) are not part of the table and are also mapped to 0.
+    ///
+    /// # Panics
+    ///
+    /// - if `p` is greater than the message range of `ct`
+    /// - if `p` is not a prime
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let p = 13;
+    /// let ct = cks.encrypt(5u8);
+    /// let ct_res = sks.modinv_scalar_parallelized(&ct, p);
+    ///
+    /// // Decrypt:
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, 8); // 5 * 8 = 40 = 3 * 13 + 1
+    /// ```
+    pub fn modinv_scalar_parallelized(&self, ct: &RadixCiphertext, p: u64) -> RadixCiphertext {
+        let num_blocks = ct.blocks.len();
+        let modulus = self.message_modulus().0.checked_pow(num_blocks as u32);
+        assert!(
+            modulus.is_none_or(|modulus| p <= modulus),
+            "p ({p}) must not be greater than the message range of the input ({modulus:?})"
+        );
+        assert!(is_prime(p), "p ({p}) must be a prime");
+
+        let entries = (1..p).map(|x| (x, clear_modinv(x, p))).collect::<Vec<_>>();
+        self.map_lookup_parallelized(ct, &entries, 0, num_blocks)
+    }
+
+    /// Returns the base-64 digit of `value` at position `digit_pos`, mapped through `alphabet`,
+    /// if condition is true, otherwise returns 0
+    ///
+    /// - If condition == 1, the returned ciphertext encrypts `alphabet[(value / 64^digit_pos) %
+    ///   64]`
+    /// - If condition == 0, the returned ciphertext encrypts 0
+    ///
+    /// The returned radix ciphertext has the same number of blocks as `value`.
+    ///
+    /// As 64 is a power of two, the division and remainder by `64^digit_pos` and 64 are
+    /// computed with a scalar right shift by `6 * digit_pos` bits and by keeping the 6 low bits.
+    /// The digit is then trimmed to the blocks that can hold 6 bits before being mapped
+    /// through `alphabet` with [Self::map_lookup_parallelized], so that the lookup only
+    /// depends on the digit, and the result is zeroed when condition is false.
+    ///
+    /// To ensure correct results, condition must encrypt either 0 or 1
+    /// (e.g result from a comparison).
+    ///
+    /// # Digit position and alphabet
+    ///
+    /// - The digit must start inside `value`: `6 * digit_pos` must be smaller than the number of
+    ///   bits of `value`. When `value` has fewer bits than `6 * (digit_pos + 1)`, the missing high
+    ///   bits of the last digit are 0.
+    /// - `alphabet` must have exactly 64 entries, one for each possible digit.
+    /// - Each entry of `alphabet` must fit in the number of bits of `value`.
+    ///
+    /// # Panics
+    ///
+    /// - if the digit position or the alphabet does not respect the constraints above
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 8 * 2 = 16 bits of message
+    /// let size = 8;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// // The standard base64 alphabet, as ASCII codes
+    /// let alphabet = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"
+    ///     .iter()
+    ///     .map(|c| *c as u64)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// // 1000 = 15 * 64 + 40
+    /// let ct = cks.encrypt(1000u16);
+    /// let emit = cks.encrypt_bool(true);
+    /// let skip = cks.encrypt_bool(false);
+    ///
+    /// let ct_low = sks.emit_digit_if_parallelized(&ct, 0, &alphabet, &emit);
+    /// let ct_high = sks.emit_digit_if_parallelized(&ct, 1, &alphabet, &emit);
+    /// let ct_skipped = sks.emit_digit_if_parallelized(&ct, 1, &alphabet, &skip);
+    ///
+    /// // Decrypt:
+    /// let low: u16 = cks.decrypt(&ct_low);
+    /// let high: u16 = cks.decrypt(&ct_high);
+    /// let skipped: u16 = cks.decrypt(&ct_skipped);
+    /// assert_eq!(low, u16::from(b'o'));
+    /// assert_eq!(high, u16::from(b'P'));
+    /// assert_eq!(skipped, 0);
+    /// ```
+    pub fn emit_digit_if_parallelized(
+        &self,
+        value: &RadixCiphertext,
+        digit_pos: u32,
+        alphabet: &[u64],
+        condition: &BooleanBlock,
+    ) -> RadixCiphertext {
+        const DIGIT_BITS: u32 = 6;
+
+        assert_eq!(
+            alphabet.len(),
+            1 << DIGIT_BITS,
+            "The alphabet must have exactly 64 entries"
+        );
+        let num_blocks = value.blocks.len();
+        let bits_in_block = self.message_modulus().0.ilog2();
+        let num_bits = bits_in_block * num_blocks as u32;
+        let shift = digit_pos
+            .checked_mul(DIGIT_BITS)
+            .filter(|shift| *shift < num_bits)
+            .unwrap_or_else(|| {
+                panic!(
+                    "The digit at position {digit_pos} does not start inside the \
+                    {num_bits} bits of the value"
+                )
+            });
+        assert!(
+            alphabet
+                .iter()
+                .all(|entry| num_bits >= u64::BITS || *entry < (1u64 << num_bits)),
+            "Each entry of the alphabet must fit in the {num_bits} bits of the value"
+        );
+
+        let mut digit = self.scalar_right_shift_parallelized(value, shift);
+        let digit_blocks = DIGIT_BITS.div_ceil(bits_in_block) as usize;
+        if digit_blocks < num_blocks {
+            self.trim_radix_blocks_msb_assign(&mut digit, num_blocks - digit_blocks);
+        }
+        if digit.blocks.len() as u32 * bits_in_block > DIGIT_BITS {
+            digit = self.scalar_bitand_parallelized(&digit, (1u64 << DIGIT_BITS) - 1);
+        }
+
+        let entries = alphabet
+            .iter()
+            .enumerate()
+            .map(|(digit, entry)| (digit as u64, *entry))
+            .collect::<Vec<_>>();
+        let mut result = self.map_lookup_parallelized(&digit, &entries, 0, num_blocks);
+        self.zero_out_if_condition_is_false(&mut result, &condition.0);
+        result
+    }
+
+    /// Returns the next state and the output of a Mealy machine, given its current encrypted
+    /// `state` and encrypted `input`
+    ///
+    /// The transition and output functions are given as clear tables, indexed by
+    /// `[state][input]`: the returned pair encrypts
+    /// `(next_table[state][input], out_table[state][input])`.
+    ///
+    /// The `(state, input)` cell is gathered like in [Self::sample2d_parallelized], the tables
+    /// being seen as row-major 2D buffers with one row per state and one column per input: the
+    /// flat index of the cell is computed, then compared with every cell. The resulting
+    /// selectors are shared by the two tables, and turned into the clear values of the cells
+    /// like in [Self::match_value_parallelized].
+    ///
+    /// If `state` or `input` is out of the range of the tables, both results encrypt 0.
+    ///
+    /// - The next state has the same number of blocks as `state`
+    /// - The output has the number of blocks needed to represent the largest value of `out_table`
+    ///
+    /// The returned ciphertexts have clean blocks.
+    ///
+    /// # Cost
+    ///
+    /// The index computation only depends on the number of blocks of `state` and `input`. Then,
+    /// one equality check and one lookup per table are done for each cell, so the cost grows
+    /// linearly with `num_states * num_inputs`. The cells are processed in parallel, and the two
+    /// tables are gathered in parallel.
+    ///
+    /// # Panics
+    ///
+    /// - if the tables are empty, not rectangular, or do not have the same shape
+    /// - if a value of `next_table` does not fit in the number of blocks of `state`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// // Detects rising edges of a binary signal, the state is the previous input
+    /// let next_table = vec![vec![0, 1], vec![0, 1]];
+    /// let out_table = vec![vec![0, 1], vec![0, 0]];
+    ///
+    /// let mut state = cks.encrypt(0u8);
+    /// let mut outputs = vec![];
+    /// for input in [1u8, 1, 0, 1] {
+    ///     let input = cks.encrypt(input);
+    ///     let (next_state, output) =
+    ///         sks.mealy_step_parallelized(&state, &input, &next_table, &out_table);
+    ///     state = next_state;
+    ///     outputs.push(output);
+    /// }
+    ///
+    /// // Decrypt:
+    /// let dec = outputs
+    ///     .iter()
+    ///     .map(|ct| cks.decrypt::<u8>(ct))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(dec, vec![1, 0, 0, 1]);
+    /// ```
+    pub fn mealy_step_parallelized(
+        &self,
+        state: &RadixCiphertext,
+        input: &RadixCiphertext,
+        next_table: &[Vec<u64>],
+        out_table: &[Vec<u64>],
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        let num_states = next_table.len();
+        assert!(num_states > 0, "The tables must not be empty");
+        let num_inputs = next_table[0].len();
+        assert!(num_inputs > 0, "The tables must not be empty");
+        assert!(
+            next_table.iter().all(|row| row.len() == num_inputs),
+            "next_table must be rectangular"
+        );
+        assert!(
+            out_table.len() == num_states && out_table.iter().all(|row| row.len() == num_inputs),
+            "out_table must have the same shape as next_table ({num_states}x{num_inputs})"
+        );
+
+        let num_state_blocks = state.blocks.len();
+        let max_next_state = next_table.iter().flatten().copied().max().unwrap();
+        assert!(
+            self.num_blocks_to_represent_unsigned_value(max_next_state) <= num_state_blocks,
+            "The next state {max_next_state} does not fit in the {num_state_blocks} blocks of state"
+        );
+        let max_output = out_table.iter().flatten().copied().max().unwrap();
+        let num_output_blocks = self.num_blocks_to_represent_unsigned_value(max_output);
+
+        let (index, input_is_in_range) = self.compute_2d_index(input, state, num_inputs);
+        let selectors = self.compute_index_selectors(&index, num_states * num_inputs);
+
+        let gather = |table: &[Vec<u64>], max_value: u64, num_blocks: usize| {
+            if max_value == 0 {
+                // 0 is the only possible value, whatever the cell is
+                return self.create_trivial_zero_radix::<RadixCiphertext>(num_blocks);
+            }
+            let num_value_blocks = self.num_blocks_to_represent_unsigned_value(max_value);
+            let values = table.iter().flatten().copied().collect::<Vec<_>>();
+            let possible_results = self.create_possible_results(
+                num_value_blocks,
+                selectors.par_iter().cloned().zip(values.into_par_iter()),
+            );
+            let result: RadixCiphertext = self.aggregate_one_hot_vector(possible_results);
+            self.cast_to_unsigned(result, num_blocks)
+        };
+
+        let (next_state, output) = rayon::join(
+            || gather(next_table, max_next_state, num_state_blocks),
+            || gather(out_table, max_output, num_output_blocks),
+        );
+
+        // Without the range check on the input, an input past the end of a row would read the
+        // transition of the next state
+        rayon::join(
+            || self.mask_with_selector(&next_state, &input_is_in_range),
+            || self.mask_with_selector(&output, &input_is_in_range),
+        )
+    }
+
     /// Returns an encrypted `true` if the encrypted `value` is found in the encrypted slice
     pub fn unchecked_contains_parallelized<T>(&self, cts: &[T], value: &T) -> BooleanBlock
     where
@@ -1071,7 +1884,7 @@ impl ServerKey {
     /// otherwise it will be 0.
     ///
     /// Requires ct to have empty carries
-    fn compute_equality_selectors<T, Iter, Clear>(
+    pub(crate) fn compute_equality_selectors<T, Iter, Clear>(
         &self,
         ct: &T,
         possible_input_values: Iter,
@@ -1338,3 +2151,23 @@ impl ServerKey {
         }
     }
 }
+
+fn is_prime(p: u64) -> bool {
+    p >= 2 && (2..=p.isqrt()).all(|d| p % d != 0)
+}
+
+/// Inverse of `x` modulo the prime `p`, computed as `x^(p - 2) mod p` (Fermat)
+fn clear_modinv(x: u64, p: u64) -> u64 {
+    let p = p as u128;
+    let mut base = x as u128 % p;
+    let mut exponent = p - 2;
+    let mut result = 1u128;
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            result = result * base % p;
+        }
+        base = base * base % p;
+        exponent >>= 1;
+    }
+    result as u64
+}
@@ -0,0 +1,1177 @@
+use crate::integer::prelude::ServerKeyDefaultCMux;
+use crate::integer::server_key::radix_parallel::bit_extractor::BitExtractor;
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Returns the row of the `matrix` at the encrypted `index`
+    ///
+    /// - The equality between the index and each row position is computed once, and then used to
+    ///   select all the columns of the row in parallel.
+    /// - If the index is out of range, all the elements of the returned row encrypt 0.
+    ///
+    /// # Panics
+    ///
+    /// - if the matrix is not rectangular
+    /// - if the elements of the matrix do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clear_matrix = [[1u8, 2, 3], [4, 5, 6]];
+    /// let matrix = clear_matrix
+    ///     .iter()
+    ///     .map(|row| row.iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let index = cks.encrypt(1u8);
+    ///
+    /// let row = sks.select_matrix_row_parallelized(&index, &matrix);
+    ///
+    /// // Decrypt:
+    /// let dec = row.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u8>>();
+    /// assert_eq!(dec, clear_matrix[1]);
+    /// ```
+    pub fn select_matrix_row_parallelized<T>(
+        &self,
+        index: &RadixCiphertext,
+        matrix: &[Vec<T>],
+    ) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let Some(first_row) = matrix.first() else {
+            return vec![];
+        };
+        let num_columns = first_row.len();
+        assert!(
+            matrix.iter().all(|row| row.len() == num_columns),
+            "All rows of the matrix must have the same length"
+        );
+        if num_columns == 0 {
+            return vec![];
+        }
+        let num_blocks = first_row[0].blocks().len();
+        assert!(
+            matrix
+                .iter()
+                .flatten()
+                .all(|ct| ct.blocks().len() == num_blocks),
+            "All elements of the matrix must have the same number of blocks"
+        );
+
+        let selectors = self.compute_index_selectors(index, matrix.len());
+
+        (0..num_columns)
+            .into_par_iter()
+            .map(|column| {
+                let masked_column = matrix
+                    .par_iter()
+                    .zip(selectors.par_iter())
+                    .map(|(row, selector)| self.mask_with_selector(&row[column], selector))
+                    .collect::<Vec<_>>();
+                self.sum_one_hot_vector(masked_column)
+            })
+            .collect()
+    }
+
+    /// Returns the element of the ring `buffer` at position `head + offset`
+    ///
+    /// That is, `buffer[(head + offset) % buffer.len()]`, where `head` is encrypted and `offset` is
+    /// clear.
+    ///
+    /// - The effective index is computed with a modular addition against the clear length of the
+    ///   buffer, so the result is correct even when the length is not a power of two, and whatever
+    ///   the value of `head` (it does not need to be smaller than the length).
+    /// - When the length is a power of two, the modular reduction is only a masking of the low
+    ///   bits, which is much cheaper than for other lengths.
+    ///
+    /// The returned ciphertext has clean blocks
+    ///
+    /// # Panics
+    ///
+    /// - if the buffer is empty
+    /// - if the elements of the buffer do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clear_buffer = [10u8, 20, 30, 40, 50];
+    /// let buffer = clear_buffer
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let head = cks.encrypt(3u8);
+    ///
+    /// let value = sks.ring_read_parallelized(&buffer, &head, 4);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&value);
+    /// assert_eq!(dec, clear_buffer[(3 + 4) % 5]);
+    /// ```
+    pub fn ring_read_parallelized<T>(
+        &self,
+        buffer: &[T],
+        head: &RadixCiphertext,
+        offset: usize,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!buffer.is_empty(), "The buffer must not be empty");
+        let num_blocks = buffer[0].blocks().len();
+        assert!(
+            buffer.iter().all(|ct| ct.blocks().len() == num_blocks),
+            "All elements of the buffer must have the same number of blocks"
+        );
+
+        let len = buffer.len();
+        if len == 1 {
+            let mut result = buffer[0].clone();
+            if !result.block_carries_are_empty() {
+                self.full_propagate_parallelized(&mut result);
+            }
+            return result;
+        }
+
+        let offset = offset % len;
+
+        // Number of blocks needed to represent all the positions of the buffer
+        let message_modulus = u128::from(self.message_modulus().0);
+        let mut num_blocks_for_len = 0;
+        let mut num_representable = 1u128;
+        while num_representable < len as u128 {
+            num_representable *= message_modulus;
+            num_blocks_for_len += 1;
+        }
+
+        let index = if offset == 0 {
+            head.clone()
+        } else {
+            // One more block than the widest of head and offset, so that the sum does not wrap
+            let num_blocks_for_sum = head.blocks.len().max(num_blocks_for_len) + 1;
+            let extended_head = self.extend_radix_with_trivial_zero_blocks_msb(
+                head,
+                num_blocks_for_sum - head.blocks.len(),
+            );
+            self.scalar_add_parallelized(&extended_head, offset as u64)
+        };
+
+        let mut index = self.scalar_rem_parallelized(&index, len as u128);
+        // The remainder is smaller than the length, so the upper blocks encrypt 0
+        if index.blocks.len() > num_blocks_for_len {
+            let num_blocks_to_remove = index.blocks.len() - num_blocks_for_len;
+            self.trim_radix_blocks_msb_assign(&mut index, num_blocks_to_remove);
+        }
+
+        self.select_slice_element(&index, buffer)
+    }
+
+    /// Returns the element of `buffer` at position `base * stride`
+    ///
+    /// `base` is encrypted and `stride` is clear, the effective index is computed with a scalar
+    /// multiplication and the element is then selected like in [Self::ring_read_parallelized].
+    ///
+    /// - The multiplication is done on enough blocks to hold `base * stride`, so it never wraps
+    ///   around: if the effective index is out of range, the result encrypts 0, whatever the values
+    ///   of `base` and `stride`.
+    /// - A stride of 0 always returns a copy of the first element.
+    ///
+    /// The returned ciphertext has clean blocks
+    ///
+    /// # Panics
+    ///
+    /// - if the buffer is empty
+    /// - if the elements of the buffer do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clear_buffer = [10u8, 20, 30, 40, 50, 60, 70];
+    /// let buffer = clear_buffer
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let base = cks.encrypt(2u8);
+    ///
+    /// let value = sks.strided_gather_parallelized(&base, 3, &buffer);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&value);
+    /// assert_eq!(dec, clear_buffer[2 * 3]);
+    ///
+    /// // Out of range
+    /// let value = sks.strided_gather_parallelized(&base, 4, &buffer);
+    /// let dec: u8 = cks.decrypt(&value);
+    /// assert_eq!(dec, 0);
+    /// ```
+    pub fn strided_gather_parallelized<T>(
+        &self,
+        base: &RadixCiphertext,
+        stride: u64,
+        buffer: &[T],
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!buffer.is_empty(), "The buffer must not be empty");
+        let num_blocks = buffer[0].blocks().len();
+        assert!(
+            buffer.iter().all(|ct| ct.blocks().len() == num_blocks),
+            "All elements of the buffer must have the same number of blocks"
+        );
+
+        if stride == 0 {
+            let mut result = buffer[0].clone();
+            if !result.block_carries_are_empty() {
+                self.full_propagate_parallelized(&mut result);
+            }
+            return result;
+        }
+
+        let index = if stride == 1 {
+            base.clone()
+        } else {
+            // Number of blocks needed to represent the stride, so that the product does not wrap
+            let message_modulus = u128::from(self.message_modulus().0);
+            let mut num_blocks_for_stride = 0;
+            let mut num_representable = 1u128;
+            while num_representable <= u128::from(stride) {
+                num_representable *= message_modulus;
+                num_blocks_for_stride += 1;
+            }
+
+            let extended_base =
+                self.extend_radix_with_trivial_zero_blocks_msb(base, num_blocks_for_stride);
+            self.scalar_mul_parallelized(&extended_base, stride)
+        };
+
+        self.select_slice_element(&index, buffer)
+    }
+
+    /// Returns the element at the encrypted coordinates `(x, y)` of the row-major 2D `buffer`
+    ///
+    /// That is, `buffer[y * width + x]`, where `x` and `y` are encrypted and `width` is clear.
+    ///
+    /// - The flat index is computed with a scalar multiplication of `y` by `width` and an addition
+    ///   of `x`, on enough blocks so that it never wraps around. The element is then selected like
+    ///   in [Self::ring_read_parallelized].
+    /// - If `x >= width` or `y >= buffer.len() / width`, the result encrypts 0.
+    ///
+    /// The returned ciphertext has clean blocks
+    ///
+    /// # Cost
+    ///
+    /// The index computation only depends on the number of blocks of `x` and `y`. The selection
+    /// then compares the flat index with every position of the buffer and masks every element, so
+    /// its cost grows linearly with `buffer.len()` (and with the number of blocks of the
+    /// elements). The comparisons and the maskings are all done in parallel.
+    ///
+    /// # Panics
+    ///
+    /// - if the width is 0
+    /// - if the length of the buffer is not a multiple of the width
+    /// - if the elements of the buffer do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// // A 3x2 image
+    /// let width = 3;
+    /// let clear_buffer = [10u8, 20, 30, 40, 50, 60];
+    /// let buffer = clear_buffer
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let x = cks.encrypt(2u8);
+    /// let y = cks.encrypt(1u8);
+    ///
+    /// let value = sks.sample2d_parallelized(&buffer, width, &x, &y);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&value);
+    /// assert_eq!(dec, clear_buffer[width + 2]);
+    ///
+    /// // Out of range
+    /// let x = cks.encrypt(3u8);
+    /// let value = sks.sample2d_parallelized(&buffer, width, &x, &y);
+    /// let dec: u8 = cks.decrypt(&value);
+    /// assert_eq!(dec, 0);
+    /// ```
+    pub fn sample2d_parallelized<T>(
+        &self,
+        buffer: &[T],
+        width: usize,
+        x: &RadixCiphertext,
+        y: &RadixCiphertext,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_ne!(width, 0, "The width must not be 0");
+        assert_eq!(
+            buffer.len() % width,
+            0,
+            "The length of the buffer must be a multiple of the width"
+        );
+        assert!(!buffer.is_empty(), "The buffer must not be empty");
+        let num_blocks = buffer[0].blocks().len();
+        assert!(
+            buffer.iter().all(|ct| ct.blocks().len() == num_blocks),
+            "All elements of the buffer must have the same number of blocks"
+        );
+
+        let (index, x_is_in_range) = self.compute_2d_index(x, y, width);
+
+        // Without the range check on x, an x past the end of a row would read the next row
+        let value = self.select_slice_element(&index, buffer);
+        self.mask_with_selector(&value, &x_is_in_range)
+    }
+
+    /// Returns the element of `values` at the encrypted `index`
+    ///
+    /// - The index can have any number of blocks: all its blocks are compared with the positions
+    ///   of the slice, so tables larger than what a single block can index (e.g. 300 elements
+    ///   with 2 bits of message per block) are supported, as long as the index has enough blocks
+    ///   to represent their positions.
+    /// - Positions that cannot be represented with the number of blocks of `index` are never
+    ///   selected, and if the index is out of range, the result encrypts 0.
+    ///
+    /// The returned ciphertext has clean blocks
+    ///
+    /// # Panics
+    ///
+    /// - if `values` is empty
+    /// - if the elements of `values` do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clear_values = [10u8, 20, 30, 40, 50, 60];
+    /// let values = clear_values
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// // 5 does not fit in a single block of 2 bits, the index uses all the blocks
+    /// let index = cks.encrypt(5u8);
+    ///
+    /// let value = sks.select_from_slice_parallelized(&index, &values);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&value);
+    /// assert_eq!(dec, clear_values[5]);
+    /// ```
+    pub fn select_from_slice_parallelized<T>(&self, index: &RadixCiphertext, values: &[T]) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!values.is_empty(), "The values must not be empty");
+        let num_blocks = values[0].blocks().len();
+        assert!(
+            values.iter().all(|ct| ct.blocks().len() == num_blocks),
+            "All the values must have the same number of blocks"
+        );
+
+        self.select_slice_element(index, values)
+    }
+
+    /// Returns the element of `table` at the encrypted `index`
+    ///
+    /// Unlike [Self::select_from_slice_parallelized], which compares the index with every
+    /// position, the bits of `index` are extracted and used to walk down a binary tree of
+    /// cmuxes ([Self::unchecked_if_then_else_parallelized]):
+    ///
+    /// - the table is padded with trivial zeros up to the next power of two
+    /// - at level `k`, each pair of neighbouring nodes is merged into one, keeping the right one
+    ///   if bit `k` of the index (starting from the least significant one) is 1, and the left one
+    ///   otherwise
+    ///
+    /// # Out of range index
+    ///
+    /// If the index is out of range (`index >= table.len()`), the result encrypts 0: indices
+    /// that fall in the padding select a trivial zero, and the result is zeroed when the index
+    /// has bits set beyond the ones used by the tree.
+    ///
+    /// The returned ciphertext has clean blocks
+    ///
+    /// # Cost
+    ///
+    /// The tree has `table.len().next_power_of_two() - 1` cmuxes, so the number of PBS is
+    /// `O(table.len())`, but only `ceil(log2(table.len()))` cmuxes are on the critical path, as
+    /// all the cmuxes of a level are done in parallel. Cmuxes between two padding elements are
+    /// skipped.
+    ///
+    /// # Panics
+    ///
+    /// - if `table` is empty
+    /// - if the elements of `table` do not all have the same number of blocks
+    /// - if the parameters do not have as much carry space as message space (required to
+    ///   extract the bits of the index)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clear_table = [10u8, 20, 30, 40, 50];
+    /// let table = clear_table
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let index = cks.encrypt(3u8);
+    /// let value = sks.get_at_parallelized(&table, &index);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&value);
+    /// assert_eq!(dec, clear_table[3]);
+    ///
+    /// // Out of range
+    /// let index = cks.encrypt(6u8);
+    /// let value = sks.get_at_parallelized(&table, &index);
+    /// let dec: u8 = cks.decrypt(&value);
+    /// assert_eq!(dec, 0);
+    /// ```
+    pub fn get_at_parallelized(
+        &self,
+        table: &[RadixCiphertext],
+        index: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        assert!(!table.is_empty(), "The table must not be empty");
+        let num_blocks = table[0].blocks.len();
+        assert!(
+            table.iter().all(|ct| ct.blocks.len() == num_blocks),
+            "All the elements of the table must have the same number of blocks"
+        );
+
+        let mut tmp_index;
+        let index = if index.block_carries_are_empty() {
+            index
+        } else {
+            tmp_index = index.clone();
+            self.full_propagate_parallelized(&mut tmp_index);
+            &tmp_index
+        };
+
+        let bits_per_block = self.message_modulus().0.ilog2() as usize;
+        let num_index_bits = bits_per_block * index.blocks.len();
+        // Only the positions the index can represent are reachable
+        let num_tree_bits = (table.len().next_power_of_two().ilog2() as usize).min(num_index_bits);
+        let index_may_overflow = num_index_bits > num_tree_bits;
+
+        let ((bits, leaves), is_in_range) = rayon::join(
+            || {
+                rayon::join(
+                    || {
+                        BitExtractor::new(&index.blocks, self, bits_per_block)
+                            .extract_n_bits(num_tree_bits)
+                            .into_iter()
+                            .map(BooleanBlock::new_unchecked)
+                            .collect::<Vec<_>>()
+                    },
+                    || {
+                        // `None` stands for a trivial zero, from the padding
+                        let mut leaves = table
+                            .par_iter()
+                            .take(1 << num_tree_bits)
+                            .map(|ct| {
+                                let mut ct = ct.clone();
+                                if !ct.block_carries_are_empty() {
+                                    self.full_propagate_parallelized(&mut ct);
+                                }
+                                Some(ct)
+                            })
+                            .collect::<Vec<_>>();
+                        leaves.resize(1 << num_tree_bits, None);
+                        leaves
+                    },
+                )
+            },
+            || index_may_overflow.then(|| self.scalar_lt_parallelized(index, table.len() as u64)),
+        );
+
+        let mut nodes = leaves;
+        for bit in &bits {
+            nodes = nodes
+                .par_chunks_exact(2)
+                .map(|pair| match (&pair[0], &pair[1]) {
+                    (Some(left), Some(right)) => {
+                        Some(self.unchecked_if_then_else_parallelized(bit, right, left))
+                    }
+                    (Some(left), None) => {
+                        let mut result = left.clone();
+                        self.zero_out_if(&mut result, &bit.0, |x| x == 1);
+                        Some(result)
+                    }
+                    (None, Some(right)) => {
+                        let mut result = right.clone();
+                        self.zero_out_if_condition_is_false(&mut result, &bit.0);
+                        Some(result)
+                    }
+                    (None, None) => None,
+                })
+                .collect();
+        }
+
+        let value = nodes
+            .pop()
+            .flatten()
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks));
+        match is_in_range {
+            Some(is_in_range) => self.mask_with_selector(&value, &is_in_range),
+            None => value,
+        }
+    }
+
+    /// Returns the sum of the elements of `table` at the encrypted `indices`,
+    /// each scaled by its clear weight
+    ///
+    /// That is, `sum_i weights[i] * table[indices[i]]`.
+    ///
+    /// - The gathers are independent and done in parallel, each one like
+    ///   [Self::select_from_slice_parallelized], then scaled with a scalar multiplication.
+    /// - An index that is out of range gathers 0, so it does not contribute to the sum.
+    /// - The products and the sum are computed modulo `message_modulus^num_blocks`, where
+    ///   `num_blocks` is the number of blocks of the elements of `table`: an accumulation that
+    ///   overflows wraps around, like the rest of the radix arithmetic.
+    /// - If there are no indices, the result encrypts 0.
+    ///
+    /// The returned ciphertext has clean blocks
+    ///
+    /// # Panics
+    ///
+    /// - if `table` is empty
+    /// - if the elements of `table` do not all have the same number of blocks
+    /// - if `indices` and `weights` do not have the same length
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clear_table = [1u8, 2, 3, 4, 5];
+    /// let table = clear_table
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let indices = [cks.encrypt(1u8), cks.encrypt(4u8), cks.encrypt(200u8)];
+    /// let weights = [10, 3, 7];
+    ///
+    /// let sum = sks.weighted_gather_sum_parallelized(&indices, &weights, &table);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&sum);
+    /// // The index 200 is out of range and does not contribute
+    /// assert_eq!(dec, 10 * 2 + 3 * 5);
+    /// ```
+    pub fn weighted_gather_sum_parallelized<T>(
+        &self,
+        indices: &[RadixCiphertext],
+        weights: &[u64],
+        table: &[T],
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!table.is_empty(), "The table must not be empty");
+        let num_blocks = table[0].blocks().len();
+        assert!(
+            table.iter().all(|ct| ct.blocks().len() == num_blocks),
+            "All the elements of the table must have the same number of blocks"
+        );
+        assert_eq!(
+            indices.len(),
+            weights.len(),
+            "There must be exactly one weight per index"
+        );
+
+        // Clean the table once, rather than in each gather
+        let mut tmp_table;
+        let table = if table.iter().all(T::block_carries_are_empty) {
+            table
+        } else {
+            tmp_table = table.to_vec();
+            tmp_table
+                .par_iter_mut()
+                .filter(|ct| !ct.block_carries_are_empty())
+                .for_each(|ct| self.full_propagate_parallelized(ct));
+            &tmp_table
+        };
+
+        let terms = indices
+            .par_iter()
+            .zip(weights.par_iter())
+            .filter(|(_, weight)| **weight != 0)
+            .map(|(index, weight)| {
+                let value = self.select_slice_element(index, table);
+                self.scalar_mul_parallelized(&value, *weight)
+            })
+            .collect::<Vec<_>>();
+
+        // The products have clean blocks
+        self.unchecked_sum_ciphertexts_vec_parallelized(terms)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks))
+    }
+
+    /// Returns the sum of the `leaf_values` masked by their `indicators`
+    ///
+    /// That is, `sum_i indicators[i] * leaf_values[i]`, which is the evaluation of a decision
+    /// tree where `indicators[i]` encrypts whether the path to the i-th leaf is taken.
+    ///
+    /// - The indicators are expected to be one-hot (exactly one encrypts true), but the sum is
+    ///   correct (modulo `message_modulus^num_blocks`, where `num_blocks` is the number of blocks
+    ///   of the leaves) whatever the number of true indicators.
+    /// - A single masking lookup table is shared by all the leaves, and the masked leaves are then
+    ///   added together in a balanced tree.
+    ///
+    /// To ensure correct results, indicators must encrypt either 0 or 1.
+    ///
+    /// The returned ciphertext has clean blocks
+    ///
+    /// # Panics
+    ///
+    /// - if there are no leaves
+    /// - if `indicators` and `leaf_values` do not have the same length
+    /// - if the leaves do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let clear_leaves = [10u8, 20, 30, 40];
+    /// let leaves = clear_leaves
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// // The path to the third leaf is taken
+    /// let indicators = [false, false, true, false]
+    ///     .iter()
+    ///     .map(|b| cks.encrypt_bool(*b))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let value = sks.tree_aggregate_parallelized(&indicators, &leaves);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&value);
+    /// assert_eq!(dec, 30);
+    /// ```
+    pub fn tree_aggregate_parallelized<T>(
+        &self,
+        indicators: &[BooleanBlock],
+        leaf_values: &[T],
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!leaf_values.is_empty(), "There must be at least one leaf");
+        assert_eq!(
+            indicators.len(),
+            leaf_values.len(),
+            "There must be one indicator per leaf"
+        );
+        let num_blocks = leaf_values[0].blocks().len();
+        assert!(
+            leaf_values
+                .iter()
+                .all(|leaf| leaf.blocks().len() == num_blocks),
+            "All leaves must have the same number of blocks"
+        );
+
+        let mask_lut =
+            self.key.generate_lookup_table_bivariate(
+                |block, indicator| {
+                    if indicator == 1 {
+                        block
+                    } else {
+                        0
+                    }
+                },
+            );
+
+        let mut masked_leaves = leaf_values
+            .par_iter()
+            .zip(indicators.par_iter())
+            .map(|(leaf, indicator)| {
+                let mut masked = leaf.clone();
+                if !masked.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut masked);
+                }
+                self.zero_out_with_lut(&mut masked, &indicator.0, &mask_lut);
+                masked
+            })
+            .collect::<Vec<_>>();
+
+        while masked_leaves.len() > 1 {
+            masked_leaves = masked_leaves
+                .par_chunks(2)
+                .map(|pair| match pair {
+                    [lhs, rhs] => self.add_parallelized(lhs, rhs),
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        masked_leaves.pop().unwrap()
+    }
+
+    /// Increments by one the bucket of the histogram at the encrypted `value`
+    ///
+    /// - Values greater than the last bucket index are clamped, i.e. they increment the last
+    ///   bucket.
+    /// - The increment is added to the first block of each bucket without propagating the carries,
+    ///   a bucket is only propagated when its first block cannot receive another increment. So the
+    ///   buckets may have non-empty carries after this call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let mut buckets = (0..4)
+    ///     .map(|_| sks.create_trivial_zero_radix(size))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// for clear_value in [1u8, 3, 1, 200] {
+    ///     let value = cks.encrypt(clear_value);
+    ///     sks.histogram_update_parallelized(&mut buckets, &value);
+    /// }
+    ///
+    /// // Decrypt:
+    /// let dec = buckets
+    ///     .iter()
+    ///     .map(|bucket| cks.decrypt(bucket))
+    ///     .collect::<Vec<u8>>();
+    /// assert_eq!(dec, [0, 2, 0, 2]);
+    /// ```
+    pub fn histogram_update_parallelized(
+        &self,
+        buckets: &mut [RadixCiphertext],
+        value: &RadixCiphertext,
+    ) {
+        let Some(last_bucket) = buckets.len().checked_sub(1) else {
+            return;
+        };
+
+        let (mut selectors, is_in_last_bucket) = rayon::join(
+            || self.compute_index_selectors(value, last_bucket),
+            || self.scalar_ge_parallelized(value, last_bucket as u64),
+        );
+        selectors.push(is_in_last_bucket);
+
+        buckets
+            .par_iter_mut()
+            .zip(selectors.par_iter())
+            .for_each(|(bucket, selector)| {
+                if self
+                    .key
+                    .is_add_possible(bucket.blocks[0].noise_degree(), selector.0.noise_degree())
+                    .is_err()
+                {
+                    self.full_propagate_parallelized(bucket);
+                }
+                self.key
+                    .unchecked_add_assign(&mut bucket.blocks[0], &selector.0);
+            });
+    }
+
+    /// Adds `delta` to the register at the encrypted `index`, clamping the result to `[0, max]`
+    ///
+    /// - The other registers keep their value.
+    /// - If the index is out of range, no register is modified.
+    /// - A negative `delta` subtracts from the register, and the result is clamped at 0 instead
+    ///   of wrapping around.
+    /// - A positive `delta` is added on a register extended with enough blocks so that the sum
+    ///   cannot wrap around before being clamped at `max`.
+    /// - The result is also clamped at `max` if the register was already greater than `max`.
+    ///
+    /// As the index is encrypted, the update is computed for all the registers in parallel, and
+    /// only the one at the index receives it.
+    ///
+    /// The registers have clean blocks after this call.
+    ///
+    /// # Panics
+    ///
+    /// - if `max` cannot be represented with the number of blocks of a register
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let mut regs = [10u8, 20, 30]
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let max = 25;
+    /// for (clear_index, delta) in [(1u8, 15), (2, -40), (0, -3), (7, 5)] {
+    ///     let index = cks.encrypt(clear_index);
+    ///     sks.bounded_register_update_parallelized(&mut regs, &index, delta, max);
+    /// }
+    ///
+    /// // Decrypt:
+    /// let dec = regs.iter().map(|reg| cks.decrypt(reg)).collect::<Vec<u8>>();
+    /// assert_eq!(dec, [7, 25, 0]);
+    /// ```
+    pub fn bounded_register_update_parallelized(
+        &self,
+        regs: &mut [RadixCiphertext],
+        index: &RadixCiphertext,
+        delta: i64,
+        max: u64,
+    ) {
+        let bits_per_block = self.message_modulus().0.ilog2();
+        let magnitude = delta.unsigned_abs();
+
+        let selectors = self.compute_index_selectors(index, regs.len());
+
+        regs.par_iter_mut()
+            .zip(selectors.par_iter())
+            .for_each(|(reg, selector)| {
+                let num_blocks = reg.blocks.len();
+                let reg_bits = bits_per_block * num_blocks as u32;
+                assert!(
+                    reg_bits >= u64::BITS || max < (1 << reg_bits),
+                    "max ({max}) cannot be represented with {num_blocks} blocks"
+                );
+
+                if !reg.block_carries_are_empty() {
+                    self.full_propagate_parallelized(reg);
+                }
+
+                let updated = if delta >= 0 {
+                    // reg + delta needs one more bit than the biggest of the two operands
+                    let delta_bits = u64::BITS - magnitude.leading_zeros();
+                    let extended_num_blocks =
+                        (reg_bits.max(delta_bits) + 1).div_ceil(bits_per_block) as usize;
+                    let num_extra_blocks = extended_num_blocks - num_blocks;
+
+                    let mut sum =
+                        self.extend_radix_with_trivial_zero_blocks_msb(reg, num_extra_blocks);
+                    self.scalar_add_assign_parallelized(&mut sum, magnitude);
+                    let mut clamped = self.scalar_min_parallelized(&sum, max);
+                    // max fits in the register, so the extra blocks are zeros
+                    self.trim_radix_blocks_msb_assign(&mut clamped, num_extra_blocks);
+                    clamped
+                } else {
+                    let (no_underflow, difference) = rayon::join(
+                        || self.scalar_ge_parallelized(reg, magnitude),
+                        || self.scalar_sub_parallelized(reg, magnitude),
+                    );
+                    let difference =
+                        self.if_then_else_parallelized(&no_underflow, &difference, 0u64);
+                    self.scalar_min_parallelized(&difference, max)
+                };
+
+                *reg = self.if_then_else_parallelized(selector, &updated, &*reg);
+            });
+    }
+
+    /// Sets the bit of `bitset` at the encrypted `bit_index` if `condition` encrypts true
+    ///
+    /// - Bit indices are counted from the least significant bit of `bitset`.
+    /// - Indices greater than the last bit index are clamped, i.e. they set the most significant
+    ///   bit of `bitset`.
+    /// - If `condition` encrypts false, `bitset` keeps its value.
+    ///
+    /// The one-hot encoding of the index is computed with all the bit positions in parallel,
+    /// then it is masked by the condition and OR-ed into all the blocks of `bitset` in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M128, size);
+    ///
+    /// let mut bitset = cks.encrypt(0u8);
+    ///
+    /// for (clear_index, clear_condition) in [(1u8, true), (3, true), (5, false), (200, true)] {
+    ///     let index = cks.encrypt(clear_index);
+    ///     let condition = cks.encrypt_bool(clear_condition);
+    ///     sks.bitset_conditional_insert_parallelized(&mut bitset, &index, &condition);
+    /// }
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&bitset);
+    /// assert_eq!(dec, 0b1000_1010);
+    /// ```
+    pub fn bitset_conditional_insert_parallelized(
+        &self,
+        bitset: &mut RadixCiphertext,
+        bit_index: &RadixCiphertext,
+        condition: &BooleanBlock,
+    ) {
+        let num_bits_in_block = self.message_modulus().0.ilog2() as usize;
+        let Some(last_bit) = (bitset.blocks.len() * num_bits_in_block).checked_sub(1) else {
+            return;
+        };
+
+        let ((mut selectors, is_last_bit), ()) = rayon::join(
+            || {
+                rayon::join(
+                    || self.compute_index_selectors(bit_index, last_bit),
+                    || self.scalar_ge_parallelized(bit_index, last_bit as u64),
+                )
+            },
+            || {
+                if !bitset.block_carries_are_empty() {
+                    self.full_propagate_parallelized(bitset);
+                }
+            },
+        );
+        selectors.push(is_last_bit);
+
+        // At most one selector is set, so packing the selectors of a block
+        // gives a block encrypting either 0 or a single set bit
+        let blocks = selectors
+            .par_chunks(num_bits_in_block)
+            .map(|block_selectors| {
+                let mut block = self.key.create_trivial(0);
+                for (bit_position, selector) in block_selectors.iter().enumerate() {
+                    let shifted = self
+                        .key
+                        .unchecked_scalar_mul(&selector.0, 1 << bit_position);
+                    self.key.unchecked_add_assign(&mut block, &shifted);
+                }
+                if block.degree.get() != 0 {
+                    self.key.message_extract_assign(&mut block);
+                }
+                block
+            })
+            .collect::<Vec<_>>();
+        let mut one_hot = RadixCiphertext::from(blocks);
+
+        self.zero_out_if_condition_is_false(&mut one_hot, &condition.0);
+        self.unchecked_bitor_assign_parallelized(bitset, &one_hot);
+    }
+
+    /// Computes the flat index `y * width + x` of the encrypted coordinates `(x, y)` of a
+    /// row-major 2D buffer, on enough blocks so that it never wraps around, along with whether
+    /// `x < width`
+    pub(crate) fn compute_2d_index(
+        &self,
+        x: &RadixCiphertext,
+        y: &RadixCiphertext,
+        width: usize,
+    ) -> (RadixCiphertext, BooleanBlock) {
+        // Number of blocks needed to represent the width, so that the product does not wrap
+        let message_modulus = u128::from(self.message_modulus().0);
+        let mut num_blocks_for_width = 0;
+        let mut num_representable = 1u128;
+        while num_representable <= width as u128 {
+            num_representable *= message_modulus;
+            num_blocks_for_width += 1;
+        }
+        // One more block than the widest of the product and x, so that the sum does not wrap
+        let num_blocks_for_index = (y.blocks.len() + num_blocks_for_width).max(x.blocks.len()) + 1;
+
+        rayon::join(
+            || {
+                let (y_times_width, extended_x) = rayon::join(
+                    || {
+                        let extended_y = self.extend_radix_with_trivial_zero_blocks_msb(
+                            y,
+                            num_blocks_for_index - y.blocks.len(),
+                        );
+                        self.scalar_mul_parallelized(&extended_y, width as u64)
+                    },
+                    || {
+                        self.extend_radix_with_trivial_zero_blocks_msb(
+                            x,
+                            num_blocks_for_index - x.blocks.len(),
+                        )
+                    },
+                );
+                self.add_parallelized(&y_times_width, &extended_x)
+            },
+            || self.scalar_lt_parallelized(x, width as u64),
+        )
+    }
+
+    /// Returns the element of `values` at the encrypted `index`,
+    /// or an encryption of 0 if the index is out of range
+    ///
+    /// The returned ciphertext has clean blocks
+    fn select_slice_element<T>(&self, index: &RadixCiphertext, values: &[T]) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let selectors = self.compute_index_selectors(index, values.len());
+
+        let masked_values = values
+            .par_iter()
+            .zip(selectors.par_iter())
+            .map(|(value, selector)| self.mask_with_selector(value, selector))
+            .collect::<Vec<_>>();
+
+        self.sum_one_hot_vector(masked_values)
+    }
+
+    /// Computes, for each position in `0..len`, a boolean block encrypting whether `index`
+    /// is equal to that position
+    ///
+    /// Positions that cannot be represented with the number of blocks of `index`
+    /// are never selected.
+    pub(crate) fn compute_index_selectors(
+        &self,
+        index: &RadixCiphertext,
+        len: usize,
+    ) -> Vec<BooleanBlock> {
+        let mut tmp_index;
+        let index = if index.block_carries_are_empty() {
+            index
+        } else {
+            tmp_index = index.clone();
+            self.full_propagate_parallelized(&mut tmp_index);
+            &tmp_index
+        };
+
+        let num_bits_in_index = self.message_modulus().0.ilog2() * index.blocks.len() as u32;
+        let num_representable = if num_bits_in_index >= usize::BITS {
+            len
+        } else {
+            len.min(1 << num_bits_in_index)
+        };
+
+        let mut selectors =
+            self.compute_equality_selectors(index, (0..num_representable as u64).into_par_iter());
+        selectors.resize(len, self.create_trivial_boolean_block(false));
+        selectors
+    }
+
+    /// Returns a copy of `ct` if the `selector` encrypts true, otherwise returns an encryption of
+    /// 0
+    pub(crate) fn mask_with_selector<T>(&self, ct: &T, selector: &BooleanBlock) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        let mut result = ct.clone();
+        self.zero_out_if_condition_is_false(&mut result, &selector.0);
+        result
+    }
+
+    /// Sums a vec of radix ciphertexts where at most one element is non-zero
+    ///
+    /// As at most one element is non-zero, the sum never overflows a block, so only the noise
+    /// needs to be managed.
+    ///
+    /// The returned ciphertext has clean blocks
+    pub(crate) fn sum_one_hot_vector<T>(&self, mut one_hot_vector: Vec<T>) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!one_hot_vector.is_empty());
+
+        // Since all but one radix are zeros, the limiting factor
+        // for additions is the noise level
+        let chunk_size = (self.key.max_noise_level.get() as usize).max(2);
+
+        let sum_chunk = |chunk: &[T]| {
+            let mut result = chunk[0].clone();
+            for ct in &chunk[1..] {
+                self.unchecked_add_assign(&mut result, ct);
+            }
+            result
+                .blocks_mut()
+                .par_iter_mut()
+                .for_each(|block| self.key.message_extract_assign(block));
+            result
+        };
+
+        while one_hot_vector.len() > 1 {
+            one_hot_vector = one_hot_vector
+                .par_chunks(chunk_size)
+                .map(|chunk| {
+                    if chunk.len() == 1 {
+                        chunk[0].clone()
+                    } else {
+                        sum_chunk(chunk)
+                    }
+                })
+                .collect();
+        }
+
+        one_hot_vector.pop().unwrap()
+    }
+}
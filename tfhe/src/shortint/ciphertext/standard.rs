@@ -138,6 +138,28 @@ impl Ciphertext {
             && self.ct.get_mask().as_ref().iter().all(|&x| x == 0u64)
     }
 
+    /// Returns an estimate of the size in bytes of the ciphertext once serialized
+    ///
+    /// It is the size of the LWE ciphertext coefficients, plus the size of the metadata
+    /// (moduli, degree, noise level, PBS order) and of the length prefix of the coefficients,
+    /// as encoded by `bincode`. Other serialization formats may give a different size.
+    pub fn serialized_size_hint(&self) -> usize {
+        // Length prefix of the coefficients
+        let coefficients_len_size = size_of::<u64>();
+        // The ciphertext modulus is serialized as a u128 and a usize
+        let ciphertext_modulus_size = size_of::<u128>() + size_of::<u64>();
+        // degree, noise_level, message_modulus and carry_modulus
+        let metadata_size = 4 * size_of::<u64>();
+        // The enum discriminant
+        let pbs_order_size = size_of::<u32>();
+
+        coefficients_len_size
+            + std::mem::size_of_val(self.ct.as_ref())
+            + ciphertext_modulus_size
+            + metadata_size
+            + pbs_order_size
+    }
+
     pub fn noise_level(&self) -> NoiseLevel {
         self.noise_level
     }